@@ -1,5 +1,6 @@
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
 use palette::convert::FromColorUnclamped;
+use palette::white_point::D65;
 use palette::{Lab, Lch, Xyz, Yxy};
 
 #[path = "../tests/convert/data_color_mine.rs"]
@@ -76,7 +77,7 @@ fn cie_conversion(c: &mut Criterion) {
     group.bench_with_input("linsrgb to xyz", &colormine, |b, colormine| {
         b.iter(|| {
             for c in colormine {
-                black_box(Xyz::from_color_unclamped(c.linear_rgb));
+                black_box(Xyz::<D65, _>::from_color_unclamped(c.linear_rgb));
             }
         })
     });
@@ -87,7 +88,7 @@ fn cie_conversion(c: &mut Criterion) {
         |b, wide_colormine| {
             b.iter(|| {
                 for c in wide_colormine {
-                    black_box(Xyz::from_color_unclamped(c.linear_rgb));
+                    black_box(Xyz::<D65, _>::from_color_unclamped(c.linear_rgb));
                 }
             })
         },
@@ -34,7 +34,7 @@ fn main() {
         let foreground = Srgba::from(foreground.0).into_linear();
 
         let bg_rgb = background;
-        let bg_xyz = Xyza::from_color(background);
+        let bg_xyz: Xyza = Xyza::from_color(background);
 
         for alpha_step in 0..ALPHA_STEPS {
             // Copy the original so we don't stack alpha changes.
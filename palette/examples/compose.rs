@@ -15,7 +15,7 @@
 
 use palette::{
     blend::{Compose, PreAlpha, Premultiply},
-    Alpha, FromColor, IntoColor, Laba, LinSrgba, Luva, Oklaba, Srgba, Xyza, Yxya,
+    Alpha, FromColor, IntoColor, Lab, Laba, LinSrgba, Luva, Oklaba, Srgba, Xyz, Xyza, Yxya,
 };
 
 const ALPHA_STEPS: u32 = 3;
@@ -41,9 +41,9 @@ fn main() {
         let foreground = Srgba::from(foreground.0).into_linear();
 
         let bg_rgb = background.into();
-        let bg_xyz = Xyza::from_color(background).into();
+        let bg_xyz: PreAlpha<Xyz> = Xyza::from_color(background).into();
         let bg_yxy = Yxya::from_color(background).into();
-        let bg_lab = Laba::from_color(background).into();
+        let bg_lab: PreAlpha<Lab> = Laba::from_color(background).into();
         let bg_oklab = Oklaba::from_color(background).into();
         let bg_luv = Luva::from_color(background).into();
 
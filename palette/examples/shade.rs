@@ -5,7 +5,7 @@ use image::{GenericImage, GenericImageView, RgbImage};
 fn main() {
     //The same color in linear RGB, CIE L*a*b*, and HSV
     let rgb = LinSrgb::new(0.5, 0.0, 0.0);
-    let lab = Lab::from_color(rgb);
+    let lab: Lab = Lab::from_color(rgb);
     let hsv = Hsv::from_color(rgb);
 
     let mut image = RgbImage::new(220, 193);
@@ -24,6 +24,7 @@ use crate::{
     convert::{FromColorUnclamped, IntoColorUnclamped},
     num::{self, Arithmetics, One, PartialCmp, Zero},
     stimulus::Stimulus,
+    white_point::HasWhitePoint,
     ArrayExt, Clamp, ClampAssign, GetHue, IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign,
     NextArray, Saturate, SaturateAssign, SetHue, ShiftHue, ShiftHueAssign, WithAlpha, WithHue,
 };
@@ -92,6 +93,10 @@ where
     }
 }
 
+impl<C: HasWhitePoint, A> HasWhitePoint for Alpha<C, A> {
+    type WhitePoint = C::WhitePoint;
+}
+
 impl<C, A> WithAlpha<A> for Alpha<C, A> {
     type Color = C;
     type WithAlpha = Self;
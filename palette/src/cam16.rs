@@ -0,0 +1,709 @@
+//! Types for the CAM16 color appearance model.
+//!
+//! CAM16 predicts how a color stimulus is perceived under a given set of
+//! [`ViewingConditions`], such as the brightness of the adapting field and
+//! the surround. The same stimulus may look very different under different
+//! conditions, so [`Cam16`] describes its appearance with a set of
+//! correlates, instead of as a tristimulus value.
+//!
+//! ```
+//! use palette::{
+//!     cam16::{Surround, ViewingConditions},
+//!     white_point::D65,
+//!     Cam16, IntoColor, Srgb, Xyz,
+//! };
+//!
+//! // Conversion traits use the default viewing conditions.
+//! let cam16: Cam16 = Srgb::new(0.3f32, 0.8, 0.1).into_color();
+//!
+//! // Custom conditions, such as a dim room with a darker adapting field.
+//! let conditions = ViewingConditions {
+//!     adapting_luminance: 16.0,
+//!     background_luminance: 0.2,
+//!     surround: Surround::Dim,
+//!     ..ViewingConditions::default()
+//! }
+//! .bake::<D65>();
+//!
+//! let xyz: Xyz = Srgb::new(0.3f32, 0.8, 0.1).into_color();
+//! let dim_cam16 = Cam16::from_xyz(xyz, conditions);
+//! ```
+
+use core::{marker::PhantomData, ops::BitAnd};
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{
+    angle::RealAngle,
+    bool_mask::{HasBoolMask, LazySelect},
+    clamp_min, clamp_min_assign,
+    convert::FromColorUnclamped,
+    matrix::multiply_xyz,
+    num::{
+        self, Abs, Arithmetics, Cbrt, Exp, FromScalarArray, Hypot, IntoScalarArray, MinMax, One,
+        PartialCmp, Powf, Real, Sqrt, Trigonometry, Zero,
+    },
+    white_point::{Any, WhitePoint, D65},
+    Alpha, Cam16Hue, Clamp, ClampAssign, GetHue, IsWithinBounds, Mat3, Xyz,
+};
+
+pub use self::alpha::{Cam16Ucsa, Cam16a};
+pub use self::ucs::Cam16Ucs;
+pub use self::viewing_conditions::{
+    BakedViewingConditions, DegreeOfAdaptation, Surround, ViewingConditions,
+};
+
+mod alpha;
+mod ucs;
+mod viewing_conditions;
+
+/// The CAM16 color appearance model.
+///
+/// CAM16 describes the appearance of a color stimulus, given the
+/// [`ViewingConditions`] it's seen in. It's a revision of CIECAM02 that
+/// fixes some of its numerical issues, and it's commonly used as a base for
+/// perceptually uniform color spaces.
+///
+/// The appearance is described by six correlates, where either brightness or
+/// lightness, either colorfulness, chroma or saturation, and the hue are
+/// enough to describe the color. All six are calculated for convenience, but
+/// only `lightness`, `chroma` and `hue` are used when converting back into
+/// other color spaces.
+///
+/// The conversion traits, such as [`FromColorUnclamped`], use the
+/// [default viewing conditions](ViewingConditions::default). Use
+/// [`Cam16::from_xyz`] and [`Cam16::into_xyz`] for custom conditions.
+///
+/// The white point, `Wp`, is the reference white that the observer is
+/// adapted to.
+#[derive(Debug, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "Wp",
+    component = "T",
+    skip_derives(Xyz, Cam16, Cam16Ucs)
+)]
+#[repr(C)]
+pub struct Cam16<Wp = D65, T = f32> {
+    /// The lightness (J) of the color, relative to the brightness of the
+    /// reference white. It's 100.0 for the reference white.
+    pub lightness: T,
+
+    /// The chroma (C) of the color, or its colorfulness relative to the
+    /// brightness of the reference white.
+    pub chroma: T,
+
+    /// The hue (h) of the color, in degrees.
+    #[palette(unsafe_same_layout_as = "T")]
+    pub hue: Cam16Hue<T>,
+
+    /// The colorfulness (M) of the color, or how much of its hue it appears
+    /// to have. It increases with the luminance of the scene.
+    pub colorfulness: T,
+
+    /// The saturation (s) of the color, or its colorfulness relative to its
+    /// own brightness.
+    pub saturation: T,
+
+    /// The brightness (Q) of the color, or how much light it appears to
+    /// emit.
+    pub brightness: T,
+
+    /// The reference white point that the observer is adapted to.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub white_point: PhantomData<Wp>,
+}
+
+impl<Wp, T> Copy for Cam16<Wp, T> where T: Copy {}
+
+impl<Wp, T> Clone for Cam16<Wp, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Cam16<Wp, T> {
+        Cam16 {
+            lightness: self.lightness.clone(),
+            chroma: self.chroma.clone(),
+            hue: self.hue.clone(),
+            colorfulness: self.colorfulness.clone(),
+            saturation: self.saturation.clone(),
+            brightness: self.brightness.clone(),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Cam16<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Sqrt
+        + Abs
+        + Hypot
+        + MinMax
+        + Trigonometry
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    /// Calculate the appearance of an `Xyz` color, when viewed in the given
+    /// conditions.
+    ///
+    /// The luminance of the color, `y`, is expected to be relative to the
+    /// luminance of the reference white, meaning that the white point has a
+    /// luminance of 1.0.
+    ///
+    /// ```
+    /// use palette::{cam16::ViewingConditions, white_point::D65, Cam16, Xyz};
+    ///
+    /// let xyz: Xyz<D65, f32> = Xyz::new(0.3, 0.4, 0.5);
+    /// let cam16 = Cam16::from_xyz(xyz, ViewingConditions::default());
+    /// ```
+    #[must_use]
+    pub fn from_xyz(
+        color: Xyz<Wp, T>,
+        conditions: impl Into<BakedViewingConditions<Wp, T>>,
+    ) -> Self {
+        xyz_to_cam16(color, &conditions.into())
+    }
+
+    /// Calculate the `Xyz` color that has this appearance in the given
+    /// conditions.
+    ///
+    /// The result is only based on `lightness`, `chroma` and `hue`.
+    #[must_use]
+    pub fn into_xyz(self, conditions: impl Into<BakedViewingConditions<Wp, T>>) -> Xyz<Wp, T> {
+        cam16_to_xyz(self.lightness, self.chroma, self.hue, &conditions.into())
+    }
+
+    /// Create a `Cam16` color from its lightness, chroma and hue, and calculate
+    /// the rest of its correlates for the given conditions.
+    ///
+    /// ```
+    /// use palette::{cam16::ViewingConditions, white_point::D65, Cam16};
+    ///
+    /// let cam16 = Cam16::<D65, f32>::from_jch(50.0, 30.0, 120.0, ViewingConditions::default());
+    /// ```
+    #[must_use]
+    pub fn from_jch<H: Into<Cam16Hue<T>>>(
+        lightness: T,
+        chroma: T,
+        hue: H,
+        conditions: impl Into<BakedViewingConditions<Wp, T>>,
+    ) -> Self {
+        jch_to_cam16(lightness, chroma, hue.into(), &conditions.into())
+    }
+}
+
+impl<Wp, T> Cam16<Wp, T>
+where
+    T: Zero,
+{
+    /// Return the `lightness` value minimum.
+    pub fn min_lightness() -> T {
+        T::zero()
+    }
+
+    /// Return the `chroma` value minimum.
+    pub fn min_chroma() -> T {
+        T::zero()
+    }
+
+    /// Return the `colorfulness` value minimum.
+    pub fn min_colorfulness() -> T {
+        T::zero()
+    }
+
+    /// Return the `saturation` value minimum.
+    pub fn min_saturation() -> T {
+        T::zero()
+    }
+
+    /// Return the `brightness` value minimum.
+    pub fn min_brightness() -> T {
+        T::zero()
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam16<Wp, T>> for Cam16<Wp, T> {
+    fn from_color_unclamped(color: Cam16<Wp, T>) -> Self {
+        color
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Xyz<Wp, T>> for Cam16<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + Hypot
+        + Trigonometry
+        + num::Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+        xyz_to_cam16(color, &ViewingConditions::default().into())
+    }
+}
+
+impl<Wp, T> GetHue for Cam16<Wp, T>
+where
+    T: Clone,
+{
+    type Hue = Cam16Hue<T>;
+
+    #[inline]
+    fn get_hue(&self) -> Cam16Hue<T> {
+        self.hue.clone()
+    }
+}
+
+impl_is_within_bounds! {
+    Cam16<Wp> {
+        lightness => [Self::min_lightness(), None],
+        chroma => [Self::min_chroma(), None],
+        colorfulness => [Self::min_colorfulness(), None],
+        saturation => [Self::min_saturation(), None],
+        brightness => [Self::min_brightness(), None]
+    }
+    where T: Zero
+}
+
+impl<Wp, T> Clamp for Cam16<Wp, T>
+where
+    T: Zero + num::Clamp,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Cam16 {
+            lightness: clamp_min(self.lightness, Self::min_lightness()),
+            chroma: clamp_min(self.chroma, Self::min_chroma()),
+            hue: self.hue,
+            colorfulness: clamp_min(self.colorfulness, Self::min_colorfulness()),
+            saturation: clamp_min(self.saturation, Self::min_saturation()),
+            brightness: clamp_min(self.brightness, Self::min_brightness()),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> ClampAssign for Cam16<Wp, T>
+where
+    T: Zero + num::ClampAssign,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_min_assign(&mut self.lightness, Self::min_lightness());
+        clamp_min_assign(&mut self.chroma, Self::min_chroma());
+        clamp_min_assign(&mut self.colorfulness, Self::min_colorfulness());
+        clamp_min_assign(&mut self.saturation, Self::min_saturation());
+        clamp_min_assign(&mut self.brightness, Self::min_brightness());
+    }
+}
+
+impl<Wp, T> HasBoolMask for Cam16<Wp, T>
+where
+    T: HasBoolMask,
+{
+    type Mask = T::Mask;
+}
+
+impl<Wp, T> Default for Cam16<Wp, T>
+where
+    T: Zero,
+    Cam16Hue<T>: Default,
+{
+    fn default() -> Cam16<Wp, T> {
+        Cam16 {
+            lightness: T::zero(),
+            chroma: T::zero(),
+            hue: Cam16Hue::default(),
+            colorfulness: T::zero(),
+            saturation: T::zero(),
+            brightness: T::zero(),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl_array_casts!(Cam16<Wp, T>, [T; 6]);
+impl_simd_array_conversion_hue!(
+    Cam16<Wp>,
+    [lightness, chroma, colorfulness, saturation, brightness],
+    white_point
+);
+
+impl_eq_hue!(
+    Cam16<Wp>,
+    Cam16Hue,
+    [lightness, chroma, hue, colorfulness, saturation, brightness]
+);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp, T> bytemuck::Zeroable for Cam16<Wp, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp: 'static, T> bytemuck::Pod for Cam16<Wp, T> where T: bytemuck::Pod {}
+
+/// XYZ to cone response (CAM16 RGB) transformation matrix.
+#[rustfmt::skip]
+pub(crate) fn m16<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(0.401288), T::from_f64(0.650173), T::from_f64(-0.051461),
+        T::from_f64(-0.250268), T::from_f64(1.204414), T::from_f64(0.045854),
+        T::from_f64(-0.002079), T::from_f64(0.048952), T::from_f64(0.953127),
+    ]
+}
+
+/// Cone response (CAM16 RGB) to XYZ transformation matrix.
+#[rustfmt::skip]
+pub(crate) fn m16_inv<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(1.8620678550872327), T::from_f64(-1.0112546305316843), T::from_f64(0.14918677544445175),
+        T::from_f64(0.3875265432361371), T::from_f64(0.6214474419314753), T::from_f64(-0.00897398516761252),
+        T::from_f64(-0.015841498849333856), T::from_f64(-0.03412293802851556), T::from_f64(1.0499644368778493),
+    ]
+}
+
+/// The post-adaptation non-linear response compression, without the 0.1
+/// offset.
+#[inline]
+pub(crate) fn compress<T>(component: T, f_l: &T) -> T
+where
+    T: Real + Arithmetics + Powf + Abs + Zero + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let factor =
+        (f_l.clone() * component.clone().abs() / T::from_f64(100.0)).powf(T::from_f64(0.42));
+    let response = T::from_f64(400.0) * &factor / (factor + T::from_f64(27.13));
+
+    lazy_select! {
+        if component.lt(&T::zero()) => -response.clone(),
+        else => response.clone(),
+    }
+}
+
+/// The inverse of `compress`.
+#[inline]
+pub(crate) fn decompress<T>(response: T, f_l: &T) -> T
+where
+    T: Real + Arithmetics + Powf + Abs + MinMax + Zero + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let abs_response = response.clone().abs();
+    let base =
+        (T::from_f64(27.13) * &abs_response / (T::from_f64(400.0) - abs_response)).max(T::zero());
+    let component = T::from_f64(100.0) / f_l * base.powf(T::from_f64(1.0 / 0.42));
+
+    lazy_select! {
+        if response.lt(&T::zero()) => -component.clone(),
+        else => component.clone(),
+    }
+}
+
+pub(crate) fn jch_to_cam16<Wp, T>(
+    lightness: T,
+    chroma: T,
+    hue: Cam16Hue<T>,
+    conditions: &BakedViewingConditions<Wp, T>,
+) -> Cam16<Wp, T>
+where
+    T: Real + Arithmetics + Sqrt + Zero + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let brightness = lightness_to_brightness(lightness.clone(), conditions);
+    let colorfulness = chroma.clone() * &conditions.f_l_4;
+    let saturation = colorfulness_to_saturation(colorfulness.clone(), brightness.clone());
+
+    Cam16 {
+        lightness,
+        chroma,
+        hue,
+        colorfulness,
+        saturation,
+        brightness,
+        white_point: PhantomData,
+    }
+}
+
+#[inline]
+fn lightness_to_brightness<Wp, T>(lightness: T, conditions: &BakedViewingConditions<Wp, T>) -> T
+where
+    T: Real + Arithmetics + Sqrt + Clone,
+{
+    T::from_f64(4.0) / &conditions.c
+        * (lightness / T::from_f64(100.0)).sqrt()
+        * (conditions.a_w.clone() + T::from_f64(4.0))
+        * &conditions.f_l_4
+}
+
+#[inline]
+fn colorfulness_to_saturation<T>(colorfulness: T, brightness: T) -> T
+where
+    T: Real + Arithmetics + Sqrt + Zero + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    lazy_select! {
+        if brightness.gt(&T::zero()) => {
+            T::from_f64(100.0) * (colorfulness.clone() / &brightness).sqrt()
+        },
+        else => T::zero(),
+    }
+}
+
+/// The eccentricity factor, `e_t`, for a hue angle in radians.
+#[inline]
+fn eccentricity<T>(hue_radians: T) -> T
+where
+    T: Real + Arithmetics + Trigonometry,
+{
+    T::from_f64(0.25) * ((hue_radians + T::from_f64(2.0)).cos() + T::from_f64(3.8))
+}
+
+pub(crate) fn xyz_to_cam16<Wp, T>(
+    color: Xyz<Wp, T>,
+    conditions: &BakedViewingConditions<Wp, T>,
+) -> Cam16<Wp, T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Sqrt
+        + Abs
+        + Hypot
+        + Trigonometry
+        + Zero
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let Xyz {
+        x: red,
+        y: green,
+        z: blue,
+        ..
+    } = multiply_xyz(m16(), color.with_white_point::<Any>() * T::from_f64(100.0));
+
+    let [d_r, d_g, d_b] = conditions.d_rgb.clone();
+    let red = compress(red * d_r, &conditions.f_l);
+    let green = compress(green * d_g, &conditions.f_l);
+    let blue = compress(blue * d_b, &conditions.f_l);
+
+    let a = red.clone() - T::from_f64(12.0 / 11.0) * &green + T::from_f64(1.0 / 11.0) * &blue;
+    let b = (red.clone() + &green - T::from_f64(2.0) * &blue) / T::from_f64(9.0);
+    let hue_radians = b.clone().atan2(a.clone());
+
+    let achromatic =
+        (T::from_f64(2.0) * &red + &green + T::from_f64(0.05) * &blue) * &conditions.n_bb;
+    let lightness = T::from_f64(100.0)
+        * (achromatic / &conditions.a_w).powf(conditions.c.clone() * &conditions.z);
+    let brightness = lightness_to_brightness(lightness.clone(), conditions);
+
+    let t = T::from_f64(50000.0 / 13.0)
+        * &conditions.n_c
+        * &conditions.n_bb
+        * eccentricity(hue_radians.clone())
+        * a.hypot(b)
+        / (red + green + T::from_f64(1.05) * blue + T::from_f64(0.305));
+    let chroma = t.powf(T::from_f64(0.9))
+        * (lightness.clone() / T::from_f64(100.0)).sqrt()
+        * &conditions.chroma_factor;
+    let colorfulness = chroma.clone() * &conditions.f_l_4;
+    let saturation = colorfulness_to_saturation(colorfulness.clone(), brightness.clone());
+
+    Cam16 {
+        lightness,
+        chroma,
+        hue: Cam16Hue::from_radians(hue_radians),
+        colorfulness,
+        saturation,
+        brightness,
+        white_point: PhantomData,
+    }
+}
+
+pub(crate) fn cam16_to_xyz<Wp, T>(
+    lightness: T,
+    chroma: T,
+    hue: Cam16Hue<T>,
+    conditions: &BakedViewingConditions<Wp, T>,
+) -> Xyz<Wp, T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Sqrt
+        + Abs
+        + MinMax
+        + Trigonometry
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let relative_lightness = lightness / T::from_f64(100.0);
+    let alpha = lazy_select! {
+        if relative_lightness.gt(&T::zero()) => chroma.clone() / relative_lightness.clone().sqrt(),
+        else => T::zero(),
+    };
+    let t = (alpha / &conditions.chroma_factor).powf(T::from_f64(1.0 / 0.9));
+
+    let hue_radians = hue.into_raw_radians();
+    let (hue_sin, hue_cos) = hue_radians.clone().sin_cos();
+
+    let achromatic = conditions.a_w.clone()
+        * relative_lightness.powf(T::one() / (conditions.c.clone() * &conditions.z));
+    let p1 = T::from_f64(50000.0 / 13.0)
+        * &conditions.n_c
+        * &conditions.n_bb
+        * eccentricity(hue_radians);
+    let p2 = achromatic / &conditions.n_bb;
+
+    let gamma = T::from_f64(23.0) * (p2.clone() + T::from_f64(0.305)) * &t
+        / (T::from_f64(23.0) * p1
+            + T::from_f64(11.0) * t.clone() * &hue_cos
+            + T::from_f64(108.0) * t * &hue_sin);
+    let a = gamma.clone() * hue_cos;
+    let b = gamma * hue_sin;
+
+    let red = (T::from_f64(460.0) * &p2 + T::from_f64(451.0) * &a + T::from_f64(288.0) * &b)
+        / T::from_f64(1403.0);
+    let green = (T::from_f64(460.0) * &p2 - T::from_f64(891.0) * &a - T::from_f64(261.0) * &b)
+        / T::from_f64(1403.0);
+    let blue = (T::from_f64(460.0) * p2 - T::from_f64(220.0) * a - T::from_f64(6300.0) * b)
+        / T::from_f64(1403.0);
+
+    let [d_r, d_g, d_b] = conditions.d_rgb.clone();
+    let red = decompress(red, &conditions.f_l) / d_r;
+    let green = decompress(green, &conditions.f_l) / d_g;
+    let blue = decompress(blue, &conditions.f_l) / d_b;
+
+    let xyz = multiply_xyz(m16_inv(), Xyz::new(red, green, blue)) / T::from_f64(100.0);
+    xyz.with_white_point()
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Cam16, ViewingConditions};
+    use crate::{
+        convert::{FromColorUnclamped, IntoColorUnclamped},
+        white_point::D65,
+        Clamp, IsWithinBounds, Srgb, Xyz,
+    };
+
+    // Reference values from Material Color Utilities, which uses the default
+    // viewing conditions. Its sRGB to XYZ matrix is slightly different, hence
+    // the tolerance.
+    fn check_srgb(rgb: Srgb<f64>, expected: [f64; 6]) {
+        let cam16: Cam16<D65, f64> = rgb.into_color_unclamped();
+        let [lightness, chroma, hue, colorfulness, saturation, brightness] = expected;
+
+        assert_relative_eq!(cam16.lightness, lightness, epsilon = 0.05);
+        assert_relative_eq!(cam16.chroma, chroma, epsilon = 0.05);
+        assert_relative_eq!(cam16.hue.into_positive_degrees(), hue, epsilon = 0.05);
+        assert_relative_eq!(cam16.colorfulness, colorfulness, epsilon = 0.05);
+        assert_relative_eq!(cam16.saturation, saturation, epsilon = 0.05);
+        assert_relative_eq!(cam16.brightness, brightness, epsilon = 0.05);
+    }
+
+    #[test]
+    fn red() {
+        check_srgb(
+            Srgb::new(1.0, 0.0, 0.0),
+            [46.445, 113.357, 27.408, 89.494, 91.889, 105.988],
+        );
+    }
+
+    #[test]
+    fn green() {
+        check_srgb(
+            Srgb::new(0.0, 1.0, 0.0),
+            [79.331, 108.410, 142.139, 85.587, 78.604, 138.520],
+        );
+    }
+
+    #[test]
+    fn blue() {
+        check_srgb(
+            Srgb::new(0.0, 0.0, 1.0),
+            [25.465, 87.230, 282.788, 68.867, 93.674, 78.481],
+        );
+    }
+
+    #[test]
+    fn black() {
+        check_srgb(Srgb::new(0.0, 0.0, 0.0), [0.0, 0.0, 0.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn white() {
+        check_srgb(
+            Srgb::new(1.0, 1.0, 1.0),
+            [100.0, 2.869, 209.492, 2.265, 12.068, 155.521],
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        let conditions = ViewingConditions {
+            adapting_luminance: 20.0,
+            background_luminance: 0.1,
+            surround: super::Surround::Dark,
+            ..ViewingConditions::default()
+        }
+        .bake::<D65>();
+
+        for &(x, y, z) in &[
+            (0.2, 0.3, 0.4),
+            (0.9, 0.8, 0.1),
+            (0.05, 0.02, 0.3),
+            (0.95047, 1.0, 1.08883),
+        ] {
+            let xyz = Xyz::<D65, f64>::new(x, y, z);
+            let cam16 = Cam16::from_xyz(xyz, conditions);
+            assert_relative_eq!(cam16.into_xyz(conditions), xyz, epsilon = 0.000001);
+
+            let from_jch = Cam16::from_jch(cam16.lightness, cam16.chroma, cam16.hue, conditions);
+            assert_relative_eq!(from_jch, cam16, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn default_conversion_roundtrip() {
+        let rgb = Srgb::new(0.3f64, 0.7, 0.2);
+        let cam16 = Cam16::<D65, f64>::from_color_unclamped(rgb);
+        let back = Srgb::from_color_unclamped(cam16);
+        assert_relative_eq!(back, rgb, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn clamp() {
+        let cam16 = Cam16::<D65, f64>::from([-1.0, -2.0, 30.0, -3.0, -4.0, -5.0]);
+        assert!(!cam16.is_within_bounds());
+
+        let clamped = cam16.clamp();
+        assert!(clamped.is_within_bounds());
+        assert_relative_eq!(clamped, Cam16::from([0.0, 0.0, 30.0, 0.0, 0.0, 0.0]));
+    }
+}
@@ -0,0 +1,10 @@
+use crate::{white_point::D65, Alpha};
+
+use super::{Cam16, Cam16Ucs};
+
+/// CAM16 with an alpha component.
+pub type Cam16a<Wp = D65, T = f32> = Alpha<Cam16<Wp, T>, T>;
+
+/// CAM16-UCS with an alpha component. See the [`Cam16Ucsa` implementation in
+/// `Alpha`](crate::Alpha#Cam16Ucsa).
+pub type Cam16Ucsa<Wp = D65, T = f32> = Alpha<Cam16Ucs<Wp, T>, T>;
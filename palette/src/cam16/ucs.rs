@@ -0,0 +1,447 @@
+use core::{
+    marker::PhantomData,
+    ops::{Add, AddAssign, BitAnd, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{
+    angle::RealAngle,
+    blend::{PreAlpha, Premultiply},
+    bool_mask::{HasBoolMask, LazySelect},
+    clamp, clamp_assign,
+    convert::FromColorUnclamped,
+    num::{
+        self, Abs, Arithmetics, Cbrt, Exp, FromScalarArray, Hypot, IntoScalarArray, IsValidDivisor,
+        Ln, MinMax, One, PartialCmp, Powf, Real, Sqrt, Trigonometry, Zero,
+    },
+    stimulus::Stimulus,
+    white_point::{WhitePoint, D65},
+    Alpha, Cam16, Cam16Hue, Clamp, ClampAssign, ColorDifference, GetHue, IsWithinBounds, Lighten,
+    LightenAssign, Mix, MixAssign,
+};
+
+use super::{jch_to_cam16, BakedViewingConditions, ViewingConditions};
+
+/// The CAM16-UCS uniform color space.
+///
+/// CAM16-UCS is a perceptually uniform rectangular space, derived from the
+/// lightness, colorfulness and hue of [`Cam16`]. Euclidean distances in it
+/// are a good approximation of perceived color differences, and it's the
+/// recommended space for calculating them with CAM16.
+///
+/// The conversion from [`Cam16`] doesn't depend on the viewing conditions,
+/// but the conversion back does, since `chroma` has to be calculated from
+/// `colorfulness`. The conversion traits use the
+/// [default viewing conditions](ViewingConditions::default). Use
+/// [`Cam16Ucs::into_cam16`] for custom conditions.
+#[derive(Debug, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "Wp",
+    component = "T",
+    skip_derives(Cam16, Cam16Ucs)
+)]
+#[repr(C)]
+pub struct Cam16Ucs<Wp = D65, T = f32> {
+    /// The lightness (J') of the color. 0.0 is black and 100.0 is the
+    /// lightness of the reference white.
+    pub lightness: T,
+
+    /// The red-green (a') component of the color.
+    pub a: T,
+
+    /// The yellow-blue (b') component of the color.
+    pub b: T,
+
+    /// The reference white point that the observer is adapted to.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub white_point: PhantomData<Wp>,
+}
+
+impl<Wp, T> Copy for Cam16Ucs<Wp, T> where T: Copy {}
+
+impl<Wp, T> Clone for Cam16Ucs<Wp, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Cam16Ucs<Wp, T> {
+        Cam16Ucs {
+            lightness: self.lightness.clone(),
+            a: self.a.clone(),
+            b: self.b.clone(),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Cam16Ucs<Wp, T> {
+    /// Create a CAM16-UCS color.
+    pub const fn new(lightness: T, a: T, b: T) -> Self {
+        Cam16Ucs {
+            lightness,
+            a,
+            b,
+            white_point: PhantomData,
+        }
+    }
+
+    /// Convert to a `(J', a', b')` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.lightness, self.a, self.b)
+    }
+
+    /// Convert from a `(J', a', b')` tuple.
+    pub fn from_components((lightness, a, b): (T, T, T)) -> Self {
+        Self::new(lightness, a, b)
+    }
+}
+
+impl<Wp, T> Cam16Ucs<Wp, T>
+where
+    T: Zero + Real,
+{
+    /// Return the `lightness` value minimum.
+    pub fn min_lightness() -> T {
+        T::zero()
+    }
+
+    /// Return the `lightness` value maximum.
+    pub fn max_lightness() -> T {
+        T::from_f64(100.0)
+    }
+}
+
+impl<Wp, T> Cam16Ucs<Wp, T>
+where
+    T: Real + RealAngle + Arithmetics + Ln + Trigonometry + One + Clone,
+{
+    /// Calculate the CAM16-UCS coordinates of a [`Cam16`] color.
+    ///
+    /// Only `lightness`, `colorfulness` and `hue` are used.
+    #[must_use]
+    pub fn from_cam16(color: Cam16<Wp, T>) -> Self {
+        let lightness =
+            T::from_f64(1.7) * &color.lightness / (T::one() + T::from_f64(0.007) * color.lightness);
+        let colorfulness =
+            (T::one() + T::from_f64(0.0228) * color.colorfulness).ln() / T::from_f64(0.0228);
+        let (hue_sin, hue_cos) = color.hue.into_raw_radians().sin_cos();
+
+        Cam16Ucs {
+            lightness,
+            a: colorfulness.clone() * hue_cos,
+            b: colorfulness * hue_sin,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> Cam16Ucs<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Exp
+        + Powf
+        + Sqrt
+        + Hypot
+        + Trigonometry
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    /// Calculate the [`Cam16`] color with these CAM16-UCS coordinates, in the
+    /// given viewing conditions.
+    #[must_use]
+    pub fn into_cam16(self, conditions: impl Into<BakedViewingConditions<Wp, T>>) -> Cam16<Wp, T> {
+        ucs_to_cam16(self, &conditions.into())
+    }
+}
+
+fn ucs_to_cam16<Wp, T>(
+    color: Cam16Ucs<Wp, T>,
+    conditions: &BakedViewingConditions<Wp, T>,
+) -> Cam16<Wp, T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Exp
+        + Powf
+        + Sqrt
+        + Hypot
+        + Trigonometry
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let lightness =
+        color.lightness.clone() / (T::from_f64(1.7) - T::from_f64(0.007) * color.lightness);
+    let hue = Cam16Hue::from_radians(color.b.clone().atan2(color.a.clone()));
+    let colorfulness =
+        ((color.a.hypot(color.b) * T::from_f64(0.0228)).exp() - T::one()) / T::from_f64(0.0228);
+    let chroma = colorfulness / &conditions.f_l_4;
+
+    jch_to_cam16(lightness, chroma, hue, conditions)
+}
+
+///<span id="Cam16Ucsa"></span>[`Cam16Ucsa`](crate::Cam16Ucsa) implementations.
+impl<Wp, T, A> Alpha<Cam16Ucs<Wp, T>, A> {
+    /// Create a CAM16-UCS color with transparency.
+    pub const fn new(lightness: T, a: T, b: T, alpha: A) -> Self {
+        Alpha {
+            color: Cam16Ucs::new(lightness, a, b),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(J', a', b', alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.lightness, self.color.a, self.color.b, self.alpha)
+    }
+
+    /// Convert from a `(J', a', b', alpha)` tuple.
+    pub fn from_components((lightness, a, b, alpha): (T, T, T, A)) -> Self {
+        Self::new(lightness, a, b, alpha)
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam16Ucs<Wp, T>> for Cam16Ucs<Wp, T> {
+    fn from_color_unclamped(color: Cam16Ucs<Wp, T>) -> Self {
+        color
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam16<Wp, T>> for Cam16Ucs<Wp, T>
+where
+    T: Real + RealAngle + Arithmetics + Ln + Trigonometry + One + Clone,
+{
+    fn from_color_unclamped(color: Cam16<Wp, T>) -> Self {
+        Self::from_cam16(color)
+    }
+}
+
+impl<Wp, T> FromColorUnclamped<Cam16Ucs<Wp, T>> for Cam16<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Exp
+        + Powf
+        + Sqrt
+        + Cbrt
+        + Abs
+        + Hypot
+        + Trigonometry
+        + num::Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    fn from_color_unclamped(color: Cam16Ucs<Wp, T>) -> Self {
+        ucs_to_cam16(color, &ViewingConditions::default().into())
+    }
+}
+
+impl<Wp, T> From<(T, T, T)> for Cam16Ucs<Wp, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<Wp, T> From<Cam16Ucs<Wp, T>> for (T, T, T) {
+    fn from(color: Cam16Ucs<Wp, T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<Wp, T, A> From<(T, T, T, A)> for Alpha<Cam16Ucs<Wp, T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<Wp, T, A> From<Alpha<Cam16Ucs<Wp, T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<Cam16Ucs<Wp, T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_is_within_bounds! {
+    Cam16Ucs<Wp> {
+        lightness => [Self::min_lightness(), Self::max_lightness()]
+    }
+    where T: Real + Zero
+}
+
+impl<Wp, T> Clamp for Cam16Ucs<Wp, T>
+where
+    T: Zero + Real + num::Clamp,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.lightness, Self::min_lightness(), Self::max_lightness()),
+            self.a,
+            self.b,
+        )
+    }
+}
+
+impl<Wp, T> ClampAssign for Cam16Ucs<Wp, T>
+where
+    T: Zero + Real + num::ClampAssign,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(
+            &mut self.lightness,
+            Self::min_lightness(),
+            Self::max_lightness(),
+        );
+    }
+}
+
+impl_mix!(Cam16Ucs<Wp>);
+impl_lighten!(Cam16Ucs<Wp> increase {lightness => [Self::min_lightness(), Self::max_lightness()]} other {a, b} phantom: white_point);
+impl_premultiply!(Cam16Ucs<Wp> {lightness, a, b} phantom: white_point);
+
+impl<Wp, T> GetHue for Cam16Ucs<Wp, T>
+where
+    T: RealAngle + Trigonometry + Clone,
+{
+    type Hue = Cam16Hue<T>;
+
+    fn get_hue(&self) -> Cam16Hue<T> {
+        Cam16Hue::from_radians(self.b.clone().atan2(self.a.clone()))
+    }
+}
+
+/// The CAM16-UCS color difference, ΔE', as defined by Li et al.
+impl<Wp, T> ColorDifference for Cam16Ucs<Wp, T>
+where
+    T: Real + Arithmetics + Powf + Sqrt + Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_color_difference(self, other: Cam16Ucs<Wp, T>) -> Self::Scalar {
+        let lightness = self.lightness - other.lightness;
+        let a = self.a - other.a;
+        let b = self.b - other.b;
+        let distance = (lightness.clone() * lightness + a.clone() * a + b.clone() * b).sqrt();
+
+        T::from_f64(1.41) * distance.powf(T::from_f64(0.63))
+    }
+}
+
+impl<Wp, T> HasBoolMask for Cam16Ucs<Wp, T>
+where
+    T: HasBoolMask,
+{
+    type Mask = T::Mask;
+}
+
+impl<Wp, T> Default for Cam16Ucs<Wp, T>
+where
+    T: Zero,
+{
+    fn default() -> Cam16Ucs<Wp, T> {
+        Cam16Ucs::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_color_add!(Cam16Ucs<Wp, T>, [lightness, a, b], white_point);
+impl_color_sub!(Cam16Ucs<Wp, T>, [lightness, a, b], white_point);
+impl_color_mul!(Cam16Ucs<Wp, T>, [lightness, a, b], white_point);
+impl_color_div!(Cam16Ucs<Wp, T>, [lightness, a, b], white_point);
+
+impl_array_casts!(Cam16Ucs<Wp, T>, [T; 3]);
+impl_simd_array_conversion!(Cam16Ucs<Wp>, [lightness, a, b], white_point);
+
+impl_eq!(Cam16Ucs<Wp>, [lightness, a, b]);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp, T> bytemuck::Zeroable for Cam16Ucs<Wp, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<Wp: 'static, T> bytemuck::Pod for Cam16Ucs<Wp, T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        cam16::{Cam16, Cam16Ucs, Surround, ViewingConditions},
+        convert::{FromColorUnclamped, IntoColorUnclamped},
+        white_point::D65,
+        ColorDifference, Srgb,
+    };
+
+    #[test]
+    fn ranges() {
+        assert_ranges! {
+            Cam16Ucs<D65, f64>;
+            clamped {
+                lightness: 0.0 => 100.0
+            }
+            clamped_min {}
+            unclamped {
+                a: -50.0 => 50.0,
+                b: -50.0 => 50.0
+            }
+        }
+    }
+
+    #[test]
+    fn white_and_black() {
+        let white = Cam16Ucs::<D65, f64>::from_color_unclamped(Srgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white.lightness, 100.0, epsilon = 0.01);
+
+        let black = Cam16Ucs::<D65, f64>::from_color_unclamped(Srgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, Cam16Ucs::new(0.0, 0.0, 0.0));
+    }
+
+    #[test]
+    fn roundtrip() {
+        let conditions = ViewingConditions {
+            surround: Surround::Dim,
+            ..ViewingConditions::default()
+        }
+        .bake::<D65>();
+
+        let rgb = Srgb::new(0.8, 0.3, 0.5);
+        let cam16 = Cam16::from_xyz(rgb.into_linear().into_color_unclamped(), conditions);
+        let ucs = Cam16Ucs::from_cam16(cam16);
+        assert_relative_eq!(ucs.into_cam16(conditions), cam16, epsilon = 0.000001);
+
+        let back =
+            Srgb::<f64>::from_color_unclamped(Cam16Ucs::<D65, f64>::from_color_unclamped(rgb));
+        assert_relative_eq!(back, rgb, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn color_difference() {
+        let a = Cam16Ucs::<D65, f64>::new(50.0, 10.0, -5.0);
+        assert_relative_eq!(a.get_color_difference(a), 0.0);
+
+        let b = Cam16Ucs::<D65, f64>::new(53.0, 14.0, -5.0);
+        assert_relative_eq!(
+            a.get_color_difference(b),
+            1.41 * 5.0f64.powf(0.63),
+            epsilon = 0.000001
+        );
+    }
+
+    raw_pixel_conversion_tests!(Cam16Ucs<D65>: lightness, a, b);
+    raw_pixel_conversion_fail_tests!(Cam16Ucs<D65>: lightness, a, b);
+}
@@ -0,0 +1,281 @@
+use core::marker::PhantomData;
+
+use crate::{
+    bool_mask::LazySelect,
+    chromatic_adaptation,
+    matrix::multiply_xyz,
+    num::{Abs, Arithmetics, Cbrt, Clamp, Exp, One, PartialCmp, Powf, Real, Sqrt, Zero},
+    white_point::WhitePoint,
+    Xyz,
+};
+
+use super::{compress, m16};
+
+/// The conditions under which a color is viewed.
+///
+/// These are the inputs to [`Cam16`](crate::Cam16) that describe the
+/// surroundings of a color. The appearance of the same color stimulus may
+/// change a lot with them, so the same conditions have to be used when
+/// converting to and from [`Cam16`](crate::Cam16).
+///
+/// The default conditions describe a display with a white luminance of 200
+/// cd/m², viewed against a mid gray (L\* = 50) background, in an average
+/// surround.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ViewingConditions<T> {
+    /// The luminance of the adapting field, `L_A`, in cd/m². It's often
+    /// approximated as 20% of the luminance of a white object in the scene.
+    pub adapting_luminance: T,
+
+    /// The relative luminance of the background, `Y_b`, where 1.0 is the
+    /// luminance of the reference white.
+    pub background_luminance: T,
+
+    /// The relative luminance of the surrounding field.
+    pub surround: Surround,
+
+    /// How much the visual system has adapted to the reference white.
+    pub degree_of_adaptation: DegreeOfAdaptation<T>,
+}
+
+impl<T> ViewingConditions<T> {
+    /// Pre-calculate the parameters that only depend on the viewing
+    /// conditions and the white point.
+    ///
+    /// The result can be reused for converting any number of colors with the
+    /// same conditions.
+    ///
+    /// ```
+    /// use palette::{
+    ///     cam16::ViewingConditions,
+    ///     white_point::D65,
+    ///     Cam16, Xyz,
+    /// };
+    ///
+    /// let conditions = ViewingConditions::<f32>::default().bake::<D65>();
+    /// let cam16 = Cam16::from_xyz(Xyz::new(0.3, 0.4, 0.5), conditions);
+    /// let xyz = cam16.into_xyz(conditions);
+    /// ```
+    #[must_use]
+    pub fn bake<Wp>(self) -> BakedViewingConditions<Wp, T>
+    where
+        Wp: WhitePoint<T>,
+        T: Real
+            + Arithmetics
+            + Powf
+            + Sqrt
+            + Cbrt
+            + Exp
+            + Abs
+            + Clamp
+            + Zero
+            + One
+            + PartialCmp
+            + Clone,
+        T::Mask: LazySelect<T>,
+    {
+        BakedViewingConditions::new(self)
+    }
+}
+
+impl<T> Default for ViewingConditions<T>
+where
+    T: Real,
+{
+    fn default() -> Self {
+        // The relative luminance of L* = 50.
+        let mid_gray = (66.0 / 116.0) * (66.0 / 116.0) * (66.0 / 116.0);
+
+        ViewingConditions {
+            adapting_luminance: T::from_f64(200.0 / core::f64::consts::PI * mid_gray),
+            background_luminance: T::from_f64(mid_gray),
+            surround: Surround::Average,
+            degree_of_adaptation: DegreeOfAdaptation::Computed,
+        }
+    }
+}
+
+/// The relative luminance of the field surrounding the viewed scene.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Surround {
+    /// Viewing surface colors, or a display in a lit room.
+    Average,
+    /// Viewing a television or a display in a dim room.
+    Dim,
+    /// Viewing a projector or a display in a dark room.
+    Dark,
+}
+
+impl Surround {
+    /// Get the `(F, c, N_c)` factors, as defined by CIECAM02 and CAM16.
+    pub(crate) fn factors<T: Real>(self) -> (T, T, T) {
+        let (f, c, n_c) = match self {
+            Surround::Average => (1.0, 0.69, 1.0),
+            Surround::Dim => (0.9, 0.59, 0.9),
+            Surround::Dark => (0.8, 0.525, 0.8),
+        };
+
+        (T::from_f64(f), T::from_f64(c), T::from_f64(n_c))
+    }
+}
+
+/// How much the visual system has adapted to the reference white, also known
+/// as `D`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DegreeOfAdaptation<T> {
+    /// Calculate the degree of adaptation from the adapting luminance and the
+    /// surround.
+    Computed,
+
+    /// The illuminant is completely discounted, such as when viewing surface
+    /// colors under a known light source. Equivalent to `Custom(1.0)`.
+    Discounted,
+
+    /// A custom degree of adaptation, from 0.0 (no adaptation) to 1.0
+    /// (complete adaptation).
+    Custom(T),
+}
+
+/// Pre-calculated [`ViewingConditions`] for a specific white point.
+///
+/// Created with [`ViewingConditions::bake`] or `From<ViewingConditions>`.
+#[derive(Debug)]
+pub struct BakedViewingConditions<Wp, T> {
+    pub(crate) f_l: T,
+    pub(crate) f_l_4: T,
+    pub(crate) z: T,
+    pub(crate) n_bb: T,
+    pub(crate) c: T,
+    pub(crate) n_c: T,
+    pub(crate) d_rgb: [T; 3],
+    pub(crate) a_w: T,
+    pub(crate) chroma_factor: T,
+    white_point: PhantomData<Wp>,
+}
+
+impl<Wp, T> Copy for BakedViewingConditions<Wp, T> where T: Copy {}
+
+impl<Wp, T> Clone for BakedViewingConditions<Wp, T>
+where
+    T: Clone,
+{
+    fn clone(&self) -> Self {
+        BakedViewingConditions {
+            f_l: self.f_l.clone(),
+            f_l_4: self.f_l_4.clone(),
+            z: self.z.clone(),
+            n_bb: self.n_bb.clone(),
+            c: self.c.clone(),
+            n_c: self.n_c.clone(),
+            d_rgb: self.d_rgb.clone(),
+            a_w: self.a_w.clone(),
+            chroma_factor: self.chroma_factor.clone(),
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> BakedViewingConditions<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + Arithmetics
+        + Powf
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    fn new(conditions: ViewingConditions<T>) -> Self {
+        let ViewingConditions {
+            adapting_luminance,
+            background_luminance,
+            surround,
+            degree_of_adaptation,
+        } = conditions;
+
+        let (_, c, n_c) = surround.factors::<T>();
+
+        let white = Wp::get_xyz() * T::from_f64(100.0);
+        let Xyz {
+            x: r_w,
+            y: g_w,
+            z: b_w,
+            ..
+        } = multiply_xyz::<T>(m16(), white.clone());
+
+        let d = match degree_of_adaptation {
+            DegreeOfAdaptation::Computed => {
+                chromatic_adaptation::degree_of_adaptation(adapting_luminance.clone(), surround)
+            }
+            DegreeOfAdaptation::Discounted => T::one(),
+            DegreeOfAdaptation::Custom(d) => d.clamp(T::zero(), T::one()),
+        };
+
+        let adapt = |component: T| d.clone() * &white.y / component + T::one() - &d;
+        let d_rgb = [adapt(r_w.clone()), adapt(g_w.clone()), adapt(b_w.clone())];
+
+        let five_l_a = T::from_f64(5.0) * adapting_luminance;
+        let k = T::one() / (five_l_a.clone() + T::one());
+        let k4 = k.clone() * &k * &k * k;
+        let k4_inv = T::one() - &k4;
+        let f_l = T::from_f64(0.2) * k4 * &five_l_a
+            + T::from_f64(0.1) * k4_inv.clone() * k4_inv * five_l_a.cbrt();
+        let f_l_4 = f_l.clone().powf(T::from_f64(0.25));
+
+        let n = background_luminance * T::from_f64(100.0) / &white.y;
+        let z = T::from_f64(1.48) + n.clone().sqrt();
+        let n_bb = T::from_f64(0.725) * n.clone().powf(T::from_f64(-0.2));
+
+        let [d_r, d_g, d_b] = d_rgb.clone();
+        let r_aw = compress(r_w * d_r, &f_l);
+        let g_aw = compress(g_w * d_g, &f_l);
+        let b_aw = compress(b_w * d_b, &f_l);
+        let a_w = (T::from_f64(2.0) * r_aw + g_aw + T::from_f64(0.05) * b_aw) * &n_bb;
+
+        let chroma_factor =
+            (T::from_f64(1.64) - T::from_f64(0.29).powf(n.clone())).powf(T::from_f64(0.73));
+
+        BakedViewingConditions {
+            f_l,
+            f_l_4,
+            z,
+            n_bb,
+            c,
+            n_c,
+            d_rgb,
+            a_w,
+            chroma_factor,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> From<ViewingConditions<T>> for BakedViewingConditions<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + Arithmetics
+        + Powf
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from(conditions: ViewingConditions<T>) -> Self {
+        Self::new(conditions)
+    }
+}
@@ -0,0 +1,411 @@
+//! Correlated color temperature (CCT).
+//!
+//! The color of a light source is often described with its correlated color
+//! temperature, in kelvin, and its distance from the Planckian locus, `Duv`.
+//! The functions in this module convert between them and chromaticities:
+//!
+//! ```
+//! use palette::{
+//!     cct,
+//!     convert::FromColorUnclamped,
+//!     white_point::{WhitePoint, D65},
+//!     Yxy,
+//! };
+//!
+//! // The white of a 5600 K light.
+//! let white = cct::planckian_locus(5600.0f64);
+//!
+//! // The CCT and Duv of D65.
+//! let (temperature, duv) = cct::from_chromaticity(Yxy::<_, f64>::from_color_unclamped(D65::get_xyz()))
+//!     .unwrap();
+//! assert!((temperature - 6504.0).abs() < 2.0);
+//! assert!((duv - 0.0032).abs() < 0.0002);
+//! ```
+//!
+//! The Planckian locus is approximated with the method by Krystek (1985),
+//! which stays within about 1e-4 of the exact locus in CIE 1960 `uv`
+//! coordinates, relative to the CIE 1931 observer, from 1000 K to 15000 K. The
+//! inverse uses Robertson's method (1968), and is limited to temperatures from
+//! about 1667 K to 15000 K.
+
+use crate::{
+    bool_mask::HasBoolMask,
+    convert::FromColorUnclamped,
+    num::{Arithmetics, MinMax, One, PartialCmp, Real, Sqrt, Zero},
+    white_point::{Any, D65},
+    LinSrgb, Xyz, Yxy,
+};
+
+/// Get the chromaticity of a black body radiator, at the temperature `cct` in
+/// kelvin.
+///
+/// The returned chromaticity has a luminance of 1.0. Temperatures outside of
+/// 1000 K to 15000 K are extrapolated and drift away from the actual locus.
+#[must_use]
+pub fn planckian_locus<T>(cct: T) -> Yxy<Any, T>
+where
+    T: Real + Zero + One + Sqrt + Arithmetics + Clone,
+{
+    planckian_locus_with_duv(cct, T::zero())
+}
+
+/// Get the chromaticity at the distance `duv` from the Planckian locus, at the
+/// temperature `cct` in kelvin.
+///
+/// The distance is measured in the CIE 1960 UCS, perpendicular to the locus,
+/// and is positive above the locus (towards green) and negative below it
+/// (towards magenta). The returned chromaticity has a luminance of 1.0.
+#[must_use]
+pub fn planckian_locus_with_duv<T>(cct: T, duv: T) -> Yxy<Any, T>
+where
+    T: Real + Zero + One + Sqrt + Arithmetics + Clone,
+{
+    let (u, v, du, dv) = planckian_uv(cct);
+
+    // The normal that points above the locus, since u decreases with the
+    // temperature.
+    let length = (du.clone() * &du + dv.clone() * &dv).sqrt();
+    let u = u + duv.clone() * dv / &length;
+    let v = v - duv * du / length;
+
+    uv_to_yxy(u, v)
+}
+
+/// Get the chromaticity of CIE daylight, at the temperature `cct` in kelvin.
+///
+/// This is the locus of the CIE D series of illuminants, and is defined from
+/// 4000 K to 25000 K. The returned chromaticity has a luminance of 1.0.
+///
+/// The CCT of the standard illuminants are based on older physical constants,
+/// so D65 is found at 6504 K, rather than 6500 K.
+#[must_use]
+pub fn daylight_locus<T>(cct: T) -> Yxy<Any, T>
+where
+    T: Real + One + PartialCmp + HasBoolMask<Mask = bool> + Arithmetics + Clone,
+{
+    let reciprocal = T::one() / cct.clone();
+    let reciprocal2 = reciprocal.clone() * &reciprocal;
+    let reciprocal3 = reciprocal2.clone() * &reciprocal;
+
+    let x = if cct.lt_eq(&T::from_f64(7000.0)) {
+        T::from_f64(-4.6070e9) * reciprocal3
+            + T::from_f64(2.9678e6) * reciprocal2
+            + T::from_f64(0.09911e3) * reciprocal
+            + T::from_f64(0.244063)
+    } else {
+        T::from_f64(-2.0064e9) * reciprocal3
+            + T::from_f64(1.9018e6) * reciprocal2
+            + T::from_f64(0.24748e3) * reciprocal
+            + T::from_f64(0.237040)
+    };
+    let y = T::from_f64(-3.0) * x.clone() * &x + T::from_f64(2.870) * &x - T::from_f64(0.275);
+
+    Yxy::new(x, y, T::one())
+}
+
+/// Estimate the correlated color temperature, in kelvin, and the distance
+/// from the Planckian locus, `Duv`, of a chromaticity.
+///
+/// The temperature is found with Robertson's method, which interpolates
+/// between the isotemperature lines from 0 to 600 mired. The luminance is
+/// ignored.
+///
+/// Returns `None` if the result would be meaningless, which is when:
+///
+/// * the chromaticity is outside of the isotemperature lines, such as below
+///   about 1667 K or bluer than the 0 mired line,
+/// * the temperature is above 15000 K, where the approximated Planckian locus
+///   is no longer accurate, or
+/// * the chromaticity is more than 0.05 away from the Planckian locus, which is
+///   the limit where CIE 15 considers the correlated color temperature to be
+///   meaningful.
+///
+/// ```
+/// use palette::{cct, white_point::Any, Yxy};
+///
+/// let illuminant_a = Yxy::<Any, f64>::new(0.44757, 0.40745, 1.0);
+/// let (temperature, duv) = cct::from_chromaticity(illuminant_a).unwrap();
+/// assert!((temperature - 2856.0).abs() < 1.0);
+/// assert!(duv.abs() < 0.0005);
+///
+/// let purple = Yxy::<Any, f64>::new(0.24, 0.23, 1.0);
+/// assert_eq!(cct::from_chromaticity(purple), None);
+/// ```
+#[must_use]
+pub fn from_chromaticity<Wp, T>(chromaticity: Yxy<Wp, T>) -> Option<(T, T)>
+where
+    T: Real + Zero + One + Sqrt + PartialCmp + HasBoolMask<Mask = bool> + Arithmetics + Clone,
+{
+    let (u, v) = yxy_to_uv(chromaticity.x, chromaticity.y);
+
+    let mut previous_reciprocal = T::from_f64(ROBERTSON_TABLE[0].0);
+    let mut previous_distance = T::zero();
+    let mut reciprocal_temperature = None;
+
+    for (index, &(reciprocal, u_i, v_i, slope)) in ROBERTSON_TABLE.iter().enumerate() {
+        let (u_i, v_i, slope) = (T::from_f64(u_i), T::from_f64(v_i), T::from_f64(slope));
+        let reciprocal = T::from_f64(reciprocal);
+
+        let distance = ((v.clone() - v_i) - slope.clone() * (u.clone() - u_i))
+            / (T::one() + slope.clone() * slope).sqrt();
+
+        let crossed = distance.lt_eq(&T::zero()) != previous_distance.lt_eq(&T::zero());
+        if index > 0 && (crossed || distance.eq(&T::zero())) {
+            let factor = previous_distance.clone() / (previous_distance - distance);
+            reciprocal_temperature =
+                Some(previous_reciprocal.clone() + factor * (reciprocal - previous_reciprocal));
+            break;
+        }
+
+        previous_reciprocal = reciprocal;
+        previous_distance = distance;
+    }
+
+    let cct = T::from_f64(1e6) / reciprocal_temperature?;
+    if cct.gt(&T::from_f64(MAX_CCT)) {
+        return None;
+    }
+
+    let (u_p, v_p, _, _) = planckian_uv(cct.clone());
+    let delta_u = u - u_p;
+    let delta_v = v - &v_p;
+    let distance = (delta_u.clone() * delta_u + delta_v.clone() * &delta_v).sqrt();
+    if distance.gt(&T::from_f64(MAX_DUV)) {
+        return None;
+    }
+
+    let duv = if delta_v.lt(&T::zero()) {
+        -distance
+    } else {
+        distance
+    };
+
+    Some((cct, duv))
+}
+
+/// Get the color of a black body radiator, at the temperature `cct` in
+/// kelvin, as linear sRGB.
+///
+/// The color is normalized to make its largest component 1.0. Temperatures
+/// that are too warm for sRGB have their negative components clamped to 0.0.
+///
+/// ```
+/// use palette::cct;
+///
+/// let candle = cct::to_linear_srgb(1900.0f32);
+/// assert_eq!(candle.red, 1.0);
+/// ```
+#[must_use]
+pub fn to_linear_srgb<T>(cct: T) -> LinSrgb<T>
+where
+    T: Real + Zero + One + Sqrt + MinMax + Arithmetics + Clone,
+    LinSrgb<T>: FromColorUnclamped<Xyz<D65, T>>,
+{
+    let Yxy { x, y, .. } = planckian_locus(cct);
+    let xyz = Xyz::new(x.clone() / &y, T::one(), (T::one() - x - &y) / y);
+
+    let rgb = LinSrgb::from_color_unclamped(xyz);
+    let red = rgb.red.max(T::zero());
+    let green = rgb.green.max(T::zero());
+    let blue = rgb.blue.max(T::zero());
+
+    let max = red.clone().max(green.clone()).max(blue.clone());
+    LinSrgb::new(red / &max, green / &max, blue / max)
+}
+
+/// Krystek's approximation of the Planckian locus, in CIE 1960 UCS. Returns
+/// `(u, v, du/dT, dv/dT)`.
+fn planckian_uv<T>(cct: T) -> (T, T, T, T)
+where
+    T: Real + One + Arithmetics + Clone,
+{
+    let t = cct;
+    let t2 = t.clone() * &t;
+
+    let u_num = T::from_f64(0.860117757)
+        + T::from_f64(1.54118254e-4) * &t
+        + T::from_f64(1.28641212e-7) * &t2;
+    let u_den = T::one() + T::from_f64(8.42420235e-4) * &t + T::from_f64(7.08145163e-7) * &t2;
+    let v_num = T::from_f64(0.317398726)
+        + T::from_f64(4.22806245e-5) * &t
+        + T::from_f64(4.20481691e-8) * &t2;
+    let v_den = T::one() - T::from_f64(2.89741816e-5) * &t + T::from_f64(1.61456053e-7) * t2;
+
+    let du_num = T::from_f64(1.54118254e-4) + T::from_f64(2.0 * 1.28641212e-7) * &t;
+    let du_den = T::from_f64(8.42420235e-4) + T::from_f64(2.0 * 7.08145163e-7) * &t;
+    let dv_num = T::from_f64(4.22806245e-5) + T::from_f64(2.0 * 4.20481691e-8) * &t;
+    let dv_den = T::from_f64(-2.89741816e-5) + T::from_f64(2.0 * 1.61456053e-7) * t;
+
+    let du = (du_num * &u_den - u_num.clone() * du_den) / (u_den.clone() * &u_den);
+    let dv = (dv_num * &v_den - v_num.clone() * dv_den) / (v_den.clone() * &v_den);
+
+    (u_num / u_den, v_num / v_den, du, dv)
+}
+
+fn uv_to_yxy<T>(u: T, v: T) -> Yxy<Any, T>
+where
+    T: Real + One + Arithmetics + Clone,
+{
+    let denominator = T::from_f64(2.0) * &u - T::from_f64(8.0) * &v + T::from_f64(4.0);
+    let x = T::from_f64(3.0) * u / &denominator;
+    let y = T::from_f64(2.0) * v / denominator;
+
+    Yxy::new(x, y, T::one())
+}
+
+fn yxy_to_uv<T>(x: T, y: T) -> (T, T)
+where
+    T: Real + Arithmetics + Clone,
+{
+    let denominator = T::from_f64(-2.0) * &x + T::from_f64(12.0) * &y + T::from_f64(3.0);
+    let u = T::from_f64(4.0) * x / &denominator;
+    let v = T::from_f64(6.0) * y / denominator;
+
+    (u, v)
+}
+
+/// The highest temperature where Krystek's approximation is accurate.
+const MAX_CCT: f64 = 15000.0;
+
+/// The largest distance from the Planckian locus where the correlated color
+/// temperature is meaningful, according to CIE 15.
+const MAX_DUV: f64 = 0.05;
+
+/// The isotemperature lines from Robertson's method, as `(10^6 / T, u, v,
+/// slope)`.
+#[rustfmt::skip]
+const ROBERTSON_TABLE: [(f64, f64, f64, f64); 31] = [
+    (0.0, 0.18006, 0.26352, -0.24341),
+    (10.0, 0.18066, 0.26589, -0.25479),
+    (20.0, 0.18133, 0.26846, -0.26876),
+    (30.0, 0.18208, 0.27119, -0.28539),
+    (40.0, 0.18293, 0.27407, -0.30470),
+    (50.0, 0.18388, 0.27709, -0.32675),
+    (60.0, 0.18494, 0.28021, -0.35156),
+    (70.0, 0.18611, 0.28342, -0.37915),
+    (80.0, 0.18740, 0.28668, -0.40955),
+    (90.0, 0.18880, 0.28997, -0.44278),
+    (100.0, 0.19032, 0.29326, -0.47888),
+    (125.0, 0.19462, 0.30141, -0.58204),
+    (150.0, 0.19962, 0.30921, -0.70471),
+    (175.0, 0.20525, 0.31647, -0.84901),
+    (200.0, 0.21142, 0.32312, -1.0182),
+    (225.0, 0.21807, 0.32909, -1.2168),
+    (250.0, 0.22511, 0.33439, -1.4512),
+    (275.0, 0.23247, 0.33904, -1.7298),
+    (300.0, 0.24010, 0.34308, -2.0637),
+    (325.0, 0.24792, 0.34655, -2.4681),
+    (350.0, 0.25591, 0.34951, -2.9641),
+    (375.0, 0.26400, 0.35200, -3.5814),
+    (400.0, 0.27218, 0.35407, -4.3633),
+    (425.0, 0.28039, 0.35577, -5.3762),
+    (450.0, 0.28863, 0.35714, -6.7262),
+    (475.0, 0.29685, 0.35823, -8.5955),
+    (500.0, 0.30505, 0.35907, -11.324),
+    (525.0, 0.31320, 0.35968, -15.628),
+    (550.0, 0.32129, 0.36011, -23.325),
+    (575.0, 0.32931, 0.36038, -40.770),
+    (600.0, 0.33724, 0.36051, -116.45),
+];
+
+#[cfg(test)]
+mod test {
+    use super::{
+        daylight_locus, from_chromaticity, planckian_locus, planckian_locus_with_duv,
+        to_linear_srgb,
+    };
+    use crate::{
+        convert::FromColorUnclamped,
+        white_point::{Any, WhitePoint, A, D50, D65},
+        Yxy,
+    };
+
+    #[test]
+    fn planckian_locus_values() {
+        let a = planckian_locus(2856.0f64);
+        assert_relative_eq!(a.x, 0.44757, epsilon = 0.0003);
+        assert_relative_eq!(a.y, 0.40745, epsilon = 0.0004);
+
+        let white = planckian_locus(6500.0f64);
+        assert_relative_eq!(white.x, 0.3135, epsilon = 0.0001);
+        assert_relative_eq!(white.y, 0.3236, epsilon = 0.0002);
+    }
+
+    #[test]
+    fn daylight_locus_values() {
+        let d65 = daylight_locus(6504.0f64);
+        assert_relative_eq!(d65.x, 0.31271, epsilon = 0.00002);
+        assert_relative_eq!(d65.y, 0.32902, epsilon = 0.0001);
+
+        let d50 = daylight_locus(5003.0f64);
+        assert_relative_eq!(d50.x, 0.34567, epsilon = 0.00002);
+        assert_relative_eq!(d50.y, 0.35850, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn standard_illuminants() {
+        let (cct, duv) =
+            from_chromaticity(Yxy::<_, f64>::from_color_unclamped(A::get_xyz())).unwrap();
+        assert_relative_eq!(cct, 2856.0, epsilon = 1.0);
+        assert_relative_eq!(duv, 0.0, epsilon = 0.0002);
+
+        let (cct, duv) =
+            from_chromaticity(Yxy::<_, f64>::from_color_unclamped(D50::get_xyz())).unwrap();
+        assert_relative_eq!(cct, 5003.0, epsilon = 2.0);
+        assert_relative_eq!(duv, 0.0033, epsilon = 0.0001);
+
+        let (cct, duv) =
+            from_chromaticity(Yxy::<_, f64>::from_color_unclamped(D65::get_xyz())).unwrap();
+        assert_relative_eq!(cct, 6504.0, epsilon = 2.0);
+        assert_relative_eq!(duv, 0.0032, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &cct in &[1800.0f64, 2700.0, 4000.0, 5600.0, 6500.0, 9000.0, 12000.0] {
+            for &duv in &[-0.02f64, 0.0, 0.01] {
+                let chromaticity = planckian_locus_with_duv(cct, duv);
+                let (cct2, duv2) = from_chromaticity(chromaticity).unwrap();
+
+                // Robertson's method interpolates between isotemperature
+                // lines, which gets less precise away from the locus.
+                assert_relative_eq!(cct2, cct, max_relative = 0.01);
+                assert_relative_eq!(duv2, duv, epsilon = 0.0002);
+            }
+        }
+    }
+
+    #[test]
+    fn out_of_range() {
+        assert_eq!(from_chromaticity(planckian_locus(1000.0f64)), None);
+        assert_eq!(from_chromaticity(planckian_locus(100000.0f64)), None);
+
+        // Bluer than the 0 mired isotemperature line.
+        assert_eq!(from_chromaticity(Yxy::<Any, f64>::new(0.2, 0.2, 1.0)), None);
+
+        // Crosses the isotemperature lines, but far below the locus.
+        assert_eq!(
+            from_chromaticity(Yxy::<Any, f64>::new(0.24, 0.23, 1.0)),
+            None
+        );
+        assert_eq!(
+            from_chromaticity(planckian_locus_with_duv(4000.0f64, -0.06)),
+            None
+        );
+    }
+
+    #[test]
+    fn linear_srgb() {
+        let warm = to_linear_srgb(2700.0f64);
+        assert_relative_eq!(warm.red, 1.0);
+        assert!(warm.green < 1.0 && warm.blue < warm.green);
+
+        let cold = to_linear_srgb(12000.0f64);
+        assert_relative_eq!(cold.blue, 1.0);
+        assert!(cold.red < cold.green);
+
+        // The Planckian locus passes slightly below D65.
+        let white = to_linear_srgb(6504.0f64);
+        assert!(white.red > 0.9 && white.green > 0.9 && white.blue > 0.9);
+    }
+}
@@ -6,8 +6,11 @@
 //! despite the wide variation of light which might be reflected from an object
 //! and observed by our eyes.
 //!
-//! This library provides three methods for chromatic adaptation Bradford (which
-//! is the default), VonKries and XyzScaling
+//! This library provides seven methods for chromatic adaptation: Bradford
+//! (which is the default), VonKries, XyzScaling, Cat02, Cat16, Sharp and
+//! Cmccat2000. The adaptation can also be incomplete, with a degree of
+//! adaptation from 0.0 to 1.0, as when the visual system has only partially
+//! adapted to the light source.
 //!
 //! ```
 //! use palette::Xyz;
@@ -23,13 +26,48 @@
 //! //Should print {x: 0.257963, y: 0.139776,z: 0.058825}
 //! println!("{:?}", c)
 //! ```
+//!
+//! Regular conversions from [`Rgb`] to [`Xyz`] or [`Lab`](crate::Lab), and
+//! back, insert the adaptation step automatically when the white points are
+//! different, using the Bradford method. See [`ConvertFromWhitePoint`] for how
+//! it's done. Conversions between `Xyz` or `Lab` colors keep their white
+//! point, and are adapted with [`AdaptFrom`], [`AdaptInto`] or an
+//! [`Adaptation`]. An `Adaptation` can also be used to pick another method for
+//! RGB:
+//!
+//! ```
+//! use palette::{
+//!     chromatic_adaptation::{Adaptation, Method},
+//!     rgb::{DisplayP3Rgb, ProPhotoRgb},
+//!     white_point::{D50, D65},
+//!     FromColor, Lab, Xyz,
+//! };
+//!
+//! let p3 = DisplayP3Rgb::new(0.8f32, 0.3, 0.1);
+//!
+//! // D65 to D50, with the Bradford method.
+//! let prophoto = ProPhotoRgb::from_color(p3);
+//! let xyz = Xyz::<D50, f32>::from_color(p3);
+//! let lab = Lab::<D50, f32>::from_color(p3);
+//!
+//! // D65 to D50, with the Cat16 method.
+//! let lab: Lab<D50, f32> = Adaptation::<D65, D50, f32>::new(Method::Cat16).convert(p3);
+//! ```
+//!
+//! White points that are only known at runtime, such as an estimated scene
+//! illuminant, can be adapted between with a [`DynamicAdaptation`].
+
+use core::marker::PhantomData;
 
 use crate::{
+    cam16::Surround,
     convert::{FromColorUnclamped, IntoColorUnclamped},
-    matrix::{multiply_3x3, multiply_xyz, Mat3},
-    num::{Arithmetics, Real, Zero},
-    white_point::{Any, WhitePoint},
-    Xyz,
+    encoding::Linear,
+    matrix::{matrix_inverse, multiply_3x3, multiply_rgb, multiply_xyz, rgb_to_xyz_matrix, Mat3},
+    num::{Arithmetics, Clamp, Exp, FromScalar, IsValidDivisor, One, Real, Recip, Zero},
+    rgb::{Primaries, Rgb, RgbSpace},
+    white_point::{self, Any, HasWhitePoint, WhitePoint},
+    Xyz, Yxy,
 };
 
 /// Chromatic adaptation methods implemented in the library
@@ -40,6 +78,14 @@ pub enum Method {
     VonKries,
     /// XyzScaling chromatic adaptation method
     XyzScaling,
+    /// CAT02 chromatic adaptation method, from CIECAM02
+    Cat02,
+    /// CAT16 chromatic adaptation method, from CAM16
+    Cat16,
+    /// Sharp chromatic adaptation method
+    Sharp,
+    /// CMCCAT2000 chromatic adaptation method
+    Cmccat2000,
 }
 
 /// Holds the matrix coefficients for the chromatic adaptation methods
@@ -83,8 +129,84 @@ where
         let tmp = multiply_3x3(resp, adapt.ma);
         multiply_3x3(adapt.inv_ma, tmp)
     }
+
+    /// Generates a 3x3 transformation matrix to convert color from one
+    /// reference white point to another with the given cone_response, with
+    /// incomplete adaptation
+    ///
+    /// The `degree_of_adaptation`, also known as `D`, goes from 0.0 (no
+    /// adaptation) to 1.0 (complete adaptation). It can be calculated from the
+    /// viewing conditions with [`degree_of_adaptation`].
+    #[must_use]
+    fn generate_partial_transform_matrix(
+        &self,
+        source_wp: Xyz<Any, T>,
+        destination_wp: Xyz<Any, T>,
+        degree_of_adaptation: T,
+    ) -> Mat3<T>
+    where
+        T: One,
+    {
+        let adapt = self.get_cone_response();
+
+        let resp_src = multiply_xyz(adapt.ma.clone(), source_wp);
+        let resp_dst = multiply_xyz(adapt.ma.clone(), destination_wp);
+
+        let d = degree_of_adaptation;
+        let gain = |dst: T, src: T| d.clone() * dst / src + T::one() - &d;
+
+        #[rustfmt::skip]
+        let resp = [
+            gain(resp_dst.x, resp_src.x), T::zero(), T::zero(),
+            T::zero(), gain(resp_dst.y, resp_src.y), T::zero(),
+            T::zero(), T::zero(), gain(resp_dst.z, resp_src.z),
+        ];
+
+        let tmp = multiply_3x3(resp, adapt.ma);
+        multiply_3x3(adapt.inv_ma, tmp)
+    }
 }
 
+/// Calculate the degree of adaptation, `D`, from the adapting luminance in
+/// cd/m² and the surround, as defined by CIECAM02 and CAM16.
+///
+/// The result goes from 0.0 (no adaptation) to 1.0 (complete adaptation), and
+/// can be used with [`TransformMatrix::generate_partial_transform_matrix`],
+/// [`AdaptFrom::adapt_from_partially_using`] and
+/// [`AdaptInto::adapt_into_partially_using`].
+///
+/// ```
+/// use palette::{cam16::Surround, chromatic_adaptation::degree_of_adaptation};
+///
+/// let d = degree_of_adaptation(318.31f64, Surround::Average);
+/// assert!((d - 0.9945).abs() < 0.0001);
+/// ```
+#[must_use]
+pub fn degree_of_adaptation<T>(adapting_luminance: T, surround: Surround) -> T
+where
+    T: Real + Zero + One + Exp + Clamp + Arithmetics,
+{
+    let (f, _, _) = surround.factors::<T>();
+    let exponent = (-adapting_luminance - T::from_f64(42.0)) / T::from_f64(92.0);
+    let d = f * (T::one() - T::from_f64(1.0 / 3.6) * exponent.exp());
+
+    d.clamp(T::zero(), T::one())
+}
+
+#[rustfmt::skip]
+const BRADFORD_MA: Mat3<f64> = [
+    0.8951000, 0.2664000, -0.1614000,
+    -0.7502000, 1.7135000, 0.0367000,
+    0.0389000, -0.0685000, 1.0296000,
+];
+
+#[rustfmt::skip]
+const BRADFORD_INV_MA: Mat3<f64> = [
+    0.9869929, -0.1470543, 0.1599627,
+    0.4323053, 0.5183603, 0.0492912,
+    -0.0085287, 0.0400428, 0.9684867,
+];
+
 impl<T> TransformMatrix<T> for Method
 where
     T: Real + Zero + Arithmetics + Clone,
@@ -95,16 +217,8 @@ where
         match *self {
              Method::Bradford => {
                 ConeResponseMatrices::<T> {
-                    ma: [
-                        T::from_f64(0.8951000), T::from_f64(0.2664000), T::from_f64(-0.1614000),
-                        T::from_f64(-0.7502000), T::from_f64(1.7135000), T::from_f64(0.0367000),
-                        T::from_f64(0.0389000), T::from_f64(-0.0685000), T::from_f64(1.0296000)
-                    ],
-                    inv_ma: [
-                        T::from_f64(0.9869929), T::from_f64(-0.1470543), T::from_f64(0.1599627),
-                        T::from_f64(0.4323053), T::from_f64(0.5183603), T::from_f64(0.0492912),
-                        T::from_f64(-0.0085287), T::from_f64(0.0400428), T::from_f64(0.9684867)
-                    ],
+                    ma: BRADFORD_MA.map(T::from_f64),
+                    inv_ma: BRADFORD_INV_MA.map(T::from_f64),
                 }
             }
              Method::VonKries => {
@@ -135,6 +249,62 @@ where
                     ],
                 }
             }
+             Method::Cat02 => {
+                ConeResponseMatrices::<T> {
+                    ma: [
+                        T::from_f64(0.7328000), T::from_f64(0.4296000), T::from_f64(-0.1624000),
+                        T::from_f64(-0.7036000), T::from_f64(1.6975000), T::from_f64(0.0061000),
+                        T::from_f64(0.0030000), T::from_f64(0.0136000), T::from_f64(0.9834000)
+                    ],
+                    inv_ma: [
+                        T::from_f64(1.0961238), T::from_f64(-0.2788690), T::from_f64(0.1827452),
+                        T::from_f64(0.4543690), T::from_f64(0.4735332), T::from_f64(0.0720978),
+                        T::from_f64(-0.0096276), T::from_f64(-0.0056980), T::from_f64(1.0153256)
+                    ],
+                }
+            }
+             Method::Cat16 => {
+                ConeResponseMatrices::<T> {
+                    ma: [
+                        T::from_f64(0.4012880), T::from_f64(0.6501730), T::from_f64(-0.0514610),
+                        T::from_f64(-0.2502680), T::from_f64(1.2044140), T::from_f64(0.0458540),
+                        T::from_f64(-0.0020790), T::from_f64(0.0489520), T::from_f64(0.9531270)
+                    ],
+                    inv_ma: [
+                        T::from_f64(1.8620679), T::from_f64(-1.0112546), T::from_f64(0.1491868),
+                        T::from_f64(0.3875265), T::from_f64(0.6214474), T::from_f64(-0.0089740),
+                        T::from_f64(-0.0158415), T::from_f64(-0.0341229), T::from_f64(1.0499644)
+                    ],
+                }
+            }
+             Method::Sharp => {
+                ConeResponseMatrices::<T> {
+                    ma: [
+                        T::from_f64(1.2694000), T::from_f64(-0.0988000), T::from_f64(-0.1706000),
+                        T::from_f64(-0.8364000), T::from_f64(1.8006000), T::from_f64(0.0357000),
+                        T::from_f64(0.0297000), T::from_f64(-0.0315000), T::from_f64(1.0018000)
+                    ],
+                    inv_ma: [
+                        T::from_f64(0.8156333), T::from_f64(0.0471548), T::from_f64(0.1372166),
+                        T::from_f64(0.3791144), T::from_f64(0.5769424), T::from_f64(0.0440009),
+                        T::from_f64(-0.0122601), T::from_f64(0.0167431), T::from_f64(0.9955188)
+                    ],
+                }
+            }
+             Method::Cmccat2000 => {
+                ConeResponseMatrices::<T> {
+                    ma: [
+                        T::from_f64(0.7982000), T::from_f64(0.3389000), T::from_f64(-0.1371000),
+                        T::from_f64(-0.5918000), T::from_f64(1.5512000), T::from_f64(0.0406000),
+                        T::from_f64(0.0008000), T::from_f64(0.0239000), T::from_f64(0.9753000)
+                    ],
+                    inv_ma: [
+                        T::from_f64(1.0764500), T::from_f64(-0.2376624), T::from_f64(0.1612123),
+                        T::from_f64(0.4109643), T::from_f64(0.5543418), T::from_f64(0.0346939),
+                        T::from_f64(-0.0109538), T::from_f64(-0.0133894), T::from_f64(1.0243431)
+                    ],
+                }
+            }
         }
     }
 }
@@ -160,6 +330,20 @@ where
     /// method.
     #[must_use]
     fn adapt_from_using<M: TransformMatrix<T>>(color: S, method: M) -> Self;
+
+    /// Convert the source color to the destination color using the specified
+    /// method, with incomplete adaptation.
+    ///
+    /// The `degree_of_adaptation` goes from 0.0 (no adaptation) to 1.0
+    /// (complete adaptation). See [`degree_of_adaptation`].
+    #[must_use]
+    fn adapt_from_partially_using<M: TransformMatrix<T>>(
+        color: S,
+        method: M,
+        degree_of_adaptation: T,
+    ) -> Self
+    where
+        T: One;
 }
 
 impl<S, D, Swp, Dwp, T> AdaptFrom<S, Swp, Dwp, T> for D
@@ -167,8 +351,8 @@ where
     T: Real + Zero + Arithmetics + Clone,
     Swp: WhitePoint<T>,
     Dwp: WhitePoint<T>,
-    S: IntoColorUnclamped<Xyz<Swp, T>>,
-    D: FromColorUnclamped<Xyz<Dwp, T>>,
+    S: HasWhitePoint<WhitePoint = Swp> + IntoColorUnclamped<Xyz<Swp, T>>,
+    D: HasWhitePoint<WhitePoint = Dwp> + FromColorUnclamped<Xyz<Dwp, T>>,
 {
     #[inline]
     fn adapt_from_using<M: TransformMatrix<T>>(color: S, method: M) -> D {
@@ -177,6 +361,25 @@ where
         let dst_xyz = multiply_xyz(transform_matrix, src_xyz);
         D::from_color_unclamped(dst_xyz.with_white_point())
     }
+
+    #[inline]
+    fn adapt_from_partially_using<M: TransformMatrix<T>>(
+        color: S,
+        method: M,
+        degree_of_adaptation: T,
+    ) -> D
+    where
+        T: One,
+    {
+        let src_xyz = color.into_color_unclamped().with_white_point();
+        let transform_matrix = method.generate_partial_transform_matrix(
+            Swp::get_xyz(),
+            Dwp::get_xyz(),
+            degree_of_adaptation,
+        );
+        let dst_xyz = multiply_xyz(transform_matrix, src_xyz);
+        D::from_color_unclamped(dst_xyz.with_white_point())
+    }
 }
 
 /// Trait to convert color with one reference white point into another
@@ -200,6 +403,20 @@ where
     /// method.
     #[must_use]
     fn adapt_into_using<M: TransformMatrix<T>>(self, method: M) -> D;
+
+    /// Convert the source color to the destination color using the specified
+    /// method, with incomplete adaptation.
+    ///
+    /// The `degree_of_adaptation` goes from 0.0 (no adaptation) to 1.0
+    /// (complete adaptation). See [`degree_of_adaptation`].
+    #[must_use]
+    fn adapt_into_partially_using<M: TransformMatrix<T>>(
+        self,
+        method: M,
+        degree_of_adaptation: T,
+    ) -> D
+    where
+        T: One;
 }
 
 impl<S, D, Swp, Dwp, T> AdaptInto<D, Swp, Dwp, T> for S
@@ -213,13 +430,427 @@ where
     fn adapt_into_using<M: TransformMatrix<T>>(self, method: M) -> D {
         D::adapt_from_using(self, method)
     }
+
+    #[inline]
+    fn adapt_into_partially_using<M: TransformMatrix<T>>(
+        self,
+        method: M,
+        degree_of_adaptation: T,
+    ) -> D
+    where
+        T: One,
+    {
+        D::adapt_from_partially_using(self, method, degree_of_adaptation)
+    }
+}
+
+/// Adapts colors from the white point `Wp` to `Self` in regular conversions.
+///
+/// This is what lets conversions between [`Rgb`] and [`Xyz`] or [`Lab`](crate::Lab) colors
+/// with different white points adapt the colors, instead of requiring the
+/// white points to be the same. It's implemented for each white point to
+/// itself, which does nothing, and between each pair of white points in the
+/// [`white_point`](crate::white_point) module, using the Bradford method. The
+/// transformation matrix for each pair is computed at compile time.
+///
+/// Custom white points can implement it to take part in the same conversions.
+/// An [`Adaptation`] can be used to pick another method.
+///
+/// ```
+/// use palette::{rgb::DisplayP3Rgb, white_point::D50, FromColor, Lab, Xyz};
+///
+/// let p3 = DisplayP3Rgb::new(0.8f32, 0.3, 0.1);
+/// let xyz = Xyz::<D50, f32>::from_color(p3);
+/// let lab = Lab::<D50, f32>::from_color(p3);
+/// ```
+pub trait ConvertFromWhitePoint<Wp, T>: Sized {
+    /// Adapt an `Xyz` color from `Wp` to `Self`.
+    #[must_use]
+    fn convert_xyz(color: Xyz<Wp, T>) -> Xyz<Self, T>;
+}
+
+impl<Wp, T> ConvertFromWhitePoint<Wp, T> for Wp {
+    #[inline]
+    fn convert_xyz(color: Xyz<Wp, T>) -> Xyz<Wp, T> {
+        color
+    }
+}
+
+/// Computes the Bradford matrix from `$source` to `$destination` XYZ arrays
+/// in a constant expression.
+macro_rules! bradford_matrix {
+    ($source:expr, $destination:expr) => {{
+        let (ma, inv_ma) = (BRADFORD_MA, BRADFORD_INV_MA);
+        let (source, destination) = ($source, $destination);
+        let ratios = [
+            bradford_matrix!(@cone ma, 0, destination) / bradford_matrix!(@cone ma, 0, source),
+            bradford_matrix!(@cone ma, 1, destination) / bradford_matrix!(@cone ma, 1, source),
+            bradford_matrix!(@cone ma, 2, destination) / bradford_matrix!(@cone ma, 2, source),
+        ];
+        [
+            bradford_matrix!(@entry inv_ma, ratios, ma, 0, 0),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 0, 1),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 0, 2),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 1, 0),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 1, 1),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 1, 2),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 2, 0),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 2, 1),
+            bradford_matrix!(@entry inv_ma, ratios, ma, 2, 2),
+        ]
+    }};
+    (@cone $ma:ident, $row:literal, $xyz:ident) => {
+        $ma[$row * 3] * $xyz[0] + $ma[$row * 3 + 1] * $xyz[1] + $ma[$row * 3 + 2] * $xyz[2]
+    };
+    (@entry $inv_ma:ident, $ratios:ident, $ma:ident, $row:literal, $column:literal) => {
+        $inv_ma[$row * 3] * $ratios[0] * $ma[$column]
+            + $inv_ma[$row * 3 + 1] * $ratios[1] * $ma[3 + $column]
+            + $inv_ma[$row * 3 + 2] * $ratios[2] * $ma[6 + $column]
+    };
+}
+
+macro_rules! impl_convert_from_white_point {
+    ($first:ident $(, $rest:ident)*) => {
+        $(
+            impl_convert_from_white_point!(@pair $first => $rest);
+            impl_convert_from_white_point!(@pair $rest => $first);
+        )*
+
+        impl_convert_from_white_point!($($rest),*);
+    };
+    () => {};
+    (@pair $source:ident => $destination:ident) => {
+        impl<T> ConvertFromWhitePoint<white_point::$source, T> for white_point::$destination
+        where
+            T: Real + Arithmetics,
+        {
+            #[inline]
+            fn convert_xyz(color: Xyz<white_point::$source, T>) -> Xyz<Self, T> {
+                const MATRIX: Mat3<f64> =
+                    bradford_matrix!(white_point::$source::XYZ, white_point::$destination::XYZ);
+
+                multiply_xyz(MATRIX.map(T::from_f64), color.with_white_point()).with_white_point()
+            }
+        }
+    };
+}
+
+impl_convert_from_white_point!(
+    A,
+    B,
+    C,
+    D50,
+    D55,
+    D65,
+    D75,
+    E,
+    F2,
+    F7,
+    F11,
+    Dci,
+    Aces,
+    D50Degree10,
+    D55Degree10,
+    D65Degree10,
+    D75Degree10
+);
+
+/// A chromatic adaptation between two white points, with a selectable method.
+///
+/// Regular conversions between colors with different white points adapt them
+/// with the Bradford method, through [`ConvertFromWhitePoint`]. An `Adaptation`
+/// can use any other [`TransformMatrix`], such as another [`Method`], and
+/// precomputes the transformation matrix so it can be applied to many colors.
+///
+/// ```
+/// use palette::{
+///     chromatic_adaptation::{Adaptation, Method},
+///     white_point::{D50, D65},
+///     Lab, Srgb,
+/// };
+///
+/// let adaptation = Adaptation::<D65, D50, f32>::new(Method::Cat16);
+/// let lab: Lab<D50, f32> = adaptation.convert(Srgb::new(0.8f32, 0.3, 0.1));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct Adaptation<Swp, Dwp, T> {
+    matrix: Mat3<T>,
+    white_points: PhantomData<(Swp, Dwp)>,
+}
+
+impl<Swp, Dwp, T> Adaptation<Swp, Dwp, T>
+where
+    T: Zero + Arithmetics + Clone,
+    Swp: WhitePoint<T>,
+    Dwp: WhitePoint<T>,
+{
+    /// Create an adaptation from `Swp` to `Dwp`, using `method`.
+    #[must_use]
+    #[inline]
+    pub fn new<M: TransformMatrix<T>>(method: M) -> Self {
+        Self::from_matrix(method.generate_transform_matrix(Swp::get_xyz(), Dwp::get_xyz()))
+    }
+
+    /// Create an incomplete adaptation from `Swp` to `Dwp`, using `method`.
+    ///
+    /// The `degree_of_adaptation` goes from 0.0 (no adaptation) to 1.0
+    /// (complete adaptation). See [`degree_of_adaptation`].
+    #[must_use]
+    #[inline]
+    pub fn new_partial<M: TransformMatrix<T>>(method: M, degree_of_adaptation: T) -> Self
+    where
+        T: One,
+    {
+        Self::from_matrix(method.generate_partial_transform_matrix(
+            Swp::get_xyz(),
+            Dwp::get_xyz(),
+            degree_of_adaptation,
+        ))
+    }
+}
+
+impl<Swp, Dwp, T> Adaptation<Swp, Dwp, T> {
+    /// Create an adaptation from an XYZ transformation matrix.
+    #[must_use]
+    #[inline]
+    pub fn from_matrix(matrix: Mat3<T>) -> Self {
+        Adaptation {
+            matrix,
+            white_points: PhantomData,
+        }
+    }
+
+    /// Get the XYZ transformation matrix.
+    #[must_use]
+    #[inline]
+    pub fn matrix(&self) -> &Mat3<T> {
+        &self.matrix
+    }
+
+    /// Convert `color` from `Swp` to a color with `Dwp` as its white point.
+    #[must_use]
+    #[inline]
+    pub fn convert<S, D>(&self, color: S) -> D
+    where
+        T: Arithmetics + Clone,
+        S: IntoColorUnclamped<Xyz<Swp, T>>,
+        D: FromColorUnclamped<Xyz<Dwp, T>>,
+    {
+        let xyz = multiply_xyz(
+            self.matrix.clone(),
+            color.into_color_unclamped().with_white_point(),
+        );
+        D::from_color_unclamped(xyz.with_white_point())
+    }
+}
+
+impl<Swp, Dwp, T> Default for Adaptation<Swp, Dwp, T>
+where
+    T: Real + Zero + Arithmetics + Clone,
+    Swp: WhitePoint<T>,
+    Dwp: WhitePoint<T>,
+{
+    /// The Bradford adaptation from `Swp` to `Dwp`.
+    #[inline]
+    fn default() -> Self {
+        Self::new(Method::Bradford)
+    }
+}
+
+/// A chromatic adaptation between two white points that are only known at
+/// runtime.
+///
+/// [`AdaptFrom`] and [`AdaptInto`] need the white points to be types, which
+/// doesn't work when they are measured or estimated, such as the scene
+/// illuminant of a photo. A `DynamicAdaptation` takes them as values instead,
+/// and precomputes the transformation matrix so it can be applied to many
+/// colors. The white point type parameters of the colors are left unchanged,
+/// since they can't describe the runtime white points.
+///
+/// ```
+/// use palette::{
+///     chromatic_adaptation::{DynamicAdaptation, Method},
+///     LinSrgb, Yxy,
+/// };
+///
+/// // The estimated illuminant of an image, and the white point of sRGB.
+/// let illuminant = Yxy::new(0.4476, 0.4074, 1.0);
+/// let d65 = Yxy::new(0.3127, 0.3290, 1.0);
+///
+/// let adaptation = DynamicAdaptation::from_chromaticities(Method::Bradford, illuminant, d65);
+///
+/// let mut pixels = vec![LinSrgb::new(0.8f32, 0.5, 0.2), LinSrgb::new(0.3, 0.2, 0.1)];
+/// adaptation.adapt_rgb_slice(&mut pixels);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicAdaptation<T> {
+    matrix: Mat3<T>,
+}
+
+impl<T> DynamicAdaptation<T>
+where
+    T: Zero + Arithmetics + Clone,
+{
+    /// Create an adaptation from `source_wp` to `destination_wp`, using the
+    /// specified method.
+    pub fn new<M: TransformMatrix<T>>(
+        method: M,
+        source_wp: Xyz<Any, T>,
+        destination_wp: Xyz<Any, T>,
+    ) -> Self {
+        DynamicAdaptation {
+            matrix: method.generate_transform_matrix(source_wp, destination_wp),
+        }
+    }
+
+    /// Create an incomplete adaptation from `source_wp` to `destination_wp`,
+    /// using the specified method.
+    ///
+    /// The `degree_of_adaptation` goes from 0.0 (no adaptation) to 1.0
+    /// (complete adaptation). See [`degree_of_adaptation`].
+    pub fn new_partial<M: TransformMatrix<T>>(
+        method: M,
+        source_wp: Xyz<Any, T>,
+        destination_wp: Xyz<Any, T>,
+        degree_of_adaptation: T,
+    ) -> Self
+    where
+        T: One,
+    {
+        DynamicAdaptation {
+            matrix: method.generate_partial_transform_matrix(
+                source_wp,
+                destination_wp,
+                degree_of_adaptation,
+            ),
+        }
+    }
+
+    /// Create an adaptation from the white point with the chromaticity
+    /// `source_wp` to the white point with the chromaticity `destination_wp`,
+    /// using the specified method.
+    ///
+    /// Only the `x` and `y` components are used. Both white points are
+    /// normalized to a luminance of 1.0.
+    pub fn from_chromaticities<M: TransformMatrix<T>>(
+        method: M,
+        source_wp: Yxy<Any, T>,
+        destination_wp: Yxy<Any, T>,
+    ) -> Self
+    where
+        T: One,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        let source_wp = Yxy::<Any, T>::new(source_wp.x, source_wp.y, T::one());
+        let destination_wp = Yxy::<Any, T>::new(destination_wp.x, destination_wp.y, T::one());
+
+        Self::new(
+            method,
+            source_wp.into_color_unclamped(),
+            destination_wp.into_color_unclamped(),
+        )
+    }
+}
+
+impl<T> DynamicAdaptation<T> {
+    /// Create an adaptation from an already computed transformation matrix.
+    pub const fn from_matrix(matrix: Mat3<T>) -> Self {
+        DynamicAdaptation { matrix }
+    }
+
+    /// Get the transformation matrix, for `Xyz` values.
+    pub fn matrix(&self) -> &Mat3<T> {
+        &self.matrix
+    }
+
+    /// Return the transformation matrix, consuming the adaptation.
+    pub fn into_matrix(self) -> Mat3<T> {
+        self.matrix
+    }
+}
+
+impl<T> DynamicAdaptation<T>
+where
+    T: Arithmetics + Clone,
+{
+    /// Adapt a single `Xyz` color.
+    #[must_use]
+    pub fn adapt_xyz<Wp>(&self, color: Xyz<Wp, T>) -> Xyz<Wp, T> {
+        multiply_xyz(self.matrix.clone(), color.with_white_point()).with_white_point()
+    }
+
+    /// Adapt a slice of `Xyz` colors in place.
+    pub fn adapt_xyz_slice<Wp>(&self, colors: &mut [Xyz<Wp, T>]) {
+        for color in colors {
+            let xyz = Xyz::new(color.x.clone(), color.y.clone(), color.z.clone());
+            let adapted = multiply_xyz(self.matrix.clone(), xyz);
+
+            color.x = adapted.x;
+            color.y = adapted.y;
+            color.z = adapted.z;
+        }
+    }
+
+    /// Adapt a single linear RGB color.
+    ///
+    /// The transformation matrix is converted to the RGB space on each call,
+    /// so prefer [`adapt_rgb_slice`](Self::adapt_rgb_slice) for many colors.
+    #[must_use]
+    pub fn adapt_rgb<S>(&self, color: Rgb<Linear<S>, T>) -> Rgb<Linear<S>, T>
+    where
+        S: RgbSpace,
+        S::Primaries: Primaries<T>,
+        S::WhitePoint: WhitePoint<T>,
+        T: Recip + IsValidDivisor<Mask = bool> + FromScalar<Scalar = T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        let matrix = self.rgb_matrix::<S>();
+        multiply_rgb(matrix, color)
+    }
+
+    /// Adapt a slice of linear RGB colors in place.
+    pub fn adapt_rgb_slice<S>(&self, colors: &mut [Rgb<Linear<S>, T>])
+    where
+        S: RgbSpace,
+        S::Primaries: Primaries<T>,
+        S::WhitePoint: WhitePoint<T>,
+        T: Recip + IsValidDivisor<Mask = bool> + FromScalar<Scalar = T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        let matrix = self.rgb_matrix::<S>();
+
+        for color in colors {
+            *color = multiply_rgb(matrix.clone(), color.clone());
+        }
+    }
+
+    /// The transformation matrix for linear RGB values in the space `S`.
+    fn rgb_matrix<S>(&self) -> Mat3<T>
+    where
+        S: RgbSpace,
+        S::Primaries: Primaries<T>,
+        S::WhitePoint: WhitePoint<T>,
+        T: Recip + IsValidDivisor<Mask = bool> + FromScalar<Scalar = T>,
+        Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+    {
+        let rgb_to_xyz = rgb_to_xyz_matrix::<S, T>();
+        let xyz_to_rgb = matrix_inverse(rgb_to_xyz.clone());
+
+        multiply_3x3(xyz_to_rgb, multiply_3x3(self.matrix.clone(), rgb_to_xyz))
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use super::{AdaptFrom, AdaptInto, Method, TransformMatrix};
+    use super::{
+        degree_of_adaptation, AdaptFrom, AdaptInto, Adaptation, DynamicAdaptation, Method,
+        TransformMatrix,
+    };
+    use crate::cam16::Surround;
+    use crate::convert::FromColorUnclamped;
+    use crate::rgb::{DisplayP3Rgb, LinDisplayP3, LinProPhotoRgb, ProPhotoRgb};
     use crate::white_point::{WhitePoint, A, C, D50, D65};
-    use crate::Xyz;
+    use crate::{Lab, Xyz};
 
     #[test]
     fn d65_to_d50_matrix_xyz_scaling() {
@@ -293,4 +924,243 @@ mod test {
         let computed_xyz_scaling: Xyz<C, _> = input_a.adapt_into_using(Method::XyzScaling);
         assert_relative_eq!(expected_xyz_scaling, computed_xyz_scaling, epsilon = 0.0001);
     }
+
+    #[test]
+    fn rgb_across_white_points() {
+        let p3 = LinDisplayP3::new(0.8f64, 0.3, 0.1);
+
+        let converted = LinProPhotoRgb::from_color_unclamped(p3);
+        let adapted: LinProPhotoRgb<f64> = p3.adapt_into();
+        assert_relative_eq!(converted, adapted, epsilon = 0.000001);
+
+        let von_kries: LinProPhotoRgb<f64> = p3.adapt_into_using(Method::VonKries);
+        assert!((von_kries.red - converted.red).abs() > 0.001);
+
+        let white = ProPhotoRgb::from_color_unclamped(DisplayP3Rgb::new(1.0f64, 1.0, 1.0));
+        assert_relative_eq!(white, ProPhotoRgb::new(1.0, 1.0, 1.0), epsilon = 0.00001);
+
+        let roundtrip = LinDisplayP3::from_color_unclamped(converted);
+        assert_relative_eq!(roundtrip, p3, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn lab_across_white_points() {
+        let p3 = DisplayP3Rgb::new(0.8f64, 0.3, 0.1);
+
+        let lab: Lab<D50, f64> = p3.adapt_into();
+        let expected = Lab::<D50, f64>::from_color_unclamped(ProPhotoRgb::from_color_unclamped(p3));
+        assert_relative_eq!(lab, expected, epsilon = 0.000001);
+
+        let converted = Lab::<D50, f64>::from_color_unclamped(p3);
+        assert_relative_eq!(converted, expected, epsilon = 0.000001);
+
+        let roundtrip = DisplayP3Rgb::from_color_unclamped(converted);
+        assert_relative_eq!(roundtrip, p3, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn xyz_across_white_points() {
+        let p3 = DisplayP3Rgb::new(0.8f64, 0.3, 0.1);
+
+        let converted = Xyz::<D50, f64>::from_color_unclamped(p3);
+        let expected: Xyz<D50, f64> = p3.adapt_into();
+        assert_relative_eq!(converted, expected, epsilon = 0.000001);
+
+        let roundtrip = DisplayP3Rgb::from_color_unclamped(converted);
+        assert_relative_eq!(roundtrip, p3, epsilon = 0.000001);
+
+        let white = Xyz::<D50, f64>::from_color_unclamped(DisplayP3Rgb::new(1.0f64, 1.0, 1.0));
+        assert_relative_eq!(white, D50::get_xyz().with_white_point(), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn adaptation() {
+        let input = Xyz::<D65, f64>::new(0.3, 0.4, 0.5);
+
+        let bradford = Adaptation::<D65, D50, f64>::default();
+        let converted: Xyz<D50, f64> = bradford.convert(input);
+        let expected: Xyz<D50, f64> = input.adapt_into();
+        assert_relative_eq!(converted, expected, epsilon = 0.0000001);
+
+        let von_kries = Adaptation::<D65, D50, f64>::new(Method::VonKries);
+        let converted: Xyz<D50, f64> = von_kries.convert(input);
+        let expected: Xyz<D50, f64> = input.adapt_into_using(Method::VonKries);
+        assert_relative_eq!(converted, expected, epsilon = 0.0000001);
+
+        let lab: Lab<D50, f64> = von_kries.convert(input);
+        assert_relative_eq!(
+            lab,
+            Lab::from_color_unclamped(expected),
+            epsilon = 0.0000001
+        );
+
+        let none = Adaptation::<D65, D50, f64>::new_partial(Method::Cat16, 0.0);
+        let converted: Xyz<D50, f64> = none.convert(input);
+        assert_relative_eq!(converted, input.with_white_point(), epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn same_white_point_rgb_bounds() {
+        use crate::{
+            encoding::{FromLinear, IntoLinear},
+            rgb::{Rgb, RgbSpace, RgbStandard},
+            Srgb,
+        };
+
+        // The same bounds as before the conversion could adapt between white
+        // points should still be enough when the white points are the same.
+        fn convert<S1, S2, T>(color: Rgb<S2, T>) -> Rgb<S1, T>
+        where
+            S1: RgbStandard + 'static,
+            S2: RgbStandard + 'static,
+            S1::TransferFn: FromLinear<T, T>,
+            S2::TransferFn: IntoLinear<T, T>,
+            S2::Space: RgbSpace<WhitePoint = <S1::Space as RgbSpace>::WhitePoint>,
+            Xyz<<S2::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<S2, T>>,
+            Rgb<S1, T>: FromColorUnclamped<Xyz<<S1::Space as RgbSpace>::WhitePoint, T>>,
+        {
+            <Rgb<S1, T> as FromColorUnclamped<Rgb<S2, T>>>::from_color_unclamped(color)
+        }
+
+        let color = Srgb::new(0.8f64, 0.3, 0.1);
+        let converted: DisplayP3Rgb<f64> = convert(color);
+        assert_relative_eq!(converted, DisplayP3Rgb::from_color_unclamped(color));
+    }
+
+    #[test]
+    fn d65_to_d50_matrix_cat02() {
+        let expected = [
+            1.0424827, 0.0308012, -0.0527444, 0.0221296, 1.0018822, -0.0210462, -0.0011630,
+            -0.0034171, 0.7620404,
+        ];
+        let cat02 = Method::Cat02;
+        let computed = cat02.generate_transform_matrix(D65::get_xyz(), D50::get_xyz());
+        for (e, c) in expected.iter().zip(computed.iter()) {
+            assert_relative_eq!(e, c, epsilon = 0.0001)
+        }
+    }
+
+    #[test]
+    fn cone_response_inverses() {
+        for method in [
+            Method::Cat02,
+            Method::Cat16,
+            Method::Sharp,
+            Method::Cmccat2000,
+        ] {
+            let cone_response = TransformMatrix::<f64>::get_cone_response(&method);
+            let identity = crate::matrix::multiply_3x3(cone_response.ma, cone_response.inv_ma);
+            assert_relative_eq!(
+                identity[..],
+                [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0][..],
+                epsilon = 0.000001
+            );
+        }
+    }
+
+    #[test]
+    fn partial_adaptation() {
+        let cat02 = Method::Cat02;
+
+        let none = cat02.generate_partial_transform_matrix(D65::get_xyz(), D50::get_xyz(), 0.0);
+        assert_relative_eq!(
+            none[..],
+            [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0][..],
+            epsilon = 0.000001
+        );
+
+        let complete = cat02.generate_partial_transform_matrix(D65::get_xyz(), D50::get_xyz(), 1.0);
+        let expected = cat02.generate_transform_matrix(D65::get_xyz(), D50::get_xyz());
+        assert_relative_eq!(complete[..], expected[..], epsilon = 0.0000001);
+
+        let half = cat02.generate_partial_transform_matrix(D65::get_xyz(), D50::get_xyz(), 0.5);
+        let expected = [
+            1.0212413, 0.0154006, -0.0263722, 0.0110648, 1.0009411, -0.0105231, -0.0005815,
+            -0.0017086, 0.8810202,
+        ];
+        assert_relative_eq!(half[..], expected[..], epsilon = 0.0001);
+
+        let input = Xyz::<D65, f64>::new(0.3, 0.4, 0.5);
+        let adapted: Xyz<D50, f64> = input.adapt_into_partially_using(Method::Cat16, 0.0);
+        assert_relative_eq!(adapted, Xyz::new(0.3, 0.4, 0.5), epsilon = 0.000001);
+        let adapted = Xyz::<D50, f64>::adapt_from_partially_using(input, Method::Cat16, 1.0);
+        assert_relative_eq!(
+            adapted,
+            Xyz::adapt_from_using(input, Method::Cat16),
+            epsilon = 0.000001
+        );
+    }
+
+    #[test]
+    fn degree_of_adaptation_from_viewing_conditions() {
+        assert_relative_eq!(
+            degree_of_adaptation(318.31f64, Surround::Average),
+            0.99447,
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(
+            degree_of_adaptation(20.0f64, Surround::Average),
+            0.85841,
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(
+            degree_of_adaptation(20.0f64, Surround::Dark),
+            0.8 * 0.85841,
+            epsilon = 0.00001
+        );
+    }
+
+    #[test]
+    fn dynamic_adaptation() {
+        let adaptation = DynamicAdaptation::new(Method::Bradford, D65::get_xyz(), D50::get_xyz());
+        let input = Xyz::<D65, f64>::new(0.3, 0.4, 0.5);
+        let expected: Xyz<D50, f64> = input.adapt_into();
+        assert_relative_eq!(
+            adaptation.adapt_xyz(input).with_white_point(),
+            expected,
+            epsilon = 0.0000001
+        );
+
+        let mut colors = [input, Xyz::new(0.9505, 1.0, 1.089)];
+        adaptation.adapt_xyz_slice(&mut colors);
+        assert_relative_eq!(colors[0].with_white_point(), expected, epsilon = 0.0000001);
+
+        let d50 = D50::get_xyz();
+        assert_relative_eq!(colors[1].x, d50.x, epsilon = 0.001);
+        assert_relative_eq!(colors[1].y, d50.y, epsilon = 0.001);
+        assert_relative_eq!(colors[1].z, d50.z, epsilon = 0.001);
+    }
+
+    #[test]
+    fn dynamic_adaptation_from_chromaticities() {
+        use crate::{convert::FromColorUnclamped, LinSrgb, Yxy};
+
+        let adaptation = DynamicAdaptation::<f64>::from_chromaticities(
+            Method::Cat16,
+            Yxy::from_color_unclamped(D65::get_xyz()),
+            Yxy::from_color_unclamped(D50::get_xyz()),
+        );
+        let expected = Method::Cat16.generate_transform_matrix(D65::get_xyz(), D50::get_xyz());
+        assert_relative_eq!(adaptation.matrix()[..], expected[..], epsilon = 0.0000001);
+
+        // Adapting from D65 to A and back should give the same color.
+        let a = Yxy::new(0.44757, 0.40745, 1.0);
+        let d65 = Yxy::new(0.31271, 0.32902, 1.0);
+        let forward = DynamicAdaptation::from_chromaticities(Method::Bradford, d65, a);
+        let backward = DynamicAdaptation::from_chromaticities(Method::Bradford, a, d65);
+
+        let white = LinSrgb::new(1.0f64, 1.0, 1.0);
+        let mut colors = [white, LinSrgb::new(0.8, 0.5, 0.2)];
+        forward.adapt_rgb_slice(&mut colors);
+        assert!(colors[0].red > colors[0].blue);
+
+        backward.adapt_rgb_slice(&mut colors);
+        assert_relative_eq!(colors[0], white, epsilon = 0.0001);
+        assert_relative_eq!(colors[1], LinSrgb::new(0.8, 0.5, 0.2), epsilon = 0.000001);
+        assert_relative_eq!(
+            backward.adapt_rgb(forward.adapt_rgb(white)),
+            white,
+            epsilon = 0.000001
+        );
+    }
 }
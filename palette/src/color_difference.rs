@@ -1,10 +1,49 @@
+//! Color difference formulas.
+//!
+//! The [`ColorDifference`] trait is implemented for a number of color spaces,
+//! where it uses a metric that suits the space. For CIE L\*a\*b\* based
+//! colors, such as [`Lab`] and [`Lch`], that's CIEDE2000. Other formulas are
+//! still used in various industries, and are available as separate types that
+//! implement [`DifferenceFormula`]:
+//!
+//! * [`Cie76`] - the Euclidean distance in CIE L\*a\*b\*, ΔE\*<sub>ab</sub>.
+//! * [`Cie94`] - CIE94, with weights for graphic arts or textiles.
+//! * [`Cmc`] - CMC l:c, with the 2:1 acceptability or 1:1 perceptibility
+//!   weights.
+//! * [`Ciede2000`] - CIEDE2000, with custom parametric weighting factors and
+//!   the individual lightness, chroma and hue differences.
+//!
+//! ```
+//! use palette::{
+//!     color_difference::{Cie76, Cie94, Cmc, DifferenceFormula},
+//!     white_point::D65,
+//!     Lab,
+//! };
+//!
+//! let reference = Lab::<D65, f64>::new(50.0, 2.6772, -79.7751);
+//! let sample = Lab::<D65, f64>::new(50.0, 0.0, -82.7485);
+//!
+//! let delta_e76 = Cie76.get_difference(reference, sample);
+//! let delta_e94 = Cie94::graphic_arts().get_difference(reference, sample);
+//! let delta_cmc = Cmc::acceptability().get_difference(reference, sample);
+//!
+//! assert!((delta_e76 - 4.0011).abs() < 0.0001);
+//! assert!((delta_e94 - 1.3950).abs() < 0.0001);
+//! assert!((delta_cmc - 1.7387).abs() < 0.0001);
+//! ```
+//!
+//! CIE94 and CMC l:c are not symmetric. The first color is the reference, or
+//! standard, and the second color is the sample that is compared to it.
+
 use core::ops::{BitAnd, BitOr};
 
 use crate::{
     angle::RealAngle,
     bool_mask::LazySelect,
     convert::IntoColorUnclamped,
-    num::{Abs, Arithmetics, Exp, Hypot, One, PartialCmp, Powi, Real, Sqrt, Trigonometry, Zero},
+    num::{
+        Abs, Arithmetics, Exp, Hypot, MinMax, One, PartialCmp, Powi, Real, Sqrt, Trigonometry, Zero,
+    },
     Lab, Lch,
 };
 
@@ -62,12 +101,408 @@ where
     }
 }
 
+/// A color difference formula for CIE L\*a\*b\* based colors.
+///
+/// The formula is selected by its value, which may also hold its weights. See
+/// the [module level documentation](self) for an overview.
+pub trait DifferenceFormula<T> {
+    /// Return the difference between a `reference` color and a `sample` color.
+    #[must_use]
+    fn get_difference<C>(&self, reference: C, sample: C) -> T
+    where
+        C: Into<LabColorDiff<T>>;
+}
+
+/// The CIE76 color difference, ΔE\*<sub>ab</sub>.
+///
+/// This is the Euclidean distance between two colors in CIE L\*a\*b\*. It's
+/// the original, and simplest, ΔE formula, but it overestimates the
+/// difference between saturated colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Cie76;
+
+impl<T> DifferenceFormula<T> for Cie76
+where
+    T: Sqrt + Arithmetics + Clone,
+{
+    #[inline]
+    fn get_difference<C>(&self, reference: C, sample: C) -> T
+    where
+        C: Into<LabColorDiff<T>>,
+    {
+        let reference = reference.into();
+        let sample = sample.into();
+
+        let delta_l = reference.l - sample.l;
+        let delta_a = reference.a - sample.a;
+        let delta_b = reference.b - sample.b;
+
+        (delta_l.clone() * delta_l + delta_a.clone() * delta_a + delta_b.clone() * delta_b).sqrt()
+    }
+}
+
+/// The CIE94 color difference, ΔE\*<sub>94</sub>.
+///
+/// CIE94 weights the lightness, chroma and hue differences of CIE76 by the
+/// chroma of the reference color. The weights depend on the application, with
+/// one set for [graphic arts](Cie94::graphic_arts) and one for
+/// [textiles](Cie94::textile).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cie94<T> {
+    /// The lightness weight, k<sub>L</sub>.
+    pub k_l: T,
+
+    /// The chroma factor, K<sub>1</sub>, in S<sub>C</sub> = 1 + K<sub>1</sub>
+    /// C\*<sub>1</sub>.
+    pub k_1: T,
+
+    /// The hue factor, K<sub>2</sub>, in S<sub>H</sub> = 1 + K<sub>2</sub>
+    /// C\*<sub>1</sub>.
+    pub k_2: T,
+}
+
+impl<T> Cie94<T> {
+    /// Create a CIE94 formula with custom weights.
+    pub const fn new(k_l: T, k_1: T, k_2: T) -> Self {
+        Cie94 { k_l, k_1, k_2 }
+    }
+}
+
+impl<T> Cie94<T>
+where
+    T: Real,
+{
+    /// The weights for graphic arts: k<sub>L</sub> = 1, K<sub>1</sub> = 0.045
+    /// and K<sub>2</sub> = 0.015.
+    pub fn graphic_arts() -> Self {
+        Cie94::new(T::from_f64(1.0), T::from_f64(0.045), T::from_f64(0.015))
+    }
+
+    /// The weights for textiles: k<sub>L</sub> = 2, K<sub>1</sub> = 0.048 and
+    /// K<sub>2</sub> = 0.014.
+    pub fn textile() -> Self {
+        Cie94::new(T::from_f64(2.0), T::from_f64(0.048), T::from_f64(0.014))
+    }
+}
+
+impl<T> DifferenceFormula<T> for Cie94<T>
+where
+    T: Zero + One + Sqrt + MinMax + Arithmetics + Clone,
+{
+    #[inline]
+    fn get_difference<C>(&self, reference: C, sample: C) -> T
+    where
+        C: Into<LabColorDiff<T>>,
+    {
+        let reference = reference.into();
+        let sample = sample.into();
+
+        let delta_l = reference.l - sample.l;
+        let delta_c = reference.chroma.clone() - sample.chroma;
+        let delta_h_squared = delta_h_squared(
+            reference.a - sample.a,
+            reference.b - sample.b,
+            delta_c.clone(),
+        );
+
+        let s_c = T::one() + self.k_1.clone() * &reference.chroma;
+        let s_h = T::one() + self.k_2.clone() * reference.chroma;
+
+        let l_term = delta_l / &self.k_l;
+        let c_term = delta_c / s_c;
+
+        (l_term.clone() * l_term + c_term.clone() * c_term + delta_h_squared / (s_h.clone() * s_h))
+            .sqrt()
+    }
+}
+
+/// The CMC l:c color difference, ΔE<sub>CMC</sub>.
+///
+/// This formula, by the Colour Measurement Committee of the Society of Dyers
+/// and Colourists, is commonly used in the textile industry. The lightness
+/// weight, `l`, and the chroma weight, `c`, are typically 2:1 for
+/// [acceptability](Cmc::acceptability) and 1:1 for
+/// [perceptibility](Cmc::perceptibility).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cmc<T> {
+    /// The lightness weight.
+    pub l: T,
+
+    /// The chroma weight.
+    pub c: T,
+}
+
+impl<T> Cmc<T> {
+    /// Create a CMC l:c formula with custom weights.
+    pub const fn new(l: T, c: T) -> Self {
+        Cmc { l, c }
+    }
+}
+
+impl<T> Cmc<T>
+where
+    T: Real,
+{
+    /// The 2:1 weights, for acceptability.
+    pub fn acceptability() -> Self {
+        Cmc::new(T::from_f64(2.0), T::from_f64(1.0))
+    }
+
+    /// The 1:1 weights, for perceptibility.
+    pub fn perceptibility() -> Self {
+        Cmc::new(T::from_f64(1.0), T::from_f64(1.0))
+    }
+}
+
+impl<T> DifferenceFormula<T> for Cmc<T>
+where
+    T: Real
+        + RealAngle
+        + Zero
+        + One
+        + Trigonometry
+        + Abs
+        + Sqrt
+        + Powi
+        + MinMax
+        + Arithmetics
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T> + BitAnd<Output = T::Mask>,
+{
+    #[rustfmt::skip]
+    #[inline]
+    fn get_difference<C>(&self, reference: C, sample: C) -> T
+    where
+        C: Into<LabColorDiff<T>>,
+    {
+        let LabColorDiff { l, a, b, chroma } = reference.into();
+        let sample = sample.into();
+
+        let delta_l = l.clone() - sample.l;
+        let delta_c = chroma.clone() - sample.chroma;
+        let delta_h_squared = delta_h_squared(
+            a.clone() - sample.a,
+            b.clone() - sample.b,
+            delta_c.clone(),
+        );
+
+        let hue = T::radians_to_degrees(b.atan2(a));
+        let hue = lazy_select! {
+            if hue.lt(&T::zero()) => hue.clone() + T::from_f64(360.0),
+            else => hue.clone(),
+        };
+
+        let s_l = lazy_select! {
+            if l.lt(&T::from_f64(16.0)) => T::from_f64(0.511),
+            else => T::from_f64(0.040975) * &l / (T::one() + T::from_f64(0.01765) * &l),
+        };
+        let s_c = T::from_f64(0.0638) * &chroma / (T::one() + T::from_f64(0.0131) * &chroma)
+            + T::from_f64(0.638);
+
+        let chroma_pow_four = chroma.powi(4);
+        let f = (chroma_pow_four.clone() / (chroma_pow_four + T::from_f64(1900.0))).sqrt();
+        let t = lazy_select! {
+            if hue.gt_eq(&T::from_f64(164.0)) & hue.lt_eq(&T::from_f64(345.0)) => {
+                T::from_f64(0.56)
+                    + (T::from_f64(0.2) * T::degrees_to_radians(hue.clone() + T::from_f64(168.0)).cos()).abs()
+            },
+            else => {
+                T::from_f64(0.36)
+                    + (T::from_f64(0.4) * T::degrees_to_radians(hue.clone() + T::from_f64(35.0)).cos()).abs()
+            },
+        };
+        let s_h = s_c.clone() * (f.clone() * t + T::one() - f);
+
+        let l_term = delta_l / (self.l.clone() * s_l);
+        let c_term = delta_c / (self.c.clone() * s_c);
+
+        (l_term.clone() * l_term + c_term.clone() * c_term + delta_h_squared / (s_h.clone() * s_h))
+            .sqrt()
+    }
+}
+
+/// The CIEDE2000 color difference, ΔE<sub>00</sub>, with parametric weighting
+/// factors.
+///
+/// The weighting factors, k<sub>L</sub>, k<sub>C</sub> and k<sub>H</sub>,
+/// adjust the formula to the viewing conditions of an application. They are
+/// all 1 for the reference conditions, which is what [`ColorDifference`] uses
+/// for [`Lab`] and [`Lch`]. Textile and automotive paint standards commonly
+/// use k<sub>L</sub> = 2.
+///
+/// ```
+/// use palette::{
+///     color_difference::{Ciede2000, DifferenceFormula},
+///     white_point::D65,
+///     Lab,
+/// };
+///
+/// let reference = Lab::<D65, f64>::new(50.0, 2.5, 0.0);
+/// let sample = Lab::<D65, f64>::new(56.0, -27.0, -3.0);
+///
+/// let textile = Ciede2000::new(2.0, 1.0, 1.0);
+/// let components = textile.get_components(reference, sample);
+///
+/// assert!((components.delta_l - 6.0).abs() < 0.0001);
+/// assert!((components.delta_e - textile.get_difference(reference, sample)).abs() < 0.0001);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ciede2000<T> {
+    /// The lightness weight, k<sub>L</sub>.
+    pub k_l: T,
+
+    /// The chroma weight, k<sub>C</sub>.
+    pub k_c: T,
+
+    /// The hue weight, k<sub>H</sub>.
+    pub k_h: T,
+}
+
+impl<T> Ciede2000<T> {
+    /// Create a CIEDE2000 formula with custom weighting factors.
+    pub const fn new(k_l: T, k_c: T, k_h: T) -> Self {
+        Ciede2000 { k_l, k_c, k_h }
+    }
+}
+
+impl<T> Ciede2000<T>
+where
+    T: Real
+        + RealAngle
+        + One
+        + Zero
+        + Trigonometry
+        + Abs
+        + Sqrt
+        + Powi
+        + Exp
+        + Arithmetics
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T> + BitAnd<Output = T::Mask> + BitOr<Output = T::Mask>,
+{
+    /// Return the lightness, chroma and hue differences, as well as the total
+    /// difference, between a `reference` color and a `sample` color.
+    #[must_use]
+    #[inline]
+    pub fn get_components<C>(&self, reference: C, sample: C) -> Ciede2000Components<T>
+    where
+        C: Into<LabColorDiff<T>>,
+    {
+        get_ciede_components(
+            reference.into(),
+            sample.into(),
+            self.k_l.clone(),
+            self.k_c.clone(),
+            self.k_h.clone(),
+        )
+    }
+}
+
+/// The reference conditions, where k<sub>L</sub> = k<sub>C</sub> =
+/// k<sub>H</sub> = 1.
+impl<T> Default for Ciede2000<T>
+where
+    T: One,
+{
+    fn default() -> Self {
+        Ciede2000::new(T::one(), T::one(), T::one())
+    }
+}
+
+impl<T> DifferenceFormula<T> for Ciede2000<T>
+where
+    T: Real
+        + RealAngle
+        + One
+        + Zero
+        + Trigonometry
+        + Abs
+        + Sqrt
+        + Powi
+        + Exp
+        + Arithmetics
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T> + BitAnd<Output = T::Mask> + BitOr<Output = T::Mask>,
+{
+    #[inline]
+    fn get_difference<C>(&self, reference: C, sample: C) -> T
+    where
+        C: Into<LabColorDiff<T>>,
+    {
+        self.get_components(reference, sample).delta_e
+    }
+}
+
+/// The individual terms of a CIEDE2000 color difference.
+///
+/// The differences are from the reference color to the sample color, so a
+/// positive `delta_l` means that the sample is lighter than the reference. They
+/// are not weighted.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ciede2000Components<T> {
+    /// The lightness difference, ΔL'.
+    pub delta_l: T,
+
+    /// The chroma difference, ΔC'.
+    pub delta_c: T,
+
+    /// The hue difference, ΔH'.
+    pub delta_h: T,
+
+    /// The total weighted color difference, ΔE<sub>00</sub>.
+    pub delta_e: T,
+}
+
+/// Calculate ΔH\*<sup>2</sup> from the differences in a\*, b\* and chroma.
+/// It's clamped to 0, in case of rounding errors.
+#[inline]
+fn delta_h_squared<T>(delta_a: T, delta_b: T, delta_c: T) -> T
+where
+    T: Zero + MinMax + Arithmetics + Clone,
+{
+    (delta_a.clone() * delta_a + delta_b.clone() * delta_b - delta_c.clone() * delta_c)
+        .max(T::zero())
+}
+
 /// Calculate the CIEDE2000 color difference for two colors in Lab color space.
 /// There is a "just noticeable difference" between two colors when the delta E
 /// is roughly greater than 1. Thus, the color difference is more suited for
 /// calculating small distances between colors as opposed to large differences.
-#[rustfmt::skip]
+///
+/// The parametric weighting factors are all 1. See [`Ciede2000`] for custom
+/// weights.
 pub fn get_ciede_difference<T>(this: LabColorDiff<T>, other: LabColorDiff<T>) -> T
+where
+    T: Real
+        + RealAngle
+        + One
+        + Zero
+        + Trigonometry
+        + Abs
+        + Sqrt
+        + Powi
+        + Exp
+        + Arithmetics
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T> + BitAnd<Output = T::Mask> + BitOr<Output = T::Mask>,
+{
+    get_ciede_components(this, other, T::one(), T::one(), T::one()).delta_e
+}
+
+/// Calculate the CIEDE2000 components, with the parametric weighting factors
+/// `k_l`, `k_c` and `k_h`.
+#[rustfmt::skip]
+fn get_ciede_components<T>(
+    this: LabColorDiff<T>,
+    other: LabColorDiff<T>,
+    k_l: T,
+    k_c: T,
+    k_h: T,
+) -> Ciede2000Components<T>
 where
     T: Real
         + RealAngle
@@ -155,15 +590,86 @@ where
         * (c_bar_prime_pow_seven.clone() / (c_bar_prime_pow_seven + twenty_five_pow_seven)).sqrt();
     let r_t = -r_c * (T::from_f64(2.0) * delta_theta * pi_over_180).sin();
 
-    let k_l = T::one();
-    let k_c = T::one();
-    let k_h = T::one();
     let delta_l_prime = other.l - this.l;
     let delta_c_prime = c_two_prime - c_one_prime;
 
-    ((delta_l_prime.clone() / (k_l.clone() * &s_l)) * (delta_l_prime / (k_l * s_l))
+    let delta_e = ((delta_l_prime.clone() / (k_l.clone() * &s_l)) * (delta_l_prime.clone() / (k_l * s_l))
         + (delta_c_prime.clone() / (k_c.clone() * &s_c)) * (delta_c_prime.clone() / (k_c.clone() * &s_c))
         + (delta_big_h_prime.clone() / (k_h.clone() * &s_h)) * (delta_big_h_prime.clone() / (k_h.clone() * &s_h))
-        + (r_t * delta_c_prime * delta_big_h_prime) / (k_c * s_c * k_h * s_h))
-        .sqrt()
+        + (r_t * &delta_c_prime * &delta_big_h_prime) / (k_c * s_c * k_h * s_h))
+        .sqrt();
+
+    Ciede2000Components {
+        delta_l: delta_l_prime,
+        delta_c: delta_c_prime,
+        delta_h: delta_big_h_prime,
+        delta_e,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{get_ciede_difference, Ciede2000, DifferenceFormula};
+    use crate::{convert::FromColorUnclamped, white_point::D65, Lab, Lch};
+
+    #[test]
+    fn ciede2000_default_weights() {
+        let reference = Lab::<D65, f64>::new(60.2574, -34.0099, 36.2677);
+        let sample = Lab::<D65, f64>::new(60.4626, -34.1751, 39.4387);
+
+        assert_relative_eq!(
+            Ciede2000::default().get_difference(reference, sample),
+            get_ciede_difference(reference.into(), sample.into())
+        );
+        assert_relative_eq!(
+            Ciede2000::default().get_difference(reference, sample),
+            1.2644,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn ciede2000_lightness_weight() {
+        let formula = Ciede2000::new(2.0, 1.0, 1.0);
+
+        let reference = Lab::<D65, f64>::new(50.0, 2.5, 0.0);
+        let sample = Lab::<D65, f64>::new(61.0, -5.0, 29.0);
+        assert_relative_eq!(
+            formula.get_difference(reference, sample),
+            21.0747,
+            epsilon = 0.0001
+        );
+
+        let reference = Lab::<D65, f64>::new(60.2574, -34.0099, 36.2677);
+        let sample = Lab::<D65, f64>::new(60.4626, -34.1751, 39.4387);
+        assert_relative_eq!(
+            formula.get_difference(reference, sample),
+            1.2548,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn ciede2000_components() {
+        let reference = Lab::<D65, f64>::new(50.0, 2.6772, -79.7751);
+        let sample = Lab::<D65, f64>::new(50.0, 0.0, -82.7485);
+
+        let components = Ciede2000::default().get_components(reference, sample);
+        assert_relative_eq!(components.delta_l, 0.0);
+        assert_relative_eq!(components.delta_c, 2.9285, epsilon = 0.0001);
+        assert_relative_eq!(components.delta_h, -2.7264, epsilon = 0.0001);
+        assert_relative_eq!(components.delta_e, 2.0425, epsilon = 0.0001);
+
+        let reference = Lab::<D65, f64>::new(50.0, 2.5, 0.0);
+        let sample = Lab::<D65, f64>::new(61.0, -5.0, 29.0);
+
+        let components = Ciede2000::new(2.0, 1.0, 1.0).get_components(
+            Lch::from_color_unclamped(reference),
+            Lch::from_color_unclamped(sample),
+        );
+        assert_relative_eq!(components.delta_l, 11.0, epsilon = 0.0001);
+        assert_relative_eq!(components.delta_c, 26.3353, epsilon = 0.0001);
+        assert_relative_eq!(components.delta_h, 16.0440, epsilon = 0.0001);
+        assert_relative_eq!(components.delta_e, 21.0747, epsilon = 0.0001);
+    }
 }
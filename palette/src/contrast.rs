@@ -0,0 +1,490 @@
+//! Finding colors with enough contrast.
+//!
+//! [`RelativeContrast`] and [`LightnessContrast`] can tell if a pair of colors
+//! has enough contrast. The functions in this module can also fix a pair that
+//! doesn't, by adjusting the lightness of the foreground color:
+//!
+//! ```
+//! use palette::{
+//!     contrast::{find_contrasting_color, Wcag21},
+//!     Oklch, RelativeContrast, Srgb,
+//! };
+//!
+//! let background = Srgb::new(0.1f32, 0.1, 0.2);
+//! let foreground = Srgb::new(0.2f32, 0.25, 0.5);
+//! assert!(!foreground.has_min_contrast_text(background));
+//!
+//! // Search along the lightness in Oklch, to keep the hue and chroma.
+//! let foreground = find_contrasting_color::<Oklch<f32>, _, _>(foreground, background, 4.5, Wcag21)
+//!     .expect("there should be a lighter or darker blue with enough contrast");
+//! assert!(foreground.get_contrast_ratio(background) >= 4.5);
+//! ```
+//!
+//! It's also possible to pick the most legible color from a list of
+//! candidates, similar to the CSS `color-contrast()` function, with
+//! [`most_legible`] and [`most_legible_or_black_white`].
+//!
+//! The contrast can be measured with any [`ContrastMetric`], such as [`Wcag21`]
+//! for the WCAG 2.1 contrast ratio or [`Apca`] for the APCA lightness contrast.
+
+use crate::{
+    bool_mask::HasBoolMask,
+    convert::FromColorUnclamped,
+    num::{Abs, Arithmetics, One, PartialCmp, Real, Zero},
+    Clamp, FromColor, IsWithinBounds, Lighten, LightnessContrast, RelativeContrast, Srgb,
+};
+
+/// The number of bisection steps for each part of the search.
+const SEARCH_ITERATIONS: usize = 32;
+
+/// A method for measuring the contrast between a foreground and a background
+/// color.
+pub trait ContrastMetric<C> {
+    /// The type of the measured contrast.
+    type Scalar;
+
+    /// Return the contrast between `foreground` and `background`, as a value
+    /// that increases with the contrast.
+    #[must_use]
+    fn get_contrast(&self, foreground: C, background: C) -> Self::Scalar;
+}
+
+/// The WCAG 2.1 contrast ratio, from 1 to 21. See [`RelativeContrast`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Wcag21;
+
+impl<C> ContrastMetric<C> for Wcag21
+where
+    C: RelativeContrast,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn get_contrast(&self, foreground: C, background: C) -> Self::Scalar {
+        foreground.get_contrast_ratio(background)
+    }
+}
+
+/// The magnitude of the APCA lightness contrast, `|Lc|`, from 0 to about 108.
+/// See [`LightnessContrast`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Apca;
+
+impl<C> ContrastMetric<C> for Apca
+where
+    C: LightnessContrast,
+{
+    type Scalar = C::Scalar;
+
+    #[inline]
+    fn get_contrast(&self, foreground: C, background: C) -> Self::Scalar {
+        foreground.get_lightness_contrast(background).abs()
+    }
+}
+
+/// Find the color that is closest to `foreground`, in lightness, and that has
+/// at least the `target` contrast against `background`.
+///
+/// The foreground color is converted to the search space, `S`, such as
+/// [`Oklch`](crate::Oklch) or [`Lch`](crate::Lch), where only its lightness is
+/// changed. This keeps its hue and chroma, as far as the search space can. Both
+/// lighter and darker colors are tried, and the one with the smallest change is
+/// returned. `foreground` is returned as it is if it already has enough
+/// contrast.
+///
+/// `None` is returned if no color within the bounds of `C` has enough contrast,
+/// such as when increasing the lightness would push a saturated color out of
+/// the RGB gamut before reaching the target. The most saturated colors, such as
+/// the RGB primaries, can't keep their chroma at any other lightness and will
+/// only pass if they already have enough contrast. `foreground` is expected to
+/// be within the bounds of `C`.
+///
+/// The search assumes that the contrast increases as the lightness moves away
+/// from the background's, which is the case for the metrics in this module.
+#[must_use]
+pub fn find_contrasting_color<S, C, M>(
+    foreground: C,
+    background: C,
+    target: M::Scalar,
+    metric: M,
+) -> Option<C>
+where
+    M: ContrastMetric<C>,
+    M::Scalar: Real + Zero + One + Arithmetics + PartialCmp + HasBoolMask<Mask = bool> + Clone,
+    S: FromColorUnclamped<C> + Lighten<Scalar = M::Scalar> + Clone,
+    C: FromColorUnclamped<S> + IsWithinBounds<Mask = bool> + Clamp + Clone,
+{
+    if metric
+        .get_contrast(foreground.clone(), background.clone())
+        .gt_eq(&target)
+    {
+        return Some(foreground);
+    }
+
+    let start = S::from_color_unclamped(foreground);
+    let search =
+        |direction: M::Scalar| search_lightness(&start, &background, &target, &metric, direction);
+
+    match (search(M::Scalar::one()), search(-M::Scalar::one())) {
+        (Some((lighter_amount, lighter)), Some((darker_amount, darker))) => {
+            if lighter_amount.lt_eq(&darker_amount) {
+                Some(lighter)
+            } else {
+                Some(darker)
+            }
+        }
+        (Some((_, lighter)), None) => Some(lighter),
+        (None, Some((_, darker))) => Some(darker),
+        (None, None) => None,
+    }
+}
+
+/// Search for the smallest lightness change, in `direction`, that reaches the
+/// target contrast. Returns the size of the change and the color.
+fn search_lightness<S, C, M>(
+    start: &S,
+    background: &C,
+    target: &M::Scalar,
+    metric: &M,
+    direction: M::Scalar,
+) -> Option<(M::Scalar, C)>
+where
+    M: ContrastMetric<C>,
+    M::Scalar: Real + Zero + One + Arithmetics + PartialCmp + HasBoolMask<Mask = bool> + Clone,
+    S: Lighten<Scalar = M::Scalar> + Clone,
+    C: FromColorUnclamped<S> + IsWithinBounds<Mask = bool> + Clamp + Clone,
+{
+    let candidate = |amount: &M::Scalar| {
+        C::from_color_unclamped(start.clone().lighten_fixed(amount.clone() * &direction))
+    };
+    let passes = |color: C| -> bool {
+        metric
+            .get_contrast(color.clamp(), background.clone())
+            .gt_eq(target)
+    };
+    let half = M::Scalar::from_f64(0.5);
+
+    // Find how far the lightness can change before the color leaves the
+    // gamut. The gamut is assumed to be continuous along the lightness, and
+    // the starting point is assumed to be in it, even if converting it back
+    // and forth adds rounding errors.
+    let mut limit = M::Scalar::one();
+    if !candidate(&limit).is_within_bounds() {
+        let mut inside = M::Scalar::zero();
+        let mut outside = limit;
+
+        for _ in 0..SEARCH_ITERATIONS {
+            let middle = (inside.clone() + &outside) * &half;
+            if candidate(&middle).is_within_bounds() {
+                inside = middle;
+            } else {
+                outside = middle;
+            }
+        }
+
+        limit = inside;
+    }
+
+    if !passes(candidate(&limit)) {
+        return None;
+    }
+
+    // Find the smallest change that still passes.
+    let mut failing = M::Scalar::zero();
+    let mut passing = limit;
+
+    for _ in 0..SEARCH_ITERATIONS {
+        let middle = (failing.clone() + &passing) * &half;
+        if passes(candidate(&middle)) {
+            passing = middle;
+        } else {
+            failing = middle;
+        }
+    }
+
+    let color = candidate(&passing).clamp();
+    Some((passing, color))
+}
+
+/// Pick the most legible color from `candidates`, against `background`.
+///
+/// With a `target`, the first candidate that has at least the target contrast
+/// is picked. This makes it possible to list the candidates in order of
+/// preference. If none of them reach the target, or if there is no target, the
+/// candidate with the highest contrast is picked. The picked color is returned
+/// together with its contrast, or `None` if there are no candidates.
+///
+/// ```
+/// use palette::{
+///     contrast::{most_legible, Wcag21},
+///     Srgb,
+/// };
+///
+/// let background = Srgb::new(0.95f32, 0.9, 0.8);
+/// let brand_colors = [
+///     Srgb::new(1.0f32, 0.8, 0.0),
+///     Srgb::new(0.7, 0.15, 0.1),
+///     Srgb::new(0.1, 0.2, 0.5),
+/// ];
+///
+/// // The first brand color that passes WCAG 2.1 AA for text.
+/// let (color, ratio) = most_legible(background, brand_colors, Some(4.5), Wcag21).unwrap();
+/// assert_eq!(color, brand_colors[1]);
+/// assert!(ratio >= 4.5);
+///
+/// // The brand color with the most contrast.
+/// let (color, _) = most_legible(background, brand_colors, None, Wcag21).unwrap();
+/// assert_eq!(color, brand_colors[2]);
+/// ```
+#[must_use]
+pub fn most_legible<C, I, M>(
+    background: C,
+    candidates: I,
+    target: Option<M::Scalar>,
+    metric: M,
+) -> Option<(C, M::Scalar)>
+where
+    I: IntoIterator<Item = C>,
+    M: ContrastMetric<C>,
+    M::Scalar: PartialCmp + HasBoolMask<Mask = bool>,
+    C: Clone,
+{
+    let mut best: Option<(C, M::Scalar)> = None;
+
+    for candidate in candidates {
+        let contrast = metric.get_contrast(candidate.clone(), background.clone());
+
+        if let Some(target) = &target {
+            if contrast.gt_eq(target) {
+                return Some((candidate, contrast));
+            }
+        }
+
+        let is_better = match &best {
+            Some((_, best_contrast)) => contrast.gt(best_contrast),
+            None => true,
+        };
+
+        if is_better {
+            best = Some((candidate, contrast));
+        }
+    }
+
+    best
+}
+
+/// Pick the first color from `candidates` that has at least the `target`
+/// contrast against `background`, or black or white if none of them do.
+///
+/// Black or white is picked depending on which has the most contrast against
+/// the background. The picked color is returned together with its contrast.
+///
+/// ```
+/// use palette::{
+///     contrast::{most_legible_or_black_white, Apca},
+///     Srgb,
+/// };
+///
+/// let background = Srgb::new(0.1f32, 0.1, 0.3);
+/// let brand_colors = [Srgb::new(0.8f32, 0.2, 0.1), Srgb::new(0.1, 0.2, 0.5)];
+///
+/// // None of the brand colors are readable enough on the dark blue.
+/// let (color, _) = most_legible_or_black_white(background, brand_colors, 75.0, Apca);
+/// assert_eq!(color, Srgb::new(1.0, 1.0, 1.0));
+/// ```
+#[must_use]
+pub fn most_legible_or_black_white<C, I, M>(
+    background: C,
+    candidates: I,
+    target: M::Scalar,
+    metric: M,
+) -> (C, M::Scalar)
+where
+    I: IntoIterator<Item = C>,
+    M: ContrastMetric<C>,
+    M::Scalar: Real + PartialCmp + HasBoolMask<Mask = bool>,
+    C: FromColor<Srgb<M::Scalar>> + Clone,
+{
+    for candidate in candidates {
+        let contrast = metric.get_contrast(candidate.clone(), background.clone());
+
+        if contrast.gt_eq(&target) {
+            return (candidate, contrast);
+        }
+    }
+
+    let black = C::from_color(Srgb::new(
+        M::Scalar::from_f64(0.0),
+        M::Scalar::from_f64(0.0),
+        M::Scalar::from_f64(0.0),
+    ));
+    let white = C::from_color(Srgb::new(
+        M::Scalar::from_f64(1.0),
+        M::Scalar::from_f64(1.0),
+        M::Scalar::from_f64(1.0),
+    ));
+
+    let black_contrast = metric.get_contrast(black.clone(), background.clone());
+    let white_contrast = metric.get_contrast(white.clone(), background);
+
+    if white_contrast.gt_eq(&black_contrast) {
+        (white, white_contrast)
+    } else {
+        (black, black_contrast)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_contrasting_color, most_legible, most_legible_or_black_white, Apca, Wcag21};
+    use crate::{
+        white_point::D65, FromColor, IsWithinBounds, Lch, LightnessContrast, Oklch,
+        RelativeContrast, Srgb,
+    };
+
+    #[test]
+    fn already_passing() {
+        let foreground = Srgb::new(0.0f64, 0.0, 0.0);
+        let background = Srgb::new(1.0, 1.0, 1.0);
+
+        let result =
+            find_contrasting_color::<Oklch<f64>, _, _>(foreground, background, 7.0, Wcag21);
+        assert_eq!(result, Some(foreground));
+    }
+
+    #[test]
+    fn keeps_hue_and_chroma() {
+        let foreground = Srgb::new(0.3f64, 0.5, 0.4);
+        let background = Srgb::new(0.35, 0.45, 0.5);
+
+        let result =
+            find_contrasting_color::<Oklch<f64>, _, _>(foreground, background, 4.5, Wcag21)
+                .unwrap();
+        assert!(result.is_within_bounds());
+        assert!(result.get_contrast_ratio(background) >= 4.5);
+        // The smallest change that passes.
+        assert_relative_eq!(result.get_contrast_ratio(background), 4.5, epsilon = 0.001);
+
+        let original = Oklch::from_color(foreground);
+        let adjusted = Oklch::from_color(result);
+        assert_relative_eq!(adjusted.chroma, original.chroma, epsilon = 0.0001);
+        // The Oklab matrices are not exact inverses of each other.
+        assert_relative_eq!(adjusted.hue, original.hue, epsilon = 0.1);
+    }
+
+    #[test]
+    fn gamut_edge() {
+        // Rounding errors shouldn't prevent colors on the edge of the gamut
+        // from being adjusted.
+        let foreground = Srgb::new(1.0f32, 0.6, 0.3);
+        let background = Srgb::new(1.0, 1.0, 1.0);
+
+        let result =
+            find_contrasting_color::<Oklch<f32>, _, _>(foreground, background, 3.0, Wcag21)
+                .unwrap();
+        assert!(result.is_within_bounds());
+        assert!(result.get_contrast_ratio(background) >= 3.0);
+    }
+
+    #[test]
+    fn picks_closest_direction() {
+        let background = Srgb::new(0.5f64, 0.5, 0.5);
+
+        // Slightly lighter than the background, so going lighter is closer.
+        let foreground = Srgb::new(0.55f64, 0.55, 0.55);
+        let result =
+            find_contrasting_color::<Lch<D65, f64>, _, _>(foreground, background, 3.0, Wcag21)
+                .unwrap();
+        assert!(result.red > foreground.red);
+
+        let foreground = Srgb::new(0.45f64, 0.45, 0.45);
+        let result =
+            find_contrasting_color::<Lch<D65, f64>, _, _>(foreground, background, 3.0, Wcag21)
+                .unwrap();
+        assert!(result.red < foreground.red);
+    }
+
+    #[test]
+    fn apca() {
+        let background = Srgb::new(0.1f64, 0.1, 0.15);
+        let foreground = Srgb::new(0.3f64, 0.2, 0.25);
+
+        let result =
+            find_contrasting_color::<Oklch<f64>, _, _>(foreground, background, 75.0, Apca).unwrap();
+        assert!(result.get_lightness_contrast(background) <= -75.0);
+    }
+
+    #[test]
+    fn no_solution() {
+        let background = Srgb::new(0.5f64, 0.5, 0.5);
+        let foreground = Srgb::new(0.5f64, 0.5, 0.5);
+
+        // Higher than what black or white can get against the gray.
+        let result =
+            find_contrasting_color::<Oklch<f64>, _, _>(foreground, background, 6.0, Wcag21);
+        assert_eq!(result, None);
+
+        // A saturated blue leaves the gamut before getting light enough,
+        // and can't get dark enough against black.
+        let background = Srgb::new(0.0f64, 0.0, 0.0);
+        let foreground = Srgb::new(0.0f64, 0.0, 0.8);
+        let result =
+            find_contrasting_color::<Oklch<f64>, _, _>(foreground, background, 10.0, Wcag21);
+        assert_eq!(result, None);
+    }
+
+    #[test]
+    fn most_legible_with_target() {
+        let background = Srgb::new(1.0f64, 1.0, 1.0);
+        let candidates = [
+            Srgb::new(0.9f64, 0.9, 0.9),
+            Srgb::new(0.4, 0.4, 0.4),
+            Srgb::new(0.0, 0.0, 0.0),
+        ];
+
+        let (color, ratio) = most_legible(background, candidates, Some(4.5), Wcag21).unwrap();
+        assert_eq!(color, candidates[1]);
+        assert_relative_eq!(ratio, candidates[1].get_contrast_ratio(background));
+
+        // Falls back to the best candidate.
+        let (color, _) = most_legible(background, candidates, Some(30.0), Wcag21).unwrap();
+        assert_eq!(color, candidates[2]);
+
+        let (color, ratio) = most_legible(background, candidates, None, Wcag21).unwrap();
+        assert_eq!(color, candidates[2]);
+        assert_relative_eq!(ratio, 21.0);
+
+        assert_eq!(most_legible(background, [], None, Wcag21), None);
+    }
+
+    #[test]
+    fn most_legible_first_best() {
+        let background = Srgb::new(0.5f64, 0.5, 0.5);
+        let candidates = [Srgb::new(0.1f64, 0.1, 0.1), Srgb::new(0.1, 0.1, 0.1)];
+
+        let (color, _) = most_legible(background, candidates.iter().copied(), None, Apca).unwrap();
+        assert_eq!(color, candidates[0]);
+    }
+
+    #[test]
+    fn black_white_fallback() {
+        let candidates = [Srgb::new(0.5f64, 0.5, 0.5), Srgb::new(0.6, 0.6, 0.6)];
+
+        let background = Srgb::new(0.9f64, 0.9, 0.9);
+        let (color, ratio) = most_legible_or_black_white(background, candidates, 7.0, Wcag21);
+        assert_eq!(color, Srgb::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(ratio, background.get_contrast_ratio(color));
+
+        let background = Srgb::new(0.1f64, 0.1, 0.1);
+        let (color, _) = most_legible_or_black_white(background, candidates, 7.0, Wcag21);
+        assert_eq!(color, Srgb::new(1.0, 1.0, 1.0));
+
+        let (color, _) = most_legible_or_black_white(background, candidates, 4.0, Wcag21);
+        assert_eq!(color, candidates[0]);
+
+        // Also works with other color types.
+        let background = Lch::<D65, f64>::new(20.0, 10.0, 200.0);
+        let (color, _) = most_legible_or_black_white(background, [], 4.5, Wcag21);
+        assert_relative_eq!(color.l, 100.0, epsilon = 0.01);
+    }
+}
@@ -0,0 +1,627 @@
+//! Color vision deficiency (CVD) simulation and daltonization.
+//!
+//! A [`Simulator`] shows how linear sRGB colors look to someone with a
+//! [`Deficiency`] in one of their three types of cones. The severity goes from
+//! 0.0, for normal color vision, to 1.0, for a dichromat that is missing the
+//! cone type. Values in between simulate anomalous trichromacy, such as
+//! protanomaly.
+//!
+//! ```
+//! use palette::{
+//!     cvd::{Deficiency, Simulator},
+//!     LinSrgb,
+//! };
+//!
+//! let simulator = Simulator::machado_2009(Deficiency::Deutan, 0.6);
+//!
+//! let red = LinSrgb::new(0.8f32, 0.1, 0.1);
+//! let green = LinSrgb::new(0.3f32, 0.45, 0.1);
+//!
+//! // The colors are much closer for someone with deuteranomaly.
+//! let simulated_red = simulator.simulate(red);
+//! let simulated_green = simulator.simulate(green);
+//! assert!((simulated_red.red - simulated_green.red).abs() < (red.red - green.red).abs());
+//!
+//! // Daltonization shifts the lost contrast into channels that are visible.
+//! let daltonized_red = simulator.daltonize(red);
+//! ```
+//!
+//! There are three simulation methods to pick from:
+//!
+//! * [`Simulator::brettel_1997`] - Brettel, Viénot and Mollon (1997). Projects
+//!   the colors onto two half-planes in LMS space. This is accurate for all
+//!   three deficiencies, including tritanopia.
+//! * [`Simulator::vienot_1999`] - Viénot, Brettel and Mollon (1999). A
+//!   simplification of Brettel 1997 to a single plane and matrix, which works
+//!   well for protanopia and deuteranopia.
+//! * [`Simulator::machado_2009`] - Machado, Oliveira and Fernandes (2009).
+//!   Precomputed matrices from a physiological model, made for anomalous
+//!   trichromacy. This is a good default for severities below 1.0.
+//!
+//! Large amounts of colors, such as images, can be processed with the slice
+//! methods. [`Simulator::simulate_components`] and
+//! [`Simulator::daltonize_components`] work on flat component buffers, through
+//! the [`cast`](crate::cast) module.
+//!
+//! The results are not clamped, and may be slightly outside the sRGB gamut.
+
+use crate::{
+    bool_mask::Select,
+    cast,
+    encoding::Srgb,
+    matrix::{matrix_inverse, multiply_3x3, multiply_rgb, rgb_to_xyz_matrix},
+    num::{Arithmetics, PartialCmp, Real, Zero},
+    LinSrgb, Mat3,
+};
+
+/// A missing or anomalous type of cones.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Deficiency {
+    /// The long wavelength (L, or "red") cones. Protanopia or protanomaly.
+    Protan,
+
+    /// The medium wavelength (M, or "green") cones. Deuteranopia or
+    /// deuteranomaly.
+    Deutan,
+
+    /// The short wavelength (S, or "blue") cones. Tritanopia or tritanomaly.
+    Tritan,
+}
+
+impl Deficiency {
+    /// The index of the affected cone type in LMS.
+    fn cone(self) -> usize {
+        match self {
+            Deficiency::Protan => 0,
+            Deficiency::Deutan => 1,
+            Deficiency::Tritan => 2,
+        }
+    }
+}
+
+/// A simulation of a color vision deficiency, for linear sRGB colors.
+///
+/// The simulation matrices are precomputed by the constructors, so the same
+/// simulator can be applied to many colors. See the [module level
+/// documentation](self) for an overview of the methods.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Simulator<T> {
+    deficiency: Deficiency,
+    matrix: Mat3<T>,
+    // Brettel 1997 uses a second matrix for the colors on the negative side
+    // of a separation plane.
+    second_half: Option<(Mat3<T>, [T; 3])>,
+}
+
+impl<T> Simulator<T>
+where
+    T: Real,
+{
+    /// Create a simulator with the method by Brettel, Viénot and Mollon
+    /// (1997).
+    ///
+    /// The colors are projected along the axis of the affected cone type, onto
+    /// two half-planes in LMS space. They are anchored by white and by the
+    /// monochromatic lights that look the same to normal and dichromatic
+    /// observers: 475 nm and 575 nm for protans and deutans, and 485 nm and
+    /// 660 nm for tritans. A `severity` below 1.0 interpolates towards the
+    /// original colors.
+    #[must_use]
+    pub fn brettel_1997(deficiency: Deficiency, severity: f64) -> Self {
+        let (wing1, wing2) = match deficiency {
+            Deficiency::Protan | Deficiency::Deutan => (XYZ_475NM, XYZ_575NM),
+            Deficiency::Tritan => (XYZ_485NM, XYZ_660NM),
+        };
+
+        let cone = deficiency.cone();
+        let lms_from_rgb = lms_from_linear_srgb();
+        let white = multiply_array(&lms_from_rgb, [1.0, 1.0, 1.0]);
+        let wing1 = multiply_array(&XYZ_TO_LMS, wing1);
+        let wing2 = multiply_array(&XYZ_TO_LMS, wing2);
+
+        // The plane through the neutral axis and the axis of the affected
+        // cones separates the colors that go to each half-plane.
+        let mut axis = [0.0; 3];
+        axis[cone] = 1.0;
+        let mut separation = cross(white, axis);
+        if dot(separation, wing1) < 0.0 {
+            separation = separation.map(|value| -value);
+        }
+
+        // The separation plane normal, moved from LMS to RGB, so it can be
+        // used directly on the RGB colors.
+        let separation = core::array::from_fn(|column| {
+            (0..3)
+                .map(|row| separation[row] * lms_from_rgb[row * 3 + column])
+                .sum::<f64>()
+        });
+
+        let first = projection_matrix(&lms_from_rgb, cross(white, wing1), cone, severity);
+        let second = projection_matrix(&lms_from_rgb, cross(white, wing2), cone, severity);
+
+        Simulator {
+            deficiency,
+            matrix: first.map(T::from_f64),
+            second_half: Some((second.map(T::from_f64), separation.map(T::from_f64))),
+        }
+    }
+
+    /// Create a simulator with the method by Viénot, Brettel and Mollon
+    /// (1999).
+    ///
+    /// This is a simplification of [`brettel_1997`](Self::brettel_1997), with a
+    /// single projection plane through black, white and blue, which makes it a
+    /// single matrix. It's best suited for protans and deutans. Tritans use a
+    /// plane through red instead, which is less accurate. A `severity` below
+    /// 1.0 interpolates towards the original colors.
+    #[must_use]
+    pub fn vienot_1999(deficiency: Deficiency, severity: f64) -> Self {
+        let anchor = match deficiency {
+            Deficiency::Protan | Deficiency::Deutan => [0.0, 0.0, 1.0],
+            Deficiency::Tritan => [1.0, 0.0, 0.0],
+        };
+
+        let lms_from_rgb = lms_from_linear_srgb();
+        let white = multiply_array(&lms_from_rgb, [1.0, 1.0, 1.0]);
+        let anchor = multiply_array(&lms_from_rgb, anchor);
+        let matrix = projection_matrix(
+            &lms_from_rgb,
+            cross(white, anchor),
+            deficiency.cone(),
+            severity,
+        );
+
+        Simulator {
+            deficiency,
+            matrix: matrix.map(T::from_f64),
+            second_half: None,
+        }
+    }
+
+    /// Create a simulator with the method by Machado, Oliveira and Fernandes
+    /// (2009).
+    ///
+    /// The model simulates anomalous trichromacy by shifting the spectral
+    /// sensitivity of the affected cones, with the published matrices for
+    /// severities in steps of 0.1. Severities between the steps are linearly
+    /// interpolated.
+    #[must_use]
+    pub fn machado_2009(deficiency: Deficiency, severity: f64) -> Self {
+        let table = match deficiency {
+            Deficiency::Protan => &MACHADO_PROTAN,
+            Deficiency::Deutan => &MACHADO_DEUTAN,
+            Deficiency::Tritan => &MACHADO_TRITAN,
+        };
+
+        let position = severity.clamp(0.0, 1.0) * 10.0;
+        // The identity matrix is severity 0.0, and the table starts at 0.1.
+        let level = |index: usize| {
+            if index == 0 {
+                IDENTITY
+            } else {
+                table[index - 1]
+            }
+        };
+        let index = (position as usize).min(9);
+        let factor = position - index as f64;
+
+        let low = level(index);
+        let high = level(index + 1);
+        let matrix: Mat3<f64> = core::array::from_fn(|i| low[i] + (high[i] - low[i]) * factor);
+
+        Simulator {
+            deficiency,
+            matrix: matrix.map(T::from_f64),
+            second_half: None,
+        }
+    }
+}
+
+impl<T> Simulator<T> {
+    /// The simulated deficiency.
+    pub fn deficiency(&self) -> Deficiency {
+        self.deficiency
+    }
+}
+
+impl<T> Simulator<T>
+where
+    T: Real + Zero + Arithmetics + PartialCmp + Clone,
+    T::Mask: Select<T> + Clone,
+{
+    /// Simulate how `color` is perceived with the deficiency.
+    #[must_use]
+    pub fn simulate(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        let first = multiply_rgb(self.matrix.clone(), color.clone());
+
+        match &self.second_half {
+            Some((matrix, separation)) => {
+                let side = separation[0].clone() * &color.red
+                    + separation[1].clone() * &color.green
+                    + separation[2].clone() * &color.blue;
+                let second = multiply_rgb(matrix.clone(), color);
+                let is_first = side.gt_eq(&T::zero());
+
+                LinSrgb::new(
+                    is_first.clone().select(first.red, second.red),
+                    is_first.clone().select(first.green, second.green),
+                    is_first.select(first.blue, second.blue),
+                )
+            }
+            None => first,
+        }
+    }
+
+    /// Simulate how a slice of colors is perceived with the deficiency, in
+    /// place.
+    pub fn simulate_slice(&self, colors: &mut [LinSrgb<T>]) {
+        for color in colors {
+            *color = self.simulate(color.clone());
+        }
+    }
+
+    /// Simulate how a slice of linear sRGB components, in red, green and blue
+    /// order, is perceived with the deficiency, in place.
+    ///
+    /// ## Panics
+    ///
+    /// If the length of `components` is not a multiple of 3.
+    pub fn simulate_components(&self, components: &mut [T]) {
+        self.simulate_slice(cast::from_component_slice_mut(components));
+    }
+
+    /// Daltonize `color`, to make it easier to tell apart from other colors
+    /// with the deficiency.
+    ///
+    /// This is the method by Fidaner, Lin and Ozguven (2005). The difference
+    /// between the original and the simulated color is the information that is
+    /// lost. It's moved into the channels that are still visible and added to
+    /// the original color.
+    #[must_use]
+    pub fn daltonize(&self, color: LinSrgb<T>) -> LinSrgb<T> {
+        let simulated = self.simulate(color.clone());
+        let error = color.clone() - simulated;
+
+        let shift = match self.deficiency {
+            Deficiency::Protan => DALTONIZE_PROTAN,
+            Deficiency::Deutan => DALTONIZE_DEUTAN,
+            Deficiency::Tritan => DALTONIZE_TRITAN,
+        };
+
+        color + multiply_rgb(shift.map(T::from_f64), error)
+    }
+
+    /// Daltonize a slice of colors in place.
+    pub fn daltonize_slice(&self, colors: &mut [LinSrgb<T>]) {
+        for color in colors {
+            *color = self.daltonize(color.clone());
+        }
+    }
+
+    /// Daltonize a slice of linear sRGB components, in red, green and blue
+    /// order, in place.
+    ///
+    /// ## Panics
+    ///
+    /// If the length of `components` is not a multiple of 3.
+    pub fn daltonize_components(&self, components: &mut [T]) {
+        self.daltonize_slice(cast::from_component_slice_mut(components));
+    }
+}
+
+/// The matrix from linear sRGB to LMS.
+fn lms_from_linear_srgb() -> Mat3<f64> {
+    multiply_3x3(XYZ_TO_LMS, rgb_to_xyz_matrix::<Srgb, f64>())
+}
+
+/// The matrix that projects linear sRGB colors along the axis of `cone`, in
+/// LMS space, onto the plane with the `normal`. It's interpolated towards the
+/// identity matrix by `severity`.
+fn projection_matrix(
+    lms_from_rgb: &Mat3<f64>,
+    normal: [f64; 3],
+    cone: usize,
+    severity: f64,
+) -> Mat3<f64> {
+    let mut projection = IDENTITY;
+    for column in 0..3 {
+        projection[cone * 3 + column] = if column == cone {
+            0.0
+        } else {
+            -normal[column] / normal[cone]
+        };
+    }
+
+    let rgb_from_lms = matrix_inverse(*lms_from_rgb);
+    let matrix = multiply_3x3(rgb_from_lms, multiply_3x3(projection, *lms_from_rgb));
+
+    let severity = severity.clamp(0.0, 1.0);
+    core::array::from_fn(|i| IDENTITY[i] + (matrix[i] - IDENTITY[i]) * severity)
+}
+
+fn multiply_array(matrix: &Mat3<f64>, vector: [f64; 3]) -> [f64; 3] {
+    core::array::from_fn(|row| {
+        matrix[row * 3] * vector[0]
+            + matrix[row * 3 + 1] * vector[1]
+            + matrix[row * 3 + 2] * vector[2]
+    })
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [
+        a[1] * b[2] - a[2] * b[1],
+        a[2] * b[0] - a[0] * b[2],
+        a[0] * b[1] - a[1] * b[0],
+    ]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+const IDENTITY: Mat3<f64> = [1.0, 0.0, 0.0, 0.0, 1.0, 0.0, 0.0, 0.0, 1.0];
+
+/// The Smith and Pokorny (1975) cone fundamentals, as a matrix from XYZ to
+/// LMS.
+#[rustfmt::skip]
+const XYZ_TO_LMS: Mat3<f64> = [
+    0.15514, 0.54312, -0.03286,
+    -0.15514, 0.45684, 0.03286,
+    0.0, 0.0, 0.01608,
+];
+
+// The CIE 1931 color matching functions at the anchor wavelengths of Brettel
+// 1997.
+const XYZ_475NM: [f64; 3] = [0.1421, 0.1126, 1.0419];
+const XYZ_575NM: [f64; 3] = [0.8425, 0.9154, 0.0018];
+const XYZ_485NM: [f64; 3] = [0.0580, 0.1693, 0.6162];
+const XYZ_660NM: [f64; 3] = [0.1649, 0.0610, 0.0000];
+
+// The error shift matrices for daltonization, from Fidaner, Lin and Ozguven
+// (2005). The lost information is moved from the affected channel into the
+// other two.
+#[rustfmt::skip]
+const DALTONIZE_PROTAN: Mat3<f64> = [
+    0.0, 0.0, 0.0,
+    0.7, 1.0, 0.0,
+    0.7, 0.0, 1.0,
+];
+#[rustfmt::skip]
+const DALTONIZE_DEUTAN: Mat3<f64> = [
+    1.0, 0.7, 0.0,
+    0.0, 0.0, 0.0,
+    0.0, 0.7, 1.0,
+];
+#[rustfmt::skip]
+const DALTONIZE_TRITAN: Mat3<f64> = [
+    1.0, 0.0, 0.7,
+    0.0, 1.0, 0.7,
+    0.0, 0.0, 0.0,
+];
+
+// The matrices from Machado, Oliveira and Fernandes (2009), for the severities
+// 0.1 to 1.0.
+#[rustfmt::skip]
+const MACHADO_PROTAN: [Mat3<f64>; 10] = [
+    [0.856167, 0.182038, -0.038205, 0.029342, 0.955115, 0.015544, -0.002880, -0.001563, 1.004443],
+    [0.734766, 0.334872, -0.069637, 0.051840, 0.919198, 0.028963, -0.004928, -0.004209, 1.009137],
+    [0.630323, 0.465641, -0.095964, 0.069181, 0.890046, 0.040773, -0.006308, -0.007724, 1.014032],
+    [0.539009, 0.579343, -0.118352, 0.082546, 0.866121, 0.051332, -0.007136, -0.011959, 1.019095],
+    [0.458064, 0.679578, -0.137642, 0.092785, 0.846313, 0.060902, -0.007494, -0.016807, 1.024301],
+    [0.385450, 0.769005, -0.154455, 0.100526, 0.829802, 0.069673, -0.007442, -0.022190, 1.029632],
+    [0.319627, 0.849633, -0.169261, 0.106241, 0.815969, 0.077790, -0.007025, -0.028051, 1.035076],
+    [0.259411, 0.923008, -0.182420, 0.110296, 0.804340, 0.085364, -0.006276, -0.034346, 1.040622],
+    [0.203876, 0.990338, -0.194214, 0.112975, 0.794542, 0.092483, -0.005278, -0.041039, 1.046317],
+    [0.152286, 1.052583, -0.204868, 0.114503, 0.786281, 0.099216, -0.003882, -0.048116, 1.051998],
+];
+
+#[rustfmt::skip]
+const MACHADO_DEUTAN: [Mat3<f64>; 10] = [
+    [0.866435, 0.177704, -0.044139, 0.049567, 0.939063, 0.011370, -0.003453, 0.007233, 0.996220],
+    [0.760729, 0.319078, -0.079807, 0.090568, 0.889315, 0.020117, -0.006027, 0.013325, 0.992702],
+    [0.675425, 0.433850, -0.109275, 0.125303, 0.847755, 0.026942, -0.007950, 0.018572, 0.989378],
+    [0.605511, 0.528560, -0.134071, 0.155318, 0.812366, 0.032316, -0.009376, 0.023176, 0.986200],
+    [0.547494, 0.607765, -0.155259, 0.181692, 0.781742, 0.036566, -0.010410, 0.027275, 0.983136],
+    [0.498864, 0.674741, -0.173604, 0.205199, 0.754872, 0.039929, -0.011131, 0.030969, 0.980162],
+    [0.457771, 0.731899, -0.189670, 0.226409, 0.731012, 0.042579, -0.011595, 0.034333, 0.977261],
+    [0.422823, 0.781057, -0.203881, 0.245752, 0.709602, 0.044646, -0.011843, 0.037423, 0.974421],
+    [0.392952, 0.823610, -0.216562, 0.263559, 0.690210, 0.046232, -0.011910, 0.040281, 0.971630],
+    [0.367322, 0.860646, -0.227968, 0.280085, 0.672501, 0.047413, -0.011820, 0.042940, 0.968881],
+];
+
+#[rustfmt::skip]
+const MACHADO_TRITAN: [Mat3<f64>; 10] = [
+    [0.926670, 0.092514, -0.019184, 0.021191, 0.964503, 0.014306, 0.008437, 0.054813, 0.936750],
+    [0.895720, 0.133330, -0.029050, 0.029997, 0.945400, 0.024603, 0.013027, 0.104707, 0.882266],
+    [0.905871, 0.127791, -0.033662, 0.026856, 0.941251, 0.031893, 0.013410, 0.148296, 0.838294],
+    [0.948035, 0.089490, -0.037526, 0.014364, 0.946792, 0.038844, 0.010853, 0.193991, 0.795156],
+    [1.017277, 0.027029, -0.044306, -0.006113, 0.958479, 0.047634, 0.006379, 0.248708, 0.744913],
+    [1.104996, -0.046633, -0.058363, -0.032137, 0.971635, 0.060503, 0.001336, 0.317922, 0.680742],
+    [1.193214, -0.109812, -0.083402, -0.058496, 0.979410, 0.079086, -0.002346, 0.403492, 0.598854],
+    [1.257728, -0.139648, -0.118081, -0.078003, 0.975409, 0.102594, -0.003316, 0.501214, 0.502102],
+    [1.278864, -0.125333, -0.153531, -0.084748, 0.957674, 0.127074, -0.000989, 0.601151, 0.399838],
+    [1.255528, -0.076749, -0.178779, -0.078411, 0.930809, 0.147602, 0.004733, 0.691367, 0.303900],
+];
+
+#[cfg(test)]
+mod test {
+    use super::{Deficiency, Simulator};
+    use crate::LinSrgb;
+
+    const DEFICIENCIES: [Deficiency; 3] =
+        [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan];
+
+    fn simulators(severity: f64) -> [Simulator<f64>; 9] {
+        let [protan, deutan, tritan] = DEFICIENCIES;
+        [
+            Simulator::brettel_1997(protan, severity),
+            Simulator::brettel_1997(deutan, severity),
+            Simulator::brettel_1997(tritan, severity),
+            Simulator::vienot_1999(protan, severity),
+            Simulator::vienot_1999(deutan, severity),
+            Simulator::vienot_1999(tritan, severity),
+            Simulator::machado_2009(protan, severity),
+            Simulator::machado_2009(deutan, severity),
+            Simulator::machado_2009(tritan, severity),
+        ]
+    }
+
+    #[test]
+    fn neutral_colors() {
+        for simulator in simulators(1.0) {
+            for value in [0.0, 0.2, 0.5, 1.0] {
+                let gray = LinSrgb::new(value, value, value);
+                assert_relative_eq!(simulator.simulate(gray), gray, epsilon = 0.0001);
+                assert_relative_eq!(simulator.daltonize(gray), gray, epsilon = 0.0001);
+            }
+        }
+    }
+
+    #[test]
+    fn zero_severity() {
+        let color = LinSrgb::new(0.8, 0.3, 0.1);
+
+        for simulator in simulators(0.0) {
+            assert_relative_eq!(simulator.simulate(color), color, epsilon = 0.000001);
+            assert_relative_eq!(simulator.daltonize(color), color, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn machado_full_severity() {
+        let simulator = Simulator::<f64>::machado_2009(Deficiency::Protan, 1.0);
+        assert_relative_eq!(
+            simulator.simulate(LinSrgb::new(1.0, 0.0, 0.0)),
+            LinSrgb::new(0.152286, 0.114503, -0.003882)
+        );
+    }
+
+    #[test]
+    fn machado_interpolation() {
+        let low = Simulator::<f64>::machado_2009(Deficiency::Deutan, 0.5);
+        let high = Simulator::<f64>::machado_2009(Deficiency::Deutan, 0.6);
+        let middle = Simulator::<f64>::machado_2009(Deficiency::Deutan, 0.55);
+
+        let color = LinSrgb::new(0.1, 0.7, 0.3);
+        let expected = (low.simulate(color) + high.simulate(color)) * 0.5;
+        assert_relative_eq!(middle.simulate(color), expected, epsilon = 0.000001);
+    }
+
+    #[test]
+    fn vienot_dichromat_plane() {
+        // Protanopes and deuteranopes see red and green on a yellow-blue axis,
+        // where the simulated red and green components are equal.
+        for deficiency in [Deficiency::Protan, Deficiency::Deutan] {
+            let simulator = Simulator::<f64>::vienot_1999(deficiency, 1.0);
+
+            for color in [
+                LinSrgb::new(1.0, 0.0, 0.0),
+                LinSrgb::new(0.0, 1.0, 0.0),
+                LinSrgb::new(0.3, 0.6, 0.2),
+            ] {
+                let simulated = simulator.simulate(color);
+                assert_relative_eq!(simulated.red, simulated.green, epsilon = 0.0001);
+            }
+
+            // Blue is on the plane.
+            let blue = LinSrgb::new(0.0, 0.0, 1.0);
+            assert_relative_eq!(simulator.simulate(blue), blue, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn brettel_is_close_to_machado() {
+        for deficiency in [Deficiency::Protan, Deficiency::Deutan] {
+            let brettel = Simulator::<f64>::brettel_1997(deficiency, 1.0);
+            let machado = Simulator::<f64>::machado_2009(deficiency, 1.0);
+
+            for color in [
+                LinSrgb::new(1.0, 0.0, 0.0),
+                LinSrgb::new(0.2, 0.6, 0.1),
+                LinSrgb::new(0.5, 0.4, 0.3),
+            ] {
+                assert_relative_eq!(
+                    brettel.simulate(color),
+                    machado.simulate(color),
+                    epsilon = 0.2
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn brettel_half_planes() {
+        let simulator = Simulator::<f64>::brettel_1997(Deficiency::Tritan, 1.0);
+
+        // Colors on each side of the separation plane are projected with
+        // different matrices.
+        let (_, separation) = simulator.second_half.as_ref().unwrap();
+        let side = |color: LinSrgb<f64>| {
+            separation[0] * color.red + separation[1] * color.green + separation[2] * color.blue
+        };
+        let red = LinSrgb::new(1.0, 0.0, 0.0);
+        let green = LinSrgb::new(0.0, 1.0, 0.0);
+        assert!(side(red) * side(green) < 0.0);
+
+        // Tritanopes confuse blue and green.
+        let blue = simulator.simulate(LinSrgb::new(0.0, 0.0, 1.0));
+        let teal = simulator.simulate(LinSrgb::new(0.0, 0.5, 0.5));
+        assert!(blue.blue < teal.blue);
+    }
+
+    #[test]
+    fn daltonize_increases_contrast() {
+        let simulator = Simulator::<f64>::machado_2009(Deficiency::Deutan, 1.0);
+
+        let red = LinSrgb::new(0.6, 0.2, 0.1);
+        let green = LinSrgb::new(0.3, 0.4, 0.1);
+
+        let difference = |a: LinSrgb<f64>, b: LinSrgb<f64>| {
+            let a = simulator.simulate(a);
+            let b = simulator.simulate(b);
+            (a.red - b.red).abs() + (a.green - b.green).abs() + (a.blue - b.blue).abs()
+        };
+
+        let before = difference(red, green);
+        let after = difference(simulator.daltonize(red), simulator.daltonize(green));
+        assert!(after > before);
+    }
+
+    #[test]
+    fn slices() {
+        let simulator = Simulator::<f32>::brettel_1997(Deficiency::Protan, 0.8);
+        let colors = [
+            LinSrgb::new(0.8f32, 0.3, 0.1),
+            LinSrgb::new(0.1, 0.3, 0.8),
+            LinSrgb::new(0.5, 0.5, 0.5),
+        ];
+
+        let mut simulated = colors;
+        simulator.simulate_slice(&mut simulated);
+        let mut daltonized = colors;
+        simulator.daltonize_slice(&mut daltonized);
+
+        let mut components = [0.8f32, 0.3, 0.1, 0.1, 0.3, 0.8, 0.5, 0.5, 0.5];
+        simulator.simulate_components(&mut components);
+        assert_eq!(
+            crate::cast::into_array(simulated[1]),
+            [components[3], components[4], components[5]]
+        );
+
+        let mut components = [0.8f32, 0.3, 0.1, 0.1, 0.3, 0.8, 0.5, 0.5, 0.5];
+        simulator.daltonize_components(&mut components);
+
+        for (index, color) in colors.iter().enumerate() {
+            assert_eq!(simulated[index], simulator.simulate(*color));
+            assert_eq!(daltonized[index], simulator.daltonize(*color));
+            assert_eq!(
+                daltonized[index],
+                LinSrgb::new(
+                    components[index * 3],
+                    components[index * 3 + 1],
+                    components[index * 3 + 2]
+                )
+            );
+        }
+    }
+}
@@ -5,12 +5,28 @@
 //! represented as type parameters in Palette, as a form of type branding, to
 //! prevent accidental mixups.
 
-pub use self::gamma::{F2p2, Gamma};
+pub use self::aces::{Aces2065, AcesCc, AcesCct, AcesCg};
+pub use self::adobe::AdobeRgb;
+pub use self::gamma::{F2p2, F2p6, Gamma};
+pub use self::hlg::Hlg;
 pub use self::linear::Linear;
+pub use self::p3::{DciP3, DisplayP3};
+pub use self::parametric::{Parametric, ParametricCurve};
+pub use self::pq::Pq;
+pub use self::prophoto::ProPhotoRgb;
+pub use self::rec_standards::{Bt1886, Rec2020, Rec709, RecOetf};
 pub use self::srgb::Srgb;
 
+pub mod aces;
+pub mod adobe;
 pub mod gamma;
+pub mod hlg;
 pub mod linear;
+pub mod p3;
+pub mod parametric;
+pub mod pq;
+pub mod prophoto;
+pub mod rec_standards;
 pub mod srgb;
 
 /// A transfer function from linear space.
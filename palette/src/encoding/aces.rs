@@ -0,0 +1,388 @@
+//! The Academy Color Encoding System (ACES) color spaces.
+//!
+//! ACES defines two sets of primaries and a few encodings of them:
+//!
+//! * [`Aces2065`] is linear with the [`Ap0`] primaries, which cover the whole
+//!   visible gamut. It's used for exchanging and archiving images.
+//! * [`AcesCg`] is linear with the [`Ap1`] primaries. It's used for rendering
+//!   and compositing.
+//! * [`AcesCc`] and [`AcesCct`] are logarithmic encodings of the [`Ap1`]
+//!   primaries, used for color grading.
+//!
+//! They all have the [`Aces`] white point.
+
+use crate::{
+    bool_mask::LazySelect,
+    encoding::{linear::LinearFn, FromLinear, IntoLinear},
+    num::{Arithmetics, Exp, Ln, PartialCmp, Real},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Aces, Any},
+    Yxy,
+};
+
+/// The ACES AP0 primaries.
+///
+/// The primaries enclose the whole spectral locus, which makes the green and
+/// blue primaries imaginary colors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ap0;
+
+impl<T: Real> Primaries<T> for Ap0 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.7347),
+            T::from_f64(0.2653),
+            T::from_f64(0.3439664498),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.0),
+            T::from_f64(1.0),
+            T::from_f64(0.7281660966),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.0001),
+            T::from_f64(-0.0770),
+            T::from_f64(-0.0721325464),
+        )
+    }
+}
+
+/// The ACES AP1 primaries.
+///
+/// The primaries are close to the spectral locus, but all of them are real
+/// colors.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Ap1;
+
+impl<T: Real> Primaries<T> for Ap1 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.713),
+            T::from_f64(0.293),
+            T::from_f64(0.2722287168),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.165),
+            T::from_f64(0.830),
+            T::from_f64(0.6740817658),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.128),
+            T::from_f64(0.044),
+            T::from_f64(0.0536895174),
+        )
+    }
+}
+
+/// The ACES2065-1 standard and color space, with linear [`Ap0`] components.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Aces2065;
+
+impl RgbSpace for Aces2065 {
+    type Primaries = Ap0;
+    type WhitePoint = Aces;
+}
+
+impl RgbStandard for Aces2065 {
+    type Space = Aces2065;
+    type TransferFn = LinearFn;
+}
+
+/// The ACEScg standard and color space, with linear [`Ap1`] components.
+///
+/// ```
+/// use palette::{encoding::AcesCg, white_point::Aces, FromColor, Xyz};
+/// use palette::rgb::Rgb;
+///
+/// let cg = Rgb::<AcesCg, f32>::new(0.18, 0.18, 0.18);
+/// let xyz = Xyz::<Aces, f32>::from_color(cg);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AcesCg;
+
+impl RgbSpace for AcesCg {
+    type Primaries = Ap1;
+    type WhitePoint = Aces;
+}
+
+impl RgbStandard for AcesCg {
+    type Space = AcesCg;
+    type TransferFn = LinearFn;
+}
+
+/// `2^-16`, the lowest linear value ACEScc encodes.
+const ACESCC_MIN: f64 = 1.52587890625e-5;
+
+/// The largest half precision float, where ACEScc and ACEScct are clamped.
+const HALF_MAX: f64 = 65504.0;
+
+/// `(log2(HALF_MAX) + 9.72) / 17.52`.
+const HALF_MAX_ENCODED: f64 = 1.4679963120447153;
+
+/// The ACEScc standard and transfer function, with logarithmic [`Ap1`]
+/// components, from Academy S-2014-003.
+///
+/// ACEScc is a pure logarithmic encoding, where linear 0.18 is encoded as
+/// about 0.4136. Values at and below zero can't be represented and are
+/// encoded as the lowest value, about -0.3584.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AcesCc;
+
+impl RgbStandard for AcesCc {
+    type Space = AcesCg;
+    type TransferFn = AcesCc;
+}
+
+impl<T> IntoLinear<T, T> for AcesCc
+where
+    T: Real + Exp + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        lazy_select! {
+            if encoded.lt(&T::from_f64((9.72 - 15.0) / 17.52)) => {
+                (exp2(encoded.clone() * T::from_f64(17.52) - T::from_f64(9.72))
+                    - T::from_f64(ACESCC_MIN))
+                    * T::from_f64(2.0)
+            },
+            if encoded.lt(&T::from_f64(HALF_MAX_ENCODED)) => {
+                exp2(encoded.clone() * T::from_f64(17.52) - T::from_f64(9.72))
+            },
+            else => T::from_f64(HALF_MAX),
+        }
+    }
+}
+
+impl<T> FromLinear<T, T> for AcesCc
+where
+    T: Real + Ln + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        lazy_select! {
+            if linear.lt_eq(&T::from_f64(0.0)) => {
+                T::from_f64((-16.0 + 9.72) / 17.52)
+            },
+            if linear.lt(&T::from_f64(ACESCC_MIN * 2.0)) => {
+                (log2(T::from_f64(ACESCC_MIN) + linear.clone() * T::from_f64(0.5))
+                    + T::from_f64(9.72))
+                    / T::from_f64(17.52)
+            },
+            else => (log2(linear.clone()) + T::from_f64(9.72)) / T::from_f64(17.52),
+        }
+    }
+}
+
+const ACESCCT_X_BREAK: f64 = 0.0078125;
+const ACESCCT_Y_BREAK: f64 = 0.155251141552511;
+const ACESCCT_A: f64 = 10.5402377416545;
+const ACESCCT_B: f64 = 0.0729055341958355;
+
+/// The ACEScct standard and transfer function, with logarithmic [`Ap1`]
+/// components, from Academy S-2016-001.
+///
+/// ACEScct is the same as [`AcesCc`] above linear 0.0078125, but has a linear
+/// toe below it. This makes it behave more like the log encodings of cameras
+/// when grading the shadows.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AcesCct;
+
+impl RgbStandard for AcesCct {
+    type Space = AcesCg;
+    type TransferFn = AcesCct;
+}
+
+impl<T> IntoLinear<T, T> for AcesCct
+where
+    T: Real + Exp + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        lazy_select! {
+            if encoded.lt_eq(&T::from_f64(ACESCCT_Y_BREAK)) => {
+                (encoded.clone() - T::from_f64(ACESCCT_B)) / T::from_f64(ACESCCT_A)
+            },
+            if encoded.lt(&T::from_f64(HALF_MAX_ENCODED)) => {
+                exp2(encoded.clone() * T::from_f64(17.52) - T::from_f64(9.72))
+            },
+            else => T::from_f64(HALF_MAX),
+        }
+    }
+}
+
+impl<T> FromLinear<T, T> for AcesCct
+where
+    T: Real + Ln + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        lazy_select! {
+            if linear.lt_eq(&T::from_f64(ACESCCT_X_BREAK)) => {
+                T::from_f64(ACESCCT_A) * &linear + T::from_f64(ACESCCT_B)
+            },
+            else => (log2(linear.clone()) + T::from_f64(9.72)) / T::from_f64(17.52),
+        }
+    }
+}
+
+#[inline]
+fn exp2<T>(x: T) -> T
+where
+    T: Real + Exp + Arithmetics,
+{
+    (x * T::from_f64(core::f64::consts::LN_2)).exp()
+}
+
+#[inline]
+fn log2<T>(x: T) -> T
+where
+    T: Real + Ln + Arithmetics,
+{
+    x.ln() * T::from_f64(core::f64::consts::LOG2_E)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Aces2065, AcesCc, AcesCct, AcesCg};
+    use crate::{
+        convert::FromColorUnclamped,
+        encoding::{FromLinear, IntoLinear},
+        matrix::rgb_to_xyz_matrix,
+        rgb::{Aces2065Rgb, AcesCcRgb, AcesCctRgb, AcesCgRgb},
+        white_point::{Aces, WhitePoint},
+        Xyz,
+    };
+
+    #[test]
+    fn ap0_to_xyz() {
+        // Academy TB-2014-004
+        let dynamic = rgb_to_xyz_matrix::<Aces2065, f64>();
+        let constant = [
+            0.9525523959,
+            0.0000000000,
+            0.0000936786,
+            0.3439664498,
+            0.7281660966,
+            -0.0721325464,
+            0.0000000000,
+            0.0000000000,
+            1.0088251844,
+        ];
+        assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn ap1_to_xyz() {
+        // Academy TB-2014-004
+        let dynamic = rgb_to_xyz_matrix::<AcesCg, f64>();
+        let constant = [
+            0.6624541811,
+            0.1340042065,
+            0.1561876870,
+            0.2722287168,
+            0.6740817658,
+            0.0536895174,
+            -0.0055746495,
+            0.0040607335,
+            1.0103391003,
+        ];
+        assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn acescg_to_aces2065() {
+        let red = Aces2065Rgb::from_color_unclamped(AcesCgRgb::new(1.0f64, 0.0, 0.0));
+        assert_relative_eq!(
+            red,
+            Aces2065Rgb::new(0.6954522414, 0.0447945634, -0.0055258826),
+            epsilon = 0.000001
+        );
+
+        let white = Xyz::<Aces, f64>::from_color_unclamped(AcesCgRgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(
+            white,
+            Aces::get_xyz().with_white_point(),
+            epsilon = 0.000001
+        );
+    }
+
+    #[test]
+    fn acescc_reference_values() {
+        assert_relative_eq!(
+            <AcesCc as FromLinear<f64, f64>>::from_linear(0.18),
+            0.4135884,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <AcesCc as FromLinear<f64, f64>>::from_linear(0.0),
+            -0.3584475,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <AcesCc as FromLinear<f64, f64>>::from_linear(-1.0),
+            -0.3584475,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(<AcesCc as IntoLinear<f64, f64>>::into_linear(2.0), 65504.0);
+
+        for &linear in &[0.00001f64, 0.00002, 0.001, 0.18, 1.0, 100.0] {
+            let encoded: f64 = AcesCc::from_linear(linear);
+            let decoded: f64 = AcesCc::into_linear(encoded);
+            assert_relative_eq!(decoded, linear, max_relative = 0.0000001);
+        }
+    }
+
+    #[test]
+    fn acescct_reference_values() {
+        assert_relative_eq!(
+            <AcesCct as FromLinear<f64, f64>>::from_linear(0.18),
+            0.4135884,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <AcesCct as FromLinear<f64, f64>>::from_linear(0.0),
+            0.0729055,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <AcesCct as FromLinear<f64, f64>>::from_linear(0.0078125),
+            0.1552511,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(<AcesCct as IntoLinear<f64, f64>>::into_linear(2.0), 65504.0);
+
+        for &linear in &[-0.001f64, 0.0, 0.001, 0.0078125, 0.18, 1.0, 100.0] {
+            let encoded: f64 = AcesCct::from_linear(linear);
+            let decoded: f64 = AcesCct::into_linear(encoded);
+            assert_relative_eq!(
+                decoded,
+                linear,
+                epsilon = 0.0000001,
+                max_relative = 0.0000001
+            );
+        }
+    }
+
+    #[test]
+    fn log_encodings_share_acescg() {
+        let cg = AcesCgRgb::new(0.18f64, 0.5, 2.0);
+        let cc = AcesCcRgb::from_color_unclamped(cg);
+        let cct = AcesCctRgb::from_color_unclamped(cg);
+
+        assert_relative_eq!(AcesCgRgb::from_color_unclamped(cc), cg, epsilon = 0.000001);
+        assert_relative_eq!(AcesCgRgb::from_color_unclamped(cct), cg, epsilon = 0.000001);
+        assert_relative_eq!(cc.red, cct.red, epsilon = 0.0000001);
+    }
+}
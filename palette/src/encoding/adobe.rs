@@ -0,0 +1,121 @@
+//! The Adobe RGB (1998) standard.
+
+use crate::{
+    encoding::gamma::{GammaFn, Number},
+    luma::LumaStandard,
+    num::Real,
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, D65},
+    Yxy,
+};
+
+/// The Adobe RGB (1998) standard, color space, and transfer function.
+///
+/// Adobe RGB has a wider gamut than sRGB, mainly in the greens, and is common
+/// in photography and print workflows. It has a D65 white point and is
+/// encoded with a pure gamma of [`AdobeRgbGamma`].
+///
+/// Its white point differs from the D50 white point used for print and ICC
+/// profiles, so it's chromatically adapted when converted to `Xyz<D50>` or
+/// `Lab<D50>`:
+///
+/// ```
+/// use palette::{rgb::AdobeRgb, white_point::D50, FromColor, Lab, Xyz};
+///
+/// let color = AdobeRgb::new(0.8f32, 0.3, 0.1);
+/// let xyz = Xyz::<D50, f32>::from_color(color);
+/// let lab = Lab::<D50, f32>::from_color(color);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdobeRgb;
+
+impl<T: Real> Primaries<T> for AdobeRgb {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(T::from_f64(0.64), T::from_f64(0.33), T::from_f64(0.297377))
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(T::from_f64(0.21), T::from_f64(0.71), T::from_f64(0.627349))
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(T::from_f64(0.15), T::from_f64(0.06), T::from_f64(0.075274))
+    }
+}
+
+impl RgbSpace for AdobeRgb {
+    type Primaries = AdobeRgb;
+    type WhitePoint = D65;
+}
+
+impl RgbStandard for AdobeRgb {
+    type Space = AdobeRgb;
+    type TransferFn = GammaFn<AdobeRgbGamma>;
+}
+
+impl LumaStandard for AdobeRgb {
+    type WhitePoint = D65;
+    type TransferFn = GammaFn<AdobeRgbGamma>;
+}
+
+/// Represents `563.0 / 256.0`, or `2.19921875f64`, the gamma of Adobe RGB
+/// (1998).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct AdobeRgbGamma;
+
+impl Number for AdobeRgbGamma {
+    const VALUE: f64 = 563.0 / 256.0;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        convert::{FromColor, FromColorUnclamped},
+        encoding::AdobeRgb,
+        matrix::rgb_to_xyz_matrix,
+        rgb::{AdobeRgb as AdobeRgbColor, LinAdobeRgb},
+        white_point::{WhitePoint, D50},
+        Lab, Srgb, Xyz,
+    };
+
+    #[test]
+    fn adobe_rgb_to_xyz() {
+        let dynamic = rgb_to_xyz_matrix::<AdobeRgb, f64>();
+        let constant = [
+            0.5767309, 0.1855540, 0.1881852, 0.2973769, 0.6273491, 0.0752741, 0.0270343, 0.0706872,
+            0.9911085,
+        ];
+        assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.000001);
+    }
+
+    #[test]
+    fn gamma() {
+        let linear = LinAdobeRgb::from_color_unclamped(AdobeRgbColor::new(0.5f64, 0.0, 1.0));
+        assert_relative_eq!(
+            linear,
+            LinAdobeRgb::new(0.5f64.powf(563.0 / 256.0), 0.0, 1.0),
+            epsilon = 0.0000001
+        );
+    }
+
+    #[test]
+    fn srgb_in_adobe_rgb() {
+        let white = AdobeRgbColor::from_color_unclamped(Srgb::new(1.0f64, 1.0, 1.0));
+        assert_relative_eq!(white, AdobeRgbColor::new(1.0, 1.0, 1.0), epsilon = 0.00001);
+
+        // sRGB green is inside the Adobe RGB gamut, but not saturated in it.
+        let green = LinAdobeRgb::from_color_unclamped(Srgb::new(0.0f64, 1.0, 0.0).into_linear());
+        assert_relative_eq!(
+            green,
+            LinAdobeRgb::new(0.2848, 1.0, 0.0412),
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn adapt_to_d50() {
+        let white = Xyz::<D50, f64>::from_color(AdobeRgbColor::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, D50::get_xyz().with_white_point(), epsilon = 0.00001);
+
+        let lab = Lab::<D50, f64>::from_color(AdobeRgbColor::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(lab, Lab::new(100.0, 0.0, 0.0), epsilon = 0.001);
+    }
+}
@@ -44,7 +44,7 @@ where
 /// The transfer function for gamma encoded colors.
 ///
 /// Conversion is performed using a single `powf(x, gamma)` and `powf(x, 1.0 /
-/// gamma)` call, for from and into linear respectively. This makes
+/// gamma)` call, for into and from linear respectively. This makes
 /// `GammaFn<F2p2>` usable as a slightly less expensive approximation of the
 /// [`Srgb`][super::Srgb] transfer function.
 ///
@@ -55,23 +55,23 @@ pub struct GammaFn<N: Number = F2p2>(PhantomData<N>);
 
 impl<T, N> IntoLinear<T, T> for GammaFn<N>
 where
-    T: Real + One + Powf + Div<Output = T>,
+    T: Real + Powf,
     N: Number,
 {
     #[inline]
     fn into_linear(x: T) -> T {
-        x.powf(T::one() / T::from_f64(N::VALUE))
+        x.powf(T::from_f64(N::VALUE))
     }
 }
 
 impl<T, N> FromLinear<T, T> for GammaFn<N>
 where
-    T: Real + Powf,
+    T: Real + One + Powf + Div<Output = T>,
     N: Number,
 {
     #[inline]
     fn from_linear(x: T) -> T {
-        x.powf(T::from_f64(N::VALUE))
+        x.powf(T::one() / T::from_f64(N::VALUE))
     }
 }
 
@@ -88,3 +88,42 @@ pub struct F2p2;
 impl Number for F2p2 {
     const VALUE: f64 = 2.2;
 }
+
+/// Represents `2.6f64`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct F2p6;
+
+impl Number for F2p6 {
+    const VALUE: f64 = 2.6;
+}
+
+/// Represents `0.0f64`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct F0;
+
+impl Number for F0 {
+    const VALUE: f64 = 0.0;
+}
+
+/// Represents `100.0f64`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct F100;
+
+impl Number for F100 {
+    const VALUE: f64 = 100.0;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{rgb::GammaSrgb, LinSrgb, Srgb};
+
+    #[test]
+    fn approximates_srgb() {
+        let linear = LinSrgb::new(0.0f64, 0.2, 0.5);
+        let gamma = GammaSrgb::from_linear(linear);
+        let srgb = Srgb::from_linear(linear);
+
+        assert_relative_eq!(gamma, srgb.reinterpret_as(), epsilon = 0.02);
+        assert_relative_eq!(gamma.into_linear(), linear, epsilon = 0.0000001);
+    }
+}
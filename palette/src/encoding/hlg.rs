@@ -0,0 +1,287 @@
+//! The ARIB STD-B67 hybrid log-gamma (HLG) transfer function.
+//!
+//! [`Hlg`] can be combined with any RGB space to make an
+//! [`RgbStandard`](crate::rgb::RgbStandard), for example `(Rec2020, Hlg)` for
+//! BT.2100 HLG:
+//!
+//! ```
+//! use palette::{
+//!     encoding::{hlg::HlgOotf, Hlg, Rec2020},
+//!     rgb::{LinRec2020, Rgb},
+//!     FromColor,
+//! };
+//!
+//! let signal = Rgb::<(Rec2020, Hlg), f32>::new(0.75, 0.75, 0.75);
+//! let scene = LinRec2020::from_color(signal);
+//!
+//! // The light from a 1000 cd/m² display, in cd/m².
+//! let display = HlgOotf::new(1000.0).apply(scene);
+//! ```
+
+use crate::{
+    bool_mask::LazySelect,
+    encoding::{FromLinear, IntoLinear, Linear},
+    num::{Arithmetics, Exp, Ln, MinMax, PartialCmp, Powf, Real, Sqrt, Zero},
+    rgb::{Primaries, Rgb, RgbSpace},
+};
+
+const A: f64 = 0.17883277;
+const B: f64 = 0.28466892; // 1 - 4 * A
+const C: f64 = 0.55991073; // 0.5 - A * ln(4 * A)
+
+/// The ARIB STD-B67 hybrid log-gamma (HLG) transfer function, as used in
+/// ITU-R BT.2100.
+///
+/// HLG is scene referred and relative. This implements the HLG OETF and its
+/// inverse, where the linear values are normalized scene light from 0.0 to
+/// 1.0, and the encoded values are in the range from 0.0 to 1.0. Use
+/// [`HlgOotf`] to turn the scene light into display light.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Hlg;
+
+impl<T> IntoLinear<T, T> for Hlg
+where
+    T: Real + Exp + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        lazy_select! {
+            if encoded.lt_eq(&T::from_f64(0.5)) => encoded.clone() * &encoded / T::from_f64(3.0),
+            else => (((encoded.clone() - T::from_f64(C)) / T::from_f64(A)).exp() + T::from_f64(B))
+                / T::from_f64(12.0),
+        }
+    }
+}
+
+impl<T> FromLinear<T, T> for Hlg
+where
+    T: Real + Zero + Sqrt + Ln + MinMax + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        let linear = linear.max(T::zero());
+
+        lazy_select! {
+            if linear.lt_eq(&T::from_f64(1.0 / 12.0)) => (T::from_f64(3.0) * &linear).sqrt(),
+            else => T::from_f64(A) * (T::from_f64(12.0) * &linear - T::from_f64(B)).ln()
+                + T::from_f64(C),
+        }
+    }
+}
+
+/// The HLG opto-optical transfer function (OOTF), from ITU-R BT.2100.
+///
+/// The OOTF turns the scene light from [`Hlg`] into display light, in cd/m².
+/// It's applied to the luminance of the color, which keeps the hue and
+/// saturation intact, while adapting the contrast to the display through the
+/// system gamma:
+///
+/// `display = peak_luminance * Y^(system_gamma - 1) * scene`
+///
+/// where `Y` is the relative luminance of the scene light. The black level of
+/// the display is assumed to be zero.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct HlgOotf<T> {
+    /// The nominal peak luminance of the display, in cd/m².
+    pub peak_luminance: T,
+
+    /// The system gamma, which is 1.2 for the reference display with a peak
+    /// luminance of 1000 cd/m².
+    pub system_gamma: T,
+}
+
+impl<T> HlgOotf<T>
+where
+    T: Real + Ln + Arithmetics + Clone,
+{
+    /// Create an OOTF for a display with the nominal peak luminance
+    /// `peak_luminance`, in cd/m².
+    ///
+    /// The system gamma is `1.2 + 0.42 * log10(peak_luminance / 1000)`, as
+    /// recommended by BT.2100 for displays between 400 and 2000 cd/m².
+    pub fn new(peak_luminance: T) -> Self {
+        let system_gamma = T::from_f64(1.2)
+            + T::from_f64(0.42 / core::f64::consts::LN_10)
+                * (peak_luminance.clone() / T::from_f64(1000.0)).ln();
+
+        HlgOotf {
+            peak_luminance,
+            system_gamma,
+        }
+    }
+}
+
+impl<T> HlgOotf<T> {
+    /// Create an OOTF with a custom system gamma, for example to adapt to the
+    /// viewing environment.
+    pub const fn with_system_gamma(peak_luminance: T, system_gamma: T) -> Self {
+        HlgOotf {
+            peak_luminance,
+            system_gamma,
+        }
+    }
+}
+
+impl<T> HlgOotf<T>
+where
+    T: Real + Zero + Powf + MinMax + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    /// Turn linear scene light, as decoded by [`Hlg`], into display light in
+    /// cd/m².
+    #[must_use]
+    pub fn apply<S>(&self, scene: Rgb<Linear<S>, T>) -> Rgb<Linear<S>, T>
+    where
+        S: RgbSpace,
+        S::Primaries: Primaries<T>,
+    {
+        let luminance = luminance::<S, T>(&scene);
+        let exponent = self.system_gamma.clone() - T::from_f64(1.0);
+
+        let gain = lazy_select! {
+            if luminance.gt(&T::zero()) => {
+                self.peak_luminance.clone() * luminance.clone().powf(exponent.clone())
+            },
+            else => T::zero(),
+        };
+
+        scene * gain
+    }
+
+    /// Turn display light in cd/m² into linear scene light, which can be
+    /// encoded with [`Hlg`]. This is the inverse of [`apply`](Self::apply).
+    #[must_use]
+    pub fn apply_inverse<S>(&self, display: Rgb<Linear<S>, T>) -> Rgb<Linear<S>, T>
+    where
+        S: RgbSpace,
+        S::Primaries: Primaries<T>,
+    {
+        let luminance = luminance::<S, T>(&display) / &self.peak_luminance;
+        let exponent = (T::from_f64(1.0) - &self.system_gamma) / &self.system_gamma;
+
+        let gain = lazy_select! {
+            if luminance.gt(&T::zero()) => {
+                luminance.clone().powf(exponent.clone()) / &self.peak_luminance
+            },
+            else => T::zero(),
+        };
+
+        display * gain
+    }
+}
+
+/// The relative luminance of linear RGB, clamped to be at least zero.
+fn luminance<S, T>(color: &Rgb<Linear<S>, T>) -> T
+where
+    S: RgbSpace,
+    S::Primaries: Primaries<T>,
+    T: Zero + MinMax + Arithmetics + Clone,
+{
+    let luminance = S::Primaries::red().luma * &color.red
+        + S::Primaries::green().luma * &color.green
+        + S::Primaries::blue().luma * &color.blue;
+
+    luminance.max(T::zero())
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Hlg, HlgOotf};
+    use crate::{
+        encoding::{FromLinear, IntoLinear, Rec2020},
+        rgb::{LinRec2020, Rgb},
+        FromColor,
+    };
+
+    #[test]
+    fn reference_values() {
+        assert_relative_eq!(<Hlg as FromLinear<f64, f64>>::from_linear(0.0), 0.0);
+        assert_relative_eq!(
+            <Hlg as FromLinear<f64, f64>>::from_linear(1.0 / 12.0),
+            0.5,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <Hlg as FromLinear<f64, f64>>::from_linear(1.0),
+            1.0,
+            epsilon = 0.0000001
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &linear in &[0.0f64, 0.01, 1.0 / 12.0, 0.26496, 0.5, 1.0] {
+            let encoded: f64 = Hlg::from_linear(linear);
+            let decoded: f64 = Hlg::into_linear(encoded);
+            assert_relative_eq!(decoded, linear, epsilon = 0.0000001);
+        }
+    }
+
+    #[test]
+    fn system_gamma() {
+        assert_relative_eq!(HlgOotf::new(1000.0f64).system_gamma, 1.2);
+        assert_relative_eq!(
+            HlgOotf::new(2000.0f64).system_gamma,
+            1.3264,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            HlgOotf::new(400.0f64).system_gamma,
+            1.0329,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn ootf() {
+        let ootf = HlgOotf::new(1000.0f64);
+
+        let white = ootf.apply(LinRec2020::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(
+            white,
+            LinRec2020::new(1000.0, 1000.0, 1000.0),
+            epsilon = 0.000001
+        );
+
+        // The BT.2408 HDR reference white, 75% HLG, is 203 cd/m².
+        let signal = Rgb::<(Rec2020, Hlg), f64>::new(0.75, 0.75, 0.75);
+        let reference_white = ootf.apply(LinRec2020::from_color(signal));
+        assert_relative_eq!(reference_white.red, 203.0, epsilon = 0.5);
+
+        let black = ootf.apply(LinRec2020::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(black, LinRec2020::new(0.0, 0.0, 0.0));
+        assert_relative_eq!(ootf.apply_inverse(black), black);
+
+        let scene = LinRec2020::new(0.1, 0.5, 0.02);
+        let display = HlgOotf::with_system_gamma(500.0, 1.1).apply(scene);
+        assert_relative_eq!(
+            HlgOotf::with_system_gamma(500.0, 1.1).apply_inverse(display),
+            scene,
+            epsilon = 0.000001
+        );
+    }
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn simd() {
+        let linear = [0.0f64, 0.01, 0.26496, 1.0];
+        let simd_linear = wide::f64x4::from(linear);
+
+        let encoded: [f64; 4] = Hlg::from_linear(simd_linear).into();
+        let decoded: [f64; 4] = Hlg::into_linear(wide::f64x4::from(encoded)).into();
+        for ((&linear, encoded), decoded) in linear.iter().zip(encoded).zip(decoded) {
+            assert_relative_eq!(encoded, Hlg::from_linear(linear), epsilon = 0.0000001);
+            assert_relative_eq!(decoded, linear, epsilon = 0.0000001);
+        }
+
+        let encoded: [f32; 4] =
+            Hlg::from_linear(wide::f32x4::from([0.0, 0.01, 0.26496, 1.0])).into();
+        assert_relative_eq!(
+            encoded[..],
+            [0.0, 0.1732051, 0.75, 1.0][..],
+            epsilon = 0.00001
+        );
+    }
+}
@@ -0,0 +1,177 @@
+//! The P3 standards: Display P3 and DCI-P3.
+
+use crate::{
+    encoding::{
+        gamma::{F2p6, GammaFn},
+        Srgb,
+    },
+    luma::LumaStandard,
+    num::Real,
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, Dci, D65},
+    Yxy,
+};
+
+/// The Display P3 standard, color space, and transfer function.
+///
+/// Display P3 uses the DCI-P3 primaries with a D65 white point and the sRGB
+/// transfer function. It's the wide gamut color space of many phones,
+/// computers and the web (`color(display-p3 ...)` in CSS).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DisplayP3;
+
+impl<T: Real> Primaries<T> for DisplayP3 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.680),
+            T::from_f64(0.320),
+            T::from_f64(0.228975),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.265),
+            T::from_f64(0.690),
+            T::from_f64(0.691739),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.150),
+            T::from_f64(0.060),
+            T::from_f64(0.079287),
+        )
+    }
+}
+
+impl RgbSpace for DisplayP3 {
+    type Primaries = DisplayP3;
+    type WhitePoint = D65;
+}
+
+impl RgbStandard for DisplayP3 {
+    type Space = DisplayP3;
+    type TransferFn = Srgb;
+}
+
+impl LumaStandard for DisplayP3 {
+    type WhitePoint = D65;
+    type TransferFn = Srgb;
+}
+
+/// The DCI-P3 standard, color space, and transfer function, from SMPTE RP
+/// 431-2.
+///
+/// DCI-P3 is the color space of digital cinema projection. It has the
+/// [`Dci`] white point and is encoded with a pure 2.6 gamma.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct DciP3;
+
+impl<T: Real> Primaries<T> for DciP3 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.680),
+            T::from_f64(0.320),
+            T::from_f64(0.209492),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.265),
+            T::from_f64(0.690),
+            T::from_f64(0.721595),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.150),
+            T::from_f64(0.060),
+            T::from_f64(0.068913),
+        )
+    }
+}
+
+impl RgbSpace for DciP3 {
+    type Primaries = DciP3;
+    type WhitePoint = Dci;
+}
+
+impl RgbStandard for DciP3 {
+    type Space = DciP3;
+    type TransferFn = GammaFn<F2p6>;
+}
+
+impl LumaStandard for DciP3 {
+    type WhitePoint = Dci;
+    type TransferFn = GammaFn<F2p6>;
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        convert::FromColorUnclamped,
+        encoding::{DciP3, DisplayP3},
+        matrix::rgb_to_xyz_matrix,
+        rgb::{DciP3Rgb, DisplayP3Rgb, LinDciP3},
+        white_point::Dci,
+        Oklab, Srgb, Xyz,
+    };
+
+    #[test]
+    fn display_p3_to_xyz() {
+        let dynamic = rgb_to_xyz_matrix::<DisplayP3, f64>();
+        let constant = [
+            0.486569, 0.265673, 0.198187, 0.228973, 0.691752, 0.079275, 0.000000, 0.045114,
+            1.043786,
+        ];
+        assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0002);
+    }
+
+    #[test]
+    fn dci_p3_to_xyz() {
+        let dynamic = rgb_to_xyz_matrix::<DciP3, f64>();
+        let constant = [
+            0.445170, 0.277134, 0.172283, 0.209492, 0.721595, 0.068913, 0.000000, 0.047061,
+            0.907355,
+        ];
+        assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0001);
+    }
+
+    #[test]
+    fn srgb_in_display_p3() {
+        let red = DisplayP3Rgb::from_color_unclamped(Srgb::new(1.0f64, 0.0, 0.0));
+        assert_relative_eq!(
+            red,
+            DisplayP3Rgb::new(0.917488, 0.200287, 0.138561),
+            epsilon = 0.0001
+        );
+
+        let white = DisplayP3Rgb::from_color_unclamped(Srgb::new(1.0f64, 1.0, 1.0));
+        assert_relative_eq!(white, DisplayP3Rgb::new(1.0, 1.0, 1.0), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn display_p3_to_oklab() {
+        let p3 = DisplayP3Rgb::new(0.8f64, 0.3, 0.5);
+        let oklab = Oklab::from_color_unclamped(p3);
+        assert_relative_eq!(
+            DisplayP3Rgb::from_color_unclamped(oklab),
+            p3,
+            epsilon = 0.000001
+        );
+    }
+
+    #[test]
+    fn dci_p3_gamma() {
+        let encoded = DciP3Rgb::new(0.5f64, 0.5, 0.5);
+        let linear = LinDciP3::from_color_unclamped(encoded);
+        assert_relative_eq!(
+            linear,
+            LinDciP3::new(0.164938, 0.164938, 0.164938),
+            epsilon = 0.000001
+        );
+
+        let white = Xyz::<Dci, f64>::from_color_unclamped(DciP3Rgb::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Xyz::new(0.89459, 1.0, 0.95442), epsilon = 0.0001);
+    }
+}
@@ -0,0 +1,261 @@
+//! Parametric transfer functions, as in ICC profiles.
+
+use crate::{
+    bool_mask::LazySelect,
+    num::{Arithmetics, One, PartialCmp, Powf, Real, Zero},
+};
+
+/// Marks colors as encoded with a [`ParametricCurve`].
+///
+/// The parameters of the curve are only known at runtime, so `Parametric`
+/// doesn't implement [`IntoLinear`](crate::encoding::IntoLinear) and
+/// [`FromLinear`](crate::encoding::FromLinear). Colors with this transfer
+/// function are instead converted with the `*_with` methods, such as
+/// [`Rgb::into_linear_with`](crate::rgb::Rgb::into_linear_with), that take
+/// the curve as an argument:
+///
+/// ```
+/// use palette::{
+///     encoding::{Parametric, ParametricCurve, Srgb},
+///     rgb::Rgb,
+///     LinSrgb,
+/// };
+///
+/// // A pure 1.8 gamma, as from a `curv` or `para` tag.
+/// let curve = ParametricCurve::gamma(1.8f32);
+///
+/// let encoded = Rgb::<(Srgb, Parametric), f32>::new(0.8, 0.3, 0.1);
+/// let linear: LinSrgb = encoded.into_linear_with(&curve);
+/// let encoded_again = Rgb::<(Srgb, Parametric), f32>::from_linear_with(linear, &curve);
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Parametric;
+
+/// A transfer function with runtime parameters, from the
+/// `parametricCurveType` of the ICC specification.
+///
+/// The curve converts an encoded value `x` into a linear value `y`, using the
+/// seven parameters `g`, `a`, `b`, `c`, `d`, `e` and `f`:
+///
+/// * `y = (a * x + b)^g + e`, if `x >= d`,
+/// * `y = c * x + f`, if `x < d`.
+///
+/// The five function types from the ICC specification are special cases of
+/// this, and have their own constructors. The inverse is used for going from
+/// linear to encoded values.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ParametricCurve<T> {
+    /// The exponent of the power segment.
+    pub g: T,
+
+    /// The scale of the power segment's input.
+    pub a: T,
+
+    /// The offset of the power segment's input.
+    pub b: T,
+
+    /// The slope of the linear segment.
+    pub c: T,
+
+    /// The encoded value where the power segment starts.
+    pub d: T,
+
+    /// The offset of the power segment's output.
+    pub e: T,
+
+    /// The offset of the linear segment's output.
+    pub f: T,
+}
+
+impl<T> ParametricCurve<T> {
+    /// Create a curve from all seven parameters. This is ICC function type 4.
+    pub const fn new(g: T, a: T, b: T, c: T, d: T, e: T, f: T) -> Self {
+        ParametricCurve {
+            g,
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+        }
+    }
+}
+
+impl<T> ParametricCurve<T>
+where
+    T: Zero + One + Arithmetics + Clone,
+{
+    /// Create a pure gamma curve, `y = x^g`. This is ICC function type 0, and
+    /// also what a PNG `gAMA` chunk describes, with `g = 1 / gamma`.
+    ///
+    /// Negative values are encoded as 0.0.
+    pub fn gamma(g: T) -> Self {
+        Self::new(
+            g,
+            T::one(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+            T::zero(),
+        )
+    }
+
+    /// Create a CIE 122-1966 curve, `y = (a * x + b)^g`, that is 0.0 below `x
+    /// = -b / a`. This is ICC function type 1.
+    pub fn cie_122(g: T, a: T, b: T) -> Self {
+        let d = -(b.clone() / &a);
+        Self::new(g, a, b, T::zero(), d, T::zero(), T::zero())
+    }
+
+    /// Create an IEC 61966-3 curve, `y = (a * x + b)^g + c`, that is `c` below
+    /// `x = -b / a`. This is ICC function type 2.
+    pub fn iec_61966_3(g: T, a: T, b: T, c: T) -> Self {
+        let d = -(b.clone() / &a);
+        Self::new(g, a, b, T::zero(), d, c.clone(), c)
+    }
+
+    /// Create an IEC 61966-2-1 curve, `y = (a * x + b)^g`, with a linear
+    /// segment `y = c * x` below `x = d`. This is ICC function type 3, and is
+    /// the shape of the sRGB transfer function.
+    pub fn iec_61966_2_1(g: T, a: T, b: T, c: T, d: T) -> Self {
+        Self::new(g, a, b, c, d, T::zero(), T::zero())
+    }
+}
+
+impl<T> ParametricCurve<T>
+where
+    T: Real + Zero + One + Arithmetics + Clone,
+{
+    /// Create a curve with the parameters of the sRGB transfer function.
+    pub fn srgb() -> Self {
+        Self::iec_61966_2_1(
+            T::from_f64(2.4),
+            T::from_f64(1.0 / 1.055),
+            T::from_f64(0.055 / 1.055),
+            T::from_f64(1.0 / 12.92),
+            T::from_f64(0.04045),
+        )
+    }
+}
+
+impl<T> ParametricCurve<T>
+where
+    T: Zero + One + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    /// Convert `encoded` into linear space.
+    #[must_use]
+    #[inline]
+    pub fn into_linear(&self, encoded: T) -> T {
+        lazy_select! {
+            if encoded.gt_eq(&self.d) => {
+                (self.a.clone() * &encoded + &self.b).powf(self.g.clone()) + &self.e
+            },
+            else => self.c.clone() * &encoded + &self.f,
+        }
+    }
+
+    /// Convert `linear` from linear space, using the inverse of the curve.
+    ///
+    /// Linear values that the curve can't produce are encoded as the nearest
+    /// value it can produce. That's `d` when the linear segment is flat.
+    #[must_use]
+    #[inline]
+    pub fn from_linear(&self, linear: T) -> T {
+        let power_start = (self.a.clone() * &self.d + &self.b).powf(self.g.clone()) + &self.e;
+
+        lazy_select! {
+            if linear.gt_eq(&power_start) => {
+                ((linear.clone() - &self.e).powf(T::one() / &self.g) - &self.b) / &self.a
+            },
+            if self.c.eq(&T::zero()) => self.d.clone(),
+            else => (linear.clone() - &self.f) / &self.c,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Parametric, ParametricCurve};
+    use crate::{
+        encoding::{FromLinear, IntoLinear, Srgb},
+        luma::Luma,
+        rgb::Rgb,
+        white_point::D65,
+        LinLuma, LinSrgb,
+    };
+
+    #[test]
+    fn srgb() {
+        let curve = ParametricCurve::<f64>::srgb();
+
+        for &encoded in &[0.0, 0.01, 0.04045, 0.1, 0.5, 1.0] {
+            let linear: f64 = Srgb::into_linear(encoded);
+            assert_relative_eq!(curve.into_linear(encoded), linear, epsilon = 0.0000001);
+        }
+
+        for &linear in &[0.0, 0.001, 0.0031308, 0.1, 0.5, 1.0] {
+            let encoded: f64 = Srgb::from_linear(linear);
+            assert_relative_eq!(curve.from_linear(linear), encoded, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn function_types() {
+        let gamma = ParametricCurve::gamma(2.2f64);
+        assert_relative_eq!(gamma.into_linear(0.5), 0.5f64.powf(2.2));
+        assert_relative_eq!(gamma.into_linear(-0.5), 0.0);
+        assert_relative_eq!(gamma.from_linear(-0.5), 0.0);
+
+        let cie_122 = ParametricCurve::cie_122(2.0f64, 2.0, -0.5);
+        assert_relative_eq!(cie_122.d, 0.25);
+        assert_relative_eq!(cie_122.into_linear(0.75), 1.0);
+        assert_relative_eq!(cie_122.into_linear(0.1), 0.0);
+        assert_relative_eq!(cie_122.from_linear(0.0), 0.25);
+
+        let iec_61966_3 = ParametricCurve::iec_61966_3(2.0f64, 2.0, -0.5, 0.1);
+        assert_relative_eq!(iec_61966_3.into_linear(0.75), 1.1);
+        assert_relative_eq!(iec_61966_3.into_linear(0.1), 0.1);
+        assert_relative_eq!(iec_61966_3.from_linear(0.0), 0.25);
+
+        let general = ParametricCurve::new(2.0f64, 1.0, 0.0, 0.5, 0.2, 0.1, 0.05);
+        assert_relative_eq!(general.into_linear(0.5), 0.35);
+        assert_relative_eq!(general.into_linear(0.1), 0.1);
+
+        for curve in &[gamma, cie_122, iec_61966_3, general] {
+            for &encoded in &[0.3, 0.5, 0.8, 1.0] {
+                let linear = curve.into_linear(encoded);
+                assert_relative_eq!(curve.from_linear(linear), encoded, epsilon = 0.0000001);
+            }
+        }
+    }
+
+    #[test]
+    fn rgb_and_luma() {
+        let curve = ParametricCurve::<f64>::srgb();
+
+        let rgb = Rgb::<(Srgb, Parametric), f64>::new(0.8, 0.3, 0.1);
+        let linear: LinSrgb<f64> = rgb.into_linear_with(&curve);
+        assert_relative_eq!(
+            linear,
+            crate::Srgb::new(0.8, 0.3, 0.1).into_linear(),
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            Rgb::<(Srgb, Parametric), f64>::from_linear_with(linear, &curve),
+            rgb,
+            epsilon = 0.0000001
+        );
+
+        let luma = Luma::<(D65, Parametric), f64>::new(0.5);
+        let linear: LinLuma<D65, f64> = luma.into_linear_with(&curve);
+        assert_relative_eq!(linear.luma, 0.2140411, epsilon = 0.0000001);
+        assert_relative_eq!(
+            Luma::<(D65, Parametric), f64>::from_linear_with(linear, &curve),
+            luma,
+            epsilon = 0.0000001
+        );
+    }
+}
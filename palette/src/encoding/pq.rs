@@ -0,0 +1,132 @@
+//! The SMPTE ST 2084 perceptual quantizer (PQ).
+//!
+//! [`Pq`] can be combined with any RGB space to make an
+//! [`RgbStandard`](crate::rgb::RgbStandard), for example `(Rec2020, Pq)` for
+//! HDR10 and BT.2100 PQ. The linear values are then in cd/m²:
+//!
+//! ```
+//! use palette::{
+//!     encoding::{Pq, Rec2020},
+//!     rgb::{LinRec2020, Rgb},
+//!     FromColor,
+//! };
+//!
+//! let hdr10 = Rgb::<(Rec2020, Pq), f32>::from_color(LinRec2020::new(203.0, 203.0, 203.0));
+//! assert!((hdr10.red - 0.58).abs() < 0.001);
+//! ```
+
+use crate::{
+    encoding::{FromLinear, IntoLinear},
+    num::{Arithmetics, MinMax, One, Powf, Real, Zero},
+};
+
+const M1: f64 = 2610.0 / 16384.0;
+const M2: f64 = 2523.0 / 4096.0 * 128.0;
+const C1: f64 = 3424.0 / 4096.0;
+const C2: f64 = 2413.0 / 4096.0 * 32.0;
+const C3: f64 = 2392.0 / 4096.0 * 32.0;
+
+/// The maximum luminance that can be represented by PQ, in cd/m².
+pub const MAX_LUMINANCE: f64 = 10000.0;
+
+/// The SMPTE ST 2084 perceptual quantizer (PQ) transfer function, as used in
+/// HDR10 and ITU-R BT.2100.
+///
+/// PQ is display referred and absolute. The linear values are luminances in
+/// cd/m² (nits), from 0.0 to 10000.0, while the encoded values are in the
+/// range from 0.0 to 1.0.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Pq;
+
+impl<T> IntoLinear<T, T> for Pq
+where
+    T: Real + Zero + Powf + MinMax + Arithmetics + Clone,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        let encoded = encoded.max(T::zero()).powf(T::from_f64(1.0 / M2));
+        let numerator = (encoded.clone() - T::from_f64(C1)).max(T::zero());
+        let denominator = T::from_f64(C2) - T::from_f64(C3) * encoded;
+
+        T::from_f64(MAX_LUMINANCE) * (numerator / denominator).powf(T::from_f64(1.0 / M1))
+    }
+}
+
+impl<T> FromLinear<T, T> for Pq
+where
+    T: Real + Zero + One + Powf + MinMax + Arithmetics + Clone,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        let linear = (linear / T::from_f64(MAX_LUMINANCE))
+            .max(T::zero())
+            .powf(T::from_f64(M1));
+
+        ((T::from_f64(C1) + T::from_f64(C2) * &linear) / (T::one() + T::from_f64(C3) * linear))
+            .powf(T::from_f64(M2))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::Pq;
+    use crate::encoding::{FromLinear, IntoLinear};
+
+    #[test]
+    fn reference_values() {
+        assert_relative_eq!(
+            <Pq as FromLinear<f64, f64>>::from_linear(0.0),
+            0.0,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            <Pq as FromLinear<f64, f64>>::from_linear(100.0),
+            0.5080784,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <Pq as FromLinear<f64, f64>>::from_linear(10000.0),
+            1.0,
+            epsilon = 0.0000001
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &luminance in &[0.0f64, 0.01, 1.0, 100.0, 203.0, 1000.0, 10000.0] {
+            let encoded: f64 = Pq::from_linear(luminance);
+            let decoded: f64 = Pq::into_linear(encoded);
+            assert_relative_eq!(decoded, luminance, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn negative() {
+        let decoded: f64 = Pq::into_linear(-0.1);
+        assert_eq!(decoded, 0.0);
+
+        let encoded: f64 = Pq::from_linear(-1.0);
+        assert_relative_eq!(encoded, 0.0, epsilon = 0.000001);
+    }
+
+    #[cfg(feature = "wide")]
+    #[test]
+    fn simd() {
+        let luminance = [0.0f64, 1.0, 203.0, 10000.0];
+
+        let encoded: [f64; 4] = Pq::from_linear(wide::f64x4::from(luminance)).into();
+        let decoded: [f64; 4] = Pq::into_linear(wide::f64x4::from(encoded)).into();
+        for ((&luminance, encoded), decoded) in luminance.iter().zip(encoded).zip(decoded) {
+            assert_relative_eq!(encoded, Pq::from_linear(luminance), epsilon = 0.0000001);
+            assert_relative_eq!(decoded, luminance, epsilon = 0.000001);
+        }
+
+        let encoded: [f32; 4] =
+            Pq::from_linear(wide::f32x4::from([0.0, 100.0, 1000.0, 10000.0])).into();
+        assert_relative_eq!(
+            encoded[..],
+            [0.0, 0.5080784, 0.7518271, 1.0][..],
+            epsilon = 0.00001
+        );
+    }
+}
@@ -0,0 +1,153 @@
+//! The ProPhoto RGB (ROMM RGB) standard.
+
+use crate::{
+    bool_mask::LazySelect,
+    encoding::{FromLinear, IntoLinear},
+    luma::LumaStandard,
+    num::{Arithmetics, PartialCmp, Powf, Real},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, D50},
+    Yxy,
+};
+
+/// The ProPhoto RGB standard, color space, and transfer function, also known
+/// as ROMM RGB, from ISO 22028-2.
+///
+/// ProPhoto RGB has a very wide gamut, with two imaginary primaries, and is
+/// used for editing and archiving photos. It has a D50 white point, so it can
+/// be converted directly to `Xyz<D50>` and `Lab<D50>`:
+///
+/// ```
+/// use palette::{rgb::ProPhotoRgb, white_point::D50, FromColor, Lab};
+///
+/// let lab = Lab::<D50, f32>::from_color(ProPhotoRgb::new(0.8f32, 0.3, 0.1));
+/// ```
+///
+/// # As transfer function
+///
+/// `ProPhotoRgb` can be used as a stand-alone transfer function, which is a
+/// 1.8 gamma with a short linear segment near black.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ProPhotoRgb;
+
+impl<T: Real> Primaries<T> for ProPhotoRgb {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.7347),
+            T::from_f64(0.2653),
+            T::from_f64(0.288040),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.1596),
+            T::from_f64(0.8404),
+            T::from_f64(0.711874),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.0366),
+            T::from_f64(0.0001),
+            T::from_f64(0.000086),
+        )
+    }
+}
+
+impl RgbSpace for ProPhotoRgb {
+    type Primaries = ProPhotoRgb;
+    type WhitePoint = D50;
+}
+
+impl RgbStandard for ProPhotoRgb {
+    type Space = ProPhotoRgb;
+    type TransferFn = ProPhotoRgb;
+}
+
+impl LumaStandard for ProPhotoRgb {
+    type WhitePoint = D50;
+    type TransferFn = ProPhotoRgb;
+}
+
+/// The linear value where the linear segment ends.
+const LINEAR_END: f64 = 1.0 / 512.0;
+
+impl<T> IntoLinear<T, T> for ProPhotoRgb
+where
+    T: Real + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        lazy_select! {
+            if encoded.lt(&T::from_f64(16.0 * LINEAR_END)) => encoded.clone() / T::from_f64(16.0),
+            else => encoded.clone().powf(T::from_f64(1.8)),
+        }
+    }
+}
+
+impl<T> FromLinear<T, T> for ProPhotoRgb
+where
+    T: Real + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        lazy_select! {
+            if linear.lt(&T::from_f64(LINEAR_END)) => T::from_f64(16.0) * &linear,
+            else => linear.clone().powf(T::from_f64(1.0 / 1.8)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        convert::FromColorUnclamped,
+        encoding::{FromLinear, IntoLinear, ProPhotoRgb},
+        matrix::rgb_to_xyz_matrix,
+        rgb::{LinProPhotoRgb, ProPhotoRgb as ProPhotoRgbColor},
+        white_point::D50,
+        Lab, Xyz,
+    };
+
+    #[test]
+    fn prophoto_rgb_to_xyz() {
+        let dynamic = rgb_to_xyz_matrix::<ProPhotoRgb, f64>();
+        let constant = [
+            0.7976749, 0.1351917, 0.0313534, 0.2880402, 0.7118741, 0.0000857, 0.0000000, 0.0000000,
+            0.8252100,
+        ];
+        assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.000001);
+    }
+
+    #[test]
+    fn transfer_function() {
+        assert_relative_eq!(
+            <ProPhotoRgb as FromLinear<f64, f64>>::from_linear(0.001),
+            0.016
+        );
+        assert_relative_eq!(
+            <ProPhotoRgb as FromLinear<f64, f64>>::from_linear(0.18),
+            0.18f64.powf(1.0 / 1.8)
+        );
+
+        for &linear in &[0.0f64, 0.001, 1.0 / 512.0, 0.01, 0.18, 0.5, 1.0] {
+            let encoded: f64 = ProPhotoRgb::from_linear(linear);
+            let decoded: f64 = ProPhotoRgb::into_linear(encoded);
+            assert_relative_eq!(decoded, linear, epsilon = 0.0000001);
+        }
+    }
+
+    #[test]
+    fn to_d50() {
+        let white = Xyz::<D50, f64>::from_color_unclamped(ProPhotoRgbColor::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(white, Xyz::new(0.96422, 1.0, 0.82521), epsilon = 0.00001);
+
+        let lab = Lab::<D50, f64>::from_color_unclamped(ProPhotoRgbColor::new(1.0, 1.0, 1.0));
+        assert_relative_eq!(lab, Lab::new(100.0, 0.0, 0.0), epsilon = 0.001);
+
+        let red = Xyz::<D50, f64>::from_color_unclamped(LinProPhotoRgb::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, Xyz::new(0.7976749, 0.2880402, 0.0), epsilon = 0.000001);
+    }
+}
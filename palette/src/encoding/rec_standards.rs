@@ -0,0 +1,347 @@
+//! The ITU-R BT.709 and BT.2020 standards, and the BT.1886 display transfer
+//! function.
+
+use core::marker::PhantomData;
+
+use crate::{
+    bool_mask::LazySelect,
+    encoding::{
+        gamma::{Number, F0, F100},
+        FromLinear, IntoLinear, Srgb,
+    },
+    luma::LumaStandard,
+    num::{Arithmetics, MinMax, One, PartialCmp, Powf, Real, Zero},
+    rgb::{Primaries, RgbSpace, RgbStandard},
+    white_point::{Any, D65},
+    Yxy,
+};
+
+/// The ITU-R BT.709 standard, color space, and transfer function.
+///
+/// BT.709 is the standard for HD video. It has the same primaries and white
+/// point as sRGB, but is encoded with the BT.709 OETF, [`RecOetf`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec709;
+
+impl RgbSpace for Rec709 {
+    type Primaries = Srgb;
+    type WhitePoint = D65;
+}
+
+impl RgbStandard for Rec709 {
+    type Space = Rec709;
+    type TransferFn = RecOetf;
+}
+
+impl LumaStandard for Rec709 {
+    type WhitePoint = D65;
+    type TransferFn = RecOetf;
+}
+
+/// The ITU-R BT.2020 standard, color space, and transfer function.
+///
+/// BT.2020 is the standard for UHD video, with a wider gamut than BT.709. It's
+/// encoded with the same OETF as BT.709, [`RecOetf`]. Its primaries are also
+/// used by BT.2100 for HDR video.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Rec2020;
+
+impl<T: Real> Primaries<T> for Rec2020 {
+    fn red() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.708),
+            T::from_f64(0.292),
+            T::from_f64(0.262700),
+        )
+    }
+    fn green() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.170),
+            T::from_f64(0.797),
+            T::from_f64(0.677998),
+        )
+    }
+    fn blue() -> Yxy<Any, T> {
+        Yxy::new(
+            T::from_f64(0.131),
+            T::from_f64(0.046),
+            T::from_f64(0.059302),
+        )
+    }
+}
+
+impl RgbSpace for Rec2020 {
+    type Primaries = Rec2020;
+    type WhitePoint = D65;
+}
+
+impl RgbStandard for Rec2020 {
+    type Space = Rec2020;
+    type TransferFn = RecOetf;
+}
+
+impl LumaStandard for Rec2020 {
+    type WhitePoint = D65;
+    type TransferFn = RecOetf;
+}
+
+const ALPHA: f64 = 1.09929682680944;
+const BETA: f64 = 0.018053968510807;
+
+/// The opto-electronic transfer function (OETF) of ITU-R BT.709 and BT.2020.
+///
+/// The OETF encodes scene light, which makes it a camera side transfer
+/// function. The constants are the precise ones from BT.2020, which describe
+/// the same curve as the rounded constants in BT.709.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct RecOetf;
+
+impl<T> IntoLinear<T, T> for RecOetf
+where
+    T: Real + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        lazy_select! {
+            if encoded.lt(&T::from_f64(4.5 * BETA)) => encoded.clone() / T::from_f64(4.5),
+            else => ((encoded.clone() + T::from_f64(ALPHA - 1.0)) / T::from_f64(ALPHA))
+                .powf(T::from_f64(1.0 / 0.45)),
+        }
+    }
+}
+
+impl<T> FromLinear<T, T> for RecOetf
+where
+    T: Real + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        lazy_select! {
+            if linear.lt(&T::from_f64(BETA)) => T::from_f64(4.5) * &linear,
+            else => T::from_f64(ALPHA) * linear.clone().powf(T::from_f64(0.45))
+                - T::from_f64(ALPHA - 1.0),
+        }
+    }
+}
+
+/// The reference electro-optical transfer function (EOTF) of ITU-R BT.1886.
+///
+/// The EOTF describes how a display turns an encoded BT.709 or BT.2020 signal
+/// into light. It's a 2.4 gamma, adjusted for the white luminance `Lw` and
+/// black luminance `Lb` of the display, in cd/m². The linear values are
+/// relative to the white luminance, so 1.0 is white and `Lb / Lw` is black.
+///
+/// The default is a display with 100 cd/m² white and a perfect black, where
+/// the EOTF becomes a pure 2.4 gamma. Other displays can be described with
+/// custom [`Number`] types:
+///
+/// ```
+/// use palette::encoding::{gamma::Number, Bt1886, IntoLinear};
+///
+/// struct White;
+/// impl Number for White {
+///     const VALUE: f64 = 120.0;
+/// }
+///
+/// struct Black;
+/// impl Number for Black {
+///     const VALUE: f64 = 0.05;
+/// }
+///
+/// type Display = Bt1886<White, Black>;
+///
+/// let black: f64 = Display::into_linear(0.0);
+/// assert!((black - 0.05 / 120.0).abs() < 1e-10);
+/// ```
+///
+/// Combine it with a color space, like `(Rec709, Bt1886)`, to use it as an
+/// [`RgbStandard`](crate::rgb::RgbStandard).
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Bt1886<Lw: Number = F100, Lb: Number = F0>(PhantomData<(Lw, Lb)>);
+
+impl<Lw, Lb> Bt1886<Lw, Lb>
+where
+    Lw: Number,
+    Lb: Number,
+{
+    /// The user gain, `a`, and black level lift, `b`, relative to `Lw`.
+    #[inline]
+    fn parameters<T>() -> (T, T)
+    where
+        T: Real + One + Powf + Arithmetics + Clone,
+    {
+        let exponent = T::one() / T::from_f64(2.4);
+        let white_root = T::one();
+        let black_root = T::from_f64(Lb::VALUE / Lw::VALUE).powf(exponent);
+        let range = white_root - &black_root;
+
+        let gain = range.clone().powf(T::from_f64(2.4));
+        let lift = black_root / range;
+
+        (gain, lift)
+    }
+}
+
+impl<T, Lw, Lb> IntoLinear<T, T> for Bt1886<Lw, Lb>
+where
+    T: Real + Zero + One + Powf + MinMax + Arithmetics + Clone,
+    Lw: Number,
+    Lb: Number,
+{
+    #[inline]
+    fn into_linear(encoded: T) -> T {
+        let (gain, lift) = Self::parameters::<T>();
+
+        gain * (encoded + lift).max(T::zero()).powf(T::from_f64(2.4))
+    }
+}
+
+impl<T, Lw, Lb> FromLinear<T, T> for Bt1886<Lw, Lb>
+where
+    T: Real + Zero + One + Powf + MinMax + Arithmetics + Clone,
+    Lw: Number,
+    Lb: Number,
+{
+    #[inline]
+    fn from_linear(linear: T) -> T {
+        let (gain, lift) = Self::parameters::<T>();
+
+        (linear / gain)
+            .max(T::zero())
+            .powf(T::one() / T::from_f64(2.4))
+            - lift
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Bt1886, RecOetf};
+    use crate::{
+        convert::FromColorUnclamped,
+        encoding::{gamma::Number, FromLinear, IntoLinear, Rec2020},
+        matrix::rgb_to_xyz_matrix,
+        rgb::{LinRec2020, Rec2020Rgb, Rec709Rgb},
+        LinSrgb, Srgb,
+    };
+
+    #[test]
+    fn oetf_reference_values() {
+        // BT.709-6, item 1.2
+        assert_relative_eq!(<RecOetf as FromLinear<f64, f64>>::from_linear(0.0), 0.0);
+        assert_relative_eq!(
+            <RecOetf as FromLinear<f64, f64>>::from_linear(0.018),
+            0.081,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            <RecOetf as FromLinear<f64, f64>>::from_linear(0.5),
+            0.7055,
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            <RecOetf as FromLinear<f64, f64>>::from_linear(1.0),
+            1.0,
+            epsilon = 0.0000001
+        );
+    }
+
+    #[test]
+    fn oetf_roundtrip() {
+        for &linear in &[0.0f64, 0.01, 0.018, 0.02, 0.18, 0.5, 1.0] {
+            let encoded: f64 = RecOetf::from_linear(linear);
+            let decoded: f64 = RecOetf::into_linear(encoded);
+            assert_relative_eq!(decoded, linear, epsilon = 0.0000001);
+        }
+    }
+
+    #[test]
+    fn eotf_reference_values() {
+        struct White;
+        impl Number for White {
+            const VALUE: f64 = 100.0;
+        }
+
+        struct Black;
+        impl Number for Black {
+            const VALUE: f64 = 0.1;
+        }
+
+        // A pure 2.4 gamma with a perfect black.
+        assert_relative_eq!(
+            <Bt1886 as IntoLinear<f64, f64>>::into_linear(0.5),
+            0.5f64.powf(2.4),
+            epsilon = 0.0000001
+        );
+
+        // The ends of the signal range map to the white and black luminances.
+        type Display = Bt1886<White, Black>;
+        assert_relative_eq!(
+            <Display as IntoLinear<f64, f64>>::into_linear(0.0) * 100.0,
+            0.1,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <Display as IntoLinear<f64, f64>>::into_linear(1.0) * 100.0,
+            100.0,
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            <Display as IntoLinear<f64, f64>>::into_linear(0.5) * 100.0,
+            21.6049,
+            epsilon = 0.0001
+        );
+
+        for &encoded in &[0.0f64, 0.1, 0.5, 1.0] {
+            let linear: f64 = Display::into_linear(encoded);
+            let reencoded: f64 = Display::from_linear(linear);
+            assert_relative_eq!(reencoded, encoded, epsilon = 0.0000001);
+        }
+    }
+
+    #[test]
+    fn rec2020_to_xyz() {
+        // BT.2020 and the RGB to XYZ matrix from SMPTE RP 177.
+        let dynamic = rgb_to_xyz_matrix::<Rec2020, f64>();
+        let constant = [
+            0.636958, 0.144617, 0.168881, 0.262700, 0.677998, 0.059302, 0.000000, 0.028073,
+            1.060985,
+        ];
+        assert_relative_eq!(dynamic[..], constant[..], epsilon = 0.0003);
+    }
+
+    #[test]
+    fn rec709_shares_srgb_primaries() {
+        let rec709 = Rec709Rgb::new(0.2f64, 0.5, 0.8);
+        let linear = LinSrgb::from_color_unclamped(rec709);
+
+        assert_relative_eq!(
+            linear,
+            LinSrgb::new(
+                RecOetf::into_linear(0.2),
+                RecOetf::into_linear(0.5),
+                RecOetf::into_linear(0.8)
+            ),
+            epsilon = 0.0000001
+        );
+        assert_relative_eq!(
+            Rec709Rgb::from_color_unclamped(linear),
+            rec709,
+            epsilon = 0.0000001
+        );
+    }
+
+    #[test]
+    fn srgb_in_rec2020() {
+        let red = LinRec2020::from_color_unclamped(LinSrgb::new(1.0f64, 0.0, 0.0));
+        assert_relative_eq!(
+            red,
+            LinRec2020::new(0.6274, 0.0691, 0.0164),
+            epsilon = 0.0001
+        );
+
+        let white = Rec2020Rgb::from_color_unclamped(Srgb::new(1.0f64, 1.0, 1.0));
+        assert_relative_eq!(white, Rec2020Rgb::new(1.0, 1.0, 1.0), epsilon = 0.00001);
+    }
+}
@@ -0,0 +1,410 @@
+//! Mapping colors into the gamut of an RGB space.
+//!
+//! Colors in perceptual spaces, such as [`Oklch`], can easily end up outside
+//! the range of an RGB space. [`Clamp`] brings them back by clipping each
+//! channel, which can change the hue and lightness a lot. The methods in this
+//! module search for a similar color inside the gamut instead:
+//!
+//! ```
+//! use palette::{
+//!     gamut::{Css4, GamutMapping},
+//!     rgb::DisplayP3Rgb,
+//!     IsWithinBounds, Oklch, Srgb,
+//! };
+//!
+//! // A bright green that doesn't fit in sRGB.
+//! let green = Oklch::new(0.85f32, 0.3, 142.0);
+//!
+//! let mapped: Srgb<f32> = Css4.map_color(green);
+//! assert!(mapped.is_within_bounds());
+//!
+//! // The target can be any RGB standard, including wide gamut spaces.
+//! let mapped: DisplayP3Rgb<f32> = Css4.map_color(green);
+//! assert!(mapped.is_within_bounds());
+//! ```
+//!
+//! There are three methods to pick from:
+//!
+//! * [`Css4`] - The gamut mapping algorithm from CSS Color Module Level 4. It
+//!   reduces the chroma, while keeping the lightness and hue, until the
+//!   clipped color is less than a just noticeable difference away.
+//! * [`CuspProjection`] - Reduces the chroma along a line towards the gray with
+//!   the same lightness as the cusp of the gamut, which is the most colorful
+//!   color of the hue. This keeps more chroma for very light and very dark
+//!   colors.
+//! * [`MidGrayProjection`] - Reduces the chroma along a line towards the
+//!   middle gray, `L = 0.5`. This is simpler and faster than
+//!   [`CuspProjection`], but changes the lightness more.
+//!
+//! The projections keep the hue, while [`Css4`] can change it within the just
+//! noticeable difference. All of them work with any gamut, but they search for
+//! its edge numerically, which makes [`CuspProjection`] notably slower than
+//! the other two methods.
+
+use crate::{
+    bool_mask::HasBoolMask,
+    convert::FromColorUnclamped,
+    num::{Arithmetics, One, PartialCmp, Real, Sqrt, Zero},
+    Clamp, IsWithinBounds, Oklab, Oklch,
+};
+
+/// The number of bisection steps for searching along a line.
+const SEARCH_ITERATIONS: usize = 32;
+
+/// A method for mapping an [`Oklch`] color into the gamut of `C`.
+pub trait GamutMapping<C, T> {
+    /// Return a color in the gamut of `C` that is similar to `color`.
+    ///
+    /// Colors that are already in the gamut are only converted.
+    #[must_use]
+    fn map_color(&self, color: Oklch<T>) -> C;
+}
+
+/// The gamut mapping algorithm from [CSS Color Module Level
+/// 4](https://www.w3.org/TR/css-color-4/#binsearch).
+///
+/// It makes a binary search for the chroma, while keeping the lightness and
+/// hue. Clipping is accepted when the clipped color is closer than a just
+/// noticeable difference (JND) of 0.02 ΔEOK, which is the Euclidean distance
+/// in [`Oklab`]. This keeps more chroma than reducing it until the color is
+/// inside the gamut. Colors with a lightness of 1.0 or more become white, and
+/// colors with a lightness of 0.0 or less become black.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct Css4;
+
+impl<C, T> GamutMapping<C, T> for Css4
+where
+    T: Real + Zero + One + Sqrt + Arithmetics + PartialCmp + HasBoolMask<Mask = bool> + Clone,
+    C: FromColorUnclamped<Oklch<T>> + IsWithinBounds<Mask = bool> + Clamp + Clone,
+    Oklab<T>: FromColorUnclamped<C> + FromColorUnclamped<Oklch<T>>,
+{
+    fn map_color(&self, color: Oklch<T>) -> C {
+        let jnd = T::from_f64(0.02);
+        let epsilon = T::from_f64(0.0001);
+
+        if color.l.gt_eq(&T::one()) {
+            return C::from_color_unclamped(Oklch::new(T::one(), T::zero(), color.hue)).clamp();
+        }
+        if color.l.lt_eq(&T::zero()) {
+            return C::from_color_unclamped(Oklch::new(T::zero(), T::zero(), color.hue)).clamp();
+        }
+
+        let origin = C::from_color_unclamped(color.clone());
+        if origin.is_within_bounds() {
+            return origin;
+        }
+
+        let mut current = color;
+        let mut clipped = origin.clamp();
+        if delta_e_ok(current.clone(), clipped.clone()).lt(&jnd) {
+            return clipped;
+        }
+
+        let mut min = T::zero();
+        let mut max = current.chroma.clone();
+        let mut min_in_gamut = true;
+
+        while (max.clone() - &min).gt(&epsilon) {
+            let chroma = (min.clone() + &max) / T::from_f64(2.0);
+            current.chroma = chroma.clone();
+
+            let candidate = C::from_color_unclamped(current.clone());
+            if min_in_gamut && candidate.is_within_bounds() {
+                min = chroma;
+                continue;
+            }
+
+            clipped = candidate.clamp();
+            let difference = delta_e_ok(current.clone(), clipped.clone());
+            if difference.lt(&jnd) {
+                if (jnd.clone() - difference).lt(&epsilon) {
+                    return clipped;
+                }
+
+                min_in_gamut = false;
+                min = chroma;
+            } else {
+                max = chroma;
+            }
+        }
+
+        clipped
+    }
+}
+
+/// Gamut mapping by projecting towards the gray with the same lightness as the
+/// cusp.
+///
+/// The cusp is the most colorful color of the hue, within the gamut. The color
+/// is moved along a straight line in [`Oklch`], towards the gray with the same
+/// lightness as the cusp, until it's at the edge of the gamut. This keeps more
+/// chroma than [`MidGrayProjection`] for light and dark colors, and the
+/// lightness changes the least for colors near the cusp.
+///
+/// The cusp is found with a numerical search, so any RGB gamut can be used as
+/// target.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct CuspProjection;
+
+impl<C, T> GamutMapping<C, T> for CuspProjection
+where
+    T: Real + Zero + One + Arithmetics + PartialCmp + HasBoolMask<Mask = bool> + Clone,
+    C: FromColorUnclamped<Oklch<T>> + IsWithinBounds<Mask = bool> + Clamp + Clone,
+{
+    fn map_color(&self, color: Oklch<T>) -> C {
+        let origin = C::from_color_unclamped(color.clone());
+        if origin.is_within_bounds() {
+            return origin;
+        }
+
+        let cusp_lightness = find_cusp_lightness::<C, T>(color.clone());
+        project_towards_gray(color, cusp_lightness)
+    }
+}
+
+/// Gamut mapping by projecting towards the middle gray.
+///
+/// The color is moved along a straight line in [`Oklch`], towards the gray
+/// with a lightness of 0.5, until it's at the edge of the gamut. Colors with
+/// out of range lightness are moved back into the range by the projection.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub struct MidGrayProjection;
+
+impl<C, T> GamutMapping<C, T> for MidGrayProjection
+where
+    T: Real + Zero + One + Arithmetics + PartialCmp + HasBoolMask<Mask = bool> + Clone,
+    C: FromColorUnclamped<Oklch<T>> + IsWithinBounds<Mask = bool> + Clamp + Clone,
+{
+    fn map_color(&self, color: Oklch<T>) -> C {
+        let origin = C::from_color_unclamped(color.clone());
+        if origin.is_within_bounds() {
+            return origin;
+        }
+
+        project_towards_gray(color, T::from_f64(0.5))
+    }
+}
+
+/// The Euclidean distance in Oklab, between `color` and `clipped`.
+fn delta_e_ok<C, T>(color: Oklch<T>, clipped: C) -> T
+where
+    T: Sqrt + Arithmetics + Clone,
+    Oklab<T>: FromColorUnclamped<C> + FromColorUnclamped<Oklch<T>>,
+{
+    let color = Oklab::from_color_unclamped(color);
+    let clipped = Oklab::from_color_unclamped(clipped);
+
+    let l = color.l - clipped.l;
+    let a = color.a - clipped.a;
+    let b = color.b - clipped.b;
+
+    (l.clone() * l + a.clone() * a + b.clone() * b).sqrt()
+}
+
+/// Bisect the line from the gray with `gray_lightness` to `color`, for the
+/// point where it leaves the gamut.
+fn project_towards_gray<C, T>(color: Oklch<T>, gray_lightness: T) -> C
+where
+    T: Real + Zero + One + Arithmetics + PartialCmp + HasBoolMask<Mask = bool> + Clone,
+    C: FromColorUnclamped<Oklch<T>> + IsWithinBounds<Mask = bool> + Clamp + Clone,
+{
+    let point = |amount: T| {
+        Oklch::new(
+            gray_lightness.clone() + (color.l.clone() - &gray_lightness) * &amount,
+            color.chroma.clone() * amount,
+            color.hue.clone(),
+        )
+    };
+
+    // The gray end is assumed to be inside the gamut.
+    let mut inside = T::zero();
+    let mut outside = T::one();
+
+    for _ in 0..SEARCH_ITERATIONS {
+        let amount = (inside.clone() + &outside) / T::from_f64(2.0);
+
+        if C::from_color_unclamped(point(amount.clone())).is_within_bounds() {
+            inside = amount;
+        } else {
+            outside = amount;
+        }
+    }
+
+    // Clamping removes any rounding errors from the conversion.
+    C::from_color_unclamped(point(inside)).clamp()
+}
+
+/// Find the lightness where the gamut has the most chroma, for the hue of
+/// `color`.
+fn find_cusp_lightness<C, T>(color: Oklch<T>) -> T
+where
+    T: Real + Zero + One + Arithmetics + PartialCmp + HasBoolMask<Mask = bool> + Clone,
+    C: FromColorUnclamped<Oklch<T>> + IsWithinBounds<Mask = bool>,
+{
+    let max_chroma = |lightness: T| {
+        let is_inside = |chroma: T| {
+            C::from_color_unclamped(Oklch::new(lightness.clone(), chroma, color.hue.clone()))
+                .is_within_bounds()
+        };
+
+        // Wide gamuts may have more chroma than the usual upper limit.
+        let mut outside = T::from_f64(0.5);
+        while is_inside(outside.clone()) && outside.lt(&T::from_f64(8.0)) {
+            outside = outside * T::from_f64(2.0);
+        }
+
+        let mut inside = T::zero();
+        for _ in 0..SEARCH_ITERATIONS {
+            let chroma = (inside.clone() + &outside) / T::from_f64(2.0);
+
+            if is_inside(chroma.clone()) {
+                inside = chroma;
+            } else {
+                outside = chroma;
+            }
+        }
+
+        inside
+    };
+
+    // The maximum chroma increases up to the cusp and decreases after it,
+    // which makes it possible to use a ternary search.
+    let mut low = T::zero();
+    let mut high = T::one();
+    for _ in 0..SEARCH_ITERATIONS {
+        let third = (high.clone() - &low) / T::from_f64(3.0);
+        let lower = low.clone() + &third;
+        let upper = high.clone() - third;
+
+        if max_chroma(lower.clone()).lt(&max_chroma(upper.clone())) {
+            low = lower;
+        } else {
+            high = upper;
+        }
+    }
+
+    (low + high) / T::from_f64(2.0)
+}
+
+#[cfg(test)]
+mod test {
+    use super::{find_cusp_lightness, Css4, CuspProjection, GamutMapping, MidGrayProjection};
+    use crate::{
+        convert::FromColorUnclamped,
+        rgb::{DisplayP3Rgb, Rgb},
+        IsWithinBounds, LinSrgb, Oklab, Oklch, Srgb,
+    };
+
+    fn out_of_gamut() -> [Oklch<f64>; 5] {
+        [
+            Oklch::new(0.85, 0.4, 142.0),
+            Oklch::new(0.6, 0.35, 30.0),
+            Oklch::new(0.4, 0.4, 265.0),
+            Oklch::new(0.95, 0.3, 100.0),
+            Oklch::new(0.2, 0.4, 330.0),
+        ]
+    }
+
+    fn check_mapping<S, M>(method: M, hue_epsilon: Option<f64>)
+    where
+        M: GamutMapping<Rgb<S, f64>, f64>,
+        Rgb<S, f64>: IsWithinBounds<Mask = bool>,
+        Oklch<f64>: FromColorUnclamped<Rgb<S, f64>>,
+    {
+        for color in out_of_gamut() {
+            let mapped = method.map_color(color);
+            assert!(mapped.is_within_bounds());
+
+            let mapped = Oklch::from_color_unclamped(mapped);
+            assert!(mapped.chroma < color.chroma);
+            if let Some(hue_epsilon) = hue_epsilon {
+                assert_relative_eq!(mapped.hue, color.hue, epsilon = hue_epsilon);
+            }
+        }
+    }
+
+    #[test]
+    fn in_gamut_is_unchanged() {
+        let color = Srgb::new(0.8, 0.4, 0.2);
+        let oklch = Oklch::from_color_unclamped(color);
+
+        let css: Srgb<f64> = Css4.map_color(oklch);
+        let cusp: Srgb<f64> = CuspProjection.map_color(oklch);
+        let gray: Srgb<f64> = MidGrayProjection.map_color(oklch);
+
+        // Only the conversion changes the color.
+        let converted = Srgb::from_color_unclamped(oklch);
+        assert_eq!(css, converted);
+        assert_eq!(cusp, converted);
+        assert_eq!(gray, converted);
+    }
+
+    #[test]
+    fn css4() {
+        // Clipping within a JND can change the hue of pale colors.
+        check_mapping::<crate::encoding::Srgb, _>(Css4, None);
+        check_mapping::<crate::encoding::DisplayP3, _>(Css4, None);
+
+        // The result is within a JND of the unclipped color.
+        let color = Oklch::new(0.7, 0.3, 150.0);
+        let mapped: Srgb<f64> = Css4.map_color(color);
+        let mapped = Oklab::from_color_unclamped(mapped);
+        let mapped_lch = Oklch::from_color_unclamped(mapped);
+        let unclipped = Oklab::from_color_unclamped(Oklch::new(0.7, mapped_lch.chroma, 150.0));
+        let distance = ((mapped.l - unclipped.l).powi(2)
+            + (mapped.a - unclipped.a).powi(2)
+            + (mapped.b - unclipped.b).powi(2))
+        .sqrt();
+        assert!(distance < 0.02);
+        assert_relative_eq!(mapped.l, 0.7, epsilon = 0.02);
+    }
+
+    #[test]
+    fn css4_out_of_range_lightness() {
+        let white: Srgb<f64> = Css4.map_color(Oklch::new(1.2, 0.1, 30.0));
+        assert_relative_eq!(white, Srgb::new(1.0, 1.0, 1.0), epsilon = 0.0001);
+
+        let black: Srgb<f64> = Css4.map_color(Oklch::new(-0.1, 0.1, 30.0));
+        assert_relative_eq!(black, Srgb::new(0.0, 0.0, 0.0), epsilon = 0.0001);
+    }
+
+    #[test]
+    fn projections() {
+        check_mapping::<crate::encoding::Srgb, _>(CuspProjection, Some(0.01));
+        check_mapping::<crate::encoding::DisplayP3, _>(CuspProjection, Some(0.01));
+        check_mapping::<crate::encoding::Srgb, _>(MidGrayProjection, Some(0.01));
+        check_mapping::<crate::encoding::DisplayP3, _>(MidGrayProjection, Some(0.01));
+
+        // Out of range lightness is projected back into the gamut.
+        let mapped: Srgb<f64> = MidGrayProjection.map_color(Oklch::new(1.2, 0.1, 30.0));
+        assert!(mapped.is_within_bounds());
+    }
+
+    #[test]
+    fn projections_end_on_the_gamut_edge() {
+        let color = Oklch::new(0.6, 0.35, 30.0);
+        let cusp: LinSrgb<f64> = CuspProjection.map_color(color);
+        let gray: LinSrgb<f64> = MidGrayProjection.map_color(color);
+
+        for mapped in [cusp, gray] {
+            let max = mapped.red.max(mapped.green).max(mapped.blue);
+            let min = mapped.red.min(mapped.green).min(mapped.blue);
+            assert!(max > 0.9999 || min < 0.0001);
+        }
+    }
+
+    #[test]
+    fn cusp_lightness() {
+        // The most colorful sRGB red is the primary.
+        let red = Oklch::from_color_unclamped(LinSrgb::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(
+            find_cusp_lightness::<LinSrgb<f64>, f64>(red),
+            red.l,
+            epsilon = 0.001
+        );
+
+        // The Display P3 red is lighter than the sRGB red.
+        let lightness = find_cusp_lightness::<DisplayP3Rgb<f64>, f64>(red);
+        assert!(lightness > red.l && lightness < 0.8);
+    }
+}
@@ -0,0 +1,563 @@
+//! Types for the HCT color space and tonal palettes.
+//!
+//! [`Hct`] combines the hue and chroma of [`Cam16`](crate::Cam16) with the
+//! lightness of [CIE L\*a\*b\*](crate::Lab), which makes it possible to
+//! choose colors with a guaranteed contrast ratio, while still changing their
+//! hue and colorfulness in a perceptually uniform way. It's the color space
+//! that is used for generating dynamic color schemes in Material Design.
+//!
+//! ```
+//! use palette::{hct::TonalPalette, Hct, IntoColor, Srgb};
+//!
+//! let seed: Hct = Srgb::new(0.4f32, 0.3, 0.8).into_color();
+//! let palette = TonalPalette::from(seed);
+//!
+//! let light = palette.tone(90.0);
+//! let dark = palette.tone(20.0);
+//! ```
+
+use core::ops::{BitAnd, BitOr};
+
+use crate::{
+    angle::RealAngle,
+    bool_mask::{HasBoolMask, LazySelect},
+    cam16::{self, BakedViewingConditions, ViewingConditions},
+    convert::FromColorUnclamped,
+    encoding::{self, FromLinear},
+    num::{
+        Abs, Arithmetics, Cbrt, Clamp, Exp, Hypot, MinMax, One, PartialCmp, Powf, Powi, Real, Sqrt,
+        Trigonometry, Zero,
+    },
+    white_point::D65,
+    Alpha, Cam16Hue, GetHue, LinSrgb, Srgb, Xyz,
+};
+
+pub use self::tonal_palette::TonalPalette;
+
+mod properties;
+mod tonal_palette;
+
+/// HCT with an alpha component. See the [`Hcta` implementation in
+/// `Alpha`](crate::Alpha#Hcta).
+pub type Hcta<T = f32> = Alpha<Hct<T>, T>;
+
+/// The HCT (hue, chroma, tone) color space.
+///
+/// HCT uses the hue and chroma from [`Cam16`](crate::Cam16), and the
+/// lightness (L\*) from [CIE L\*a\*b\*](crate::Lab) as its tone. The
+/// difference in tone between two colors is closely related to their
+/// contrast ratio, so it's a good base for generating color schemes with
+/// sufficient contrast.
+///
+/// It assumes a D65 white point and the
+/// [default viewing conditions](crate::cam16::ViewingConditions::default).
+///
+/// Not all combinations of hue, chroma and tone can be displayed, and the
+/// highest possible chroma is different for each hue and tone. Use
+/// [`Hct::into_srgb_in_gamut`] to find the closest color that can be
+/// displayed in sRGB.
+#[derive(Debug, Copy, Clone, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz, Hct)
+)]
+#[repr(C)]
+pub struct Hct<T = f32> {
+    /// The CAM16 hue of the color, in degrees.
+    #[palette(unsafe_same_layout_as = "T")]
+    pub hue: Cam16Hue<T>,
+
+    /// The CAM16 chroma of the color. A color with `chroma == 0` is a shade
+    /// of grey.
+    pub chroma: T,
+
+    /// The tone of the color, which is the same as L\* in
+    /// [CIE L\*a\*b\*](crate::Lab). 0.0 is black and 100.0 is white.
+    pub tone: T,
+}
+
+impl<T> Hct<T> {
+    /// Create an HCT color.
+    pub fn new<H: Into<Cam16Hue<T>>>(hue: H, chroma: T, tone: T) -> Self {
+        Hct {
+            hue: hue.into(),
+            chroma,
+            tone,
+        }
+    }
+
+    /// Create an HCT color. This is the same as `Hct::new` without the
+    /// generic hue type. It's temporary until `const fn` supports traits.
+    pub const fn new_const(hue: Cam16Hue<T>, chroma: T, tone: T) -> Self {
+        Hct { hue, chroma, tone }
+    }
+
+    /// Convert to a `(hue, chroma, tone)` tuple.
+    pub fn into_components(self) -> (Cam16Hue<T>, T, T) {
+        (self.hue, self.chroma, self.tone)
+    }
+
+    /// Convert from a `(hue, chroma, tone)` tuple.
+    pub fn from_components<H: Into<Cam16Hue<T>>>((hue, chroma, tone): (H, T, T)) -> Self {
+        Self::new(hue, chroma, tone)
+    }
+}
+
+impl<T> Hct<T>
+where
+    T: Zero + Real,
+{
+    /// Return the `chroma` value minimum.
+    pub fn min_chroma() -> T {
+        T::zero()
+    }
+
+    /// Return the `tone` value minimum.
+    pub fn min_tone() -> T {
+        T::zero()
+    }
+
+    /// Return the `tone` value maximum.
+    pub fn max_tone() -> T {
+        T::from_f64(100.0)
+    }
+}
+
+impl<T> Hct<T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Powi
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + MinMax
+        + Trigonometry
+        + Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T> + BitAnd<Output = T::Mask> + BitOr<Output = T::Mask> + Clone,
+    LinSrgb<T>: FromColorUnclamped<Xyz<D65, T>>,
+    encoding::Srgb: FromLinear<T, T>,
+{
+    /// Find the sRGB color with the same hue and tone, and the chroma that is
+    /// as close as possible to the requested chroma.
+    ///
+    /// The hue and tone are kept, since they matter most for the harmony and
+    /// contrast of a color scheme. The chroma is reduced until the color fits
+    /// in the sRGB gamut, if it doesn't already. The result is clamped, to
+    /// make up for small rounding errors.
+    ///
+    /// ```
+    /// use palette::{Hct, Srgb};
+    ///
+    /// // This chroma is too high for this hue and tone.
+    /// let color = Hct::new(120.0f32, 150.0, 40.0);
+    /// let srgb: Srgb = color.into_srgb_in_gamut();
+    /// ```
+    #[must_use]
+    pub fn into_srgb_in_gamut(self) -> Srgb<T> {
+        let conditions = ViewingConditions::default().into();
+        let is_in_gamut = |chroma: T| {
+            let linear = LinSrgb::from_color_unclamped(hct_to_xyz(
+                self.hue.clone(),
+                chroma,
+                self.tone.clone(),
+                &conditions,
+            ));
+            is_in_srgb_gamut(linear)
+        };
+
+        // Search for the highest chroma that fits, unless the requested one
+        // already does.
+        let fits = is_in_gamut(self.chroma.clone());
+        let mut low = T::zero();
+        let mut high = self.chroma.clone();
+
+        for _ in 0..GAMUT_SEARCH_ITERATIONS {
+            let middle = (low.clone() + &high) / T::from_f64(2.0);
+            let middle_fits = is_in_gamut(middle.clone());
+
+            low = lazy_select! {
+                if middle_fits.clone() => middle.clone(),
+                else => low.clone(),
+            };
+            high = lazy_select! {
+                if middle_fits => high.clone(),
+                else => middle.clone(),
+            };
+        }
+
+        let chroma = lazy_select! {
+            if fits => self.chroma.clone(),
+            else => low.clone(),
+        };
+
+        // CAM16 colors with no chroma aren't necessarily neutral, so black,
+        // white and colors with too little chroma become gray instead.
+        let is_gray = chroma.lt(&T::from_f64(1e-4))
+            | self.tone.lt(&T::from_f64(1e-4))
+            | self.tone.gt(&T::from_f64(100.0 - 1e-4));
+        let gray = tone_to_y(self.tone.clone());
+
+        let xyz = hct_to_xyz(self.hue, chroma, self.tone, &conditions);
+        let linear: LinSrgb<T> = LinSrgb::from_color_unclamped(xyz);
+        let to_srgb_component = |component: T| {
+            let component = lazy_select! {
+                if is_gray.clone() => gray.clone(),
+                else => component.clone(),
+            };
+            component.clamp(T::zero(), T::one())
+        };
+
+        Srgb::from_linear(LinSrgb::new(
+            to_srgb_component(linear.red),
+            to_srgb_component(linear.green),
+            to_srgb_component(linear.blue),
+        ))
+    }
+}
+
+///<span id="Hcta"></span>[`Hcta`](crate::Hcta) implementations.
+impl<T, A> Alpha<Hct<T>, A> {
+    /// Create an HCT color with transparency.
+    pub fn new<H: Into<Cam16Hue<T>>>(hue: H, chroma: T, tone: T, alpha: A) -> Self {
+        Alpha {
+            color: Hct::new(hue, chroma, tone),
+            alpha,
+        }
+    }
+
+    /// Create an `Hcta` color. This is the same as `Hcta::new` without the
+    /// generic hue type. It's temporary until `const fn` supports traits.
+    pub const fn new_const(hue: Cam16Hue<T>, chroma: T, tone: T, alpha: A) -> Self {
+        Alpha {
+            color: Hct::new_const(hue, chroma, tone),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(hue, chroma, tone, alpha)` tuple.
+    pub fn into_components(self) -> (Cam16Hue<T>, T, T, A) {
+        (
+            self.color.hue,
+            self.color.chroma,
+            self.color.tone,
+            self.alpha,
+        )
+    }
+
+    /// Convert from a `(hue, chroma, tone, alpha)` tuple.
+    pub fn from_components<H: Into<Cam16Hue<T>>>((hue, chroma, tone, alpha): (H, T, T, A)) -> Self {
+        Self::new(hue, chroma, tone, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Hct<T>> for Hct<T> {
+    fn from_color_unclamped(color: Hct<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Hct<T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Powi
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + Hypot
+        + Trigonometry
+        + Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        let tone = y_to_tone(color.y.clone());
+        let cam16 = cam16::xyz_to_cam16(color, &ViewingConditions::default().into());
+
+        Hct {
+            hue: cam16.hue,
+            chroma: cam16.chroma,
+            tone,
+        }
+    }
+}
+
+impl<T> FromColorUnclamped<Hct<T>> for Xyz<D65, T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Powi
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + MinMax
+        + Trigonometry
+        + Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    fn from_color_unclamped(color: Hct<T>) -> Self {
+        hct_to_xyz(
+            color.hue,
+            color.chroma,
+            color.tone,
+            &ViewingConditions::default().into(),
+        )
+    }
+}
+
+impl<T> GetHue for Hct<T>
+where
+    T: Clone,
+{
+    type Hue = Cam16Hue<T>;
+
+    #[inline]
+    fn get_hue(&self) -> Cam16Hue<T> {
+        self.hue.clone()
+    }
+}
+
+impl<T, H: Into<Cam16Hue<T>>> From<(H, T, T)> for Hct<T> {
+    fn from(components: (H, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<Hct<T>> for (Cam16Hue<T>, T, T) {
+    fn from(color: Hct<T>) -> (Cam16Hue<T>, T, T) {
+        color.into_components()
+    }
+}
+
+impl<T, H: Into<Cam16Hue<T>>, A> From<(H, T, T, A)> for Alpha<Hct<T>, A> {
+    fn from(components: (H, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T, A> From<Alpha<Hct<T>, A>> for (Cam16Hue<T>, T, T, A) {
+    fn from(color: Alpha<Hct<T>, A>) -> (Cam16Hue<T>, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl<T> HasBoolMask for Hct<T>
+where
+    T: HasBoolMask,
+{
+    type Mask = T::Mask;
+}
+
+impl<T> Default for Hct<T>
+where
+    T: Zero + Real,
+    Cam16Hue<T>: Default,
+{
+    fn default() -> Hct<T> {
+        Hct::new(Cam16Hue::default(), Self::min_chroma(), Self::min_tone())
+    }
+}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Hct<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Hct<T> where T: bytemuck::Pod {}
+
+/// The number of steps when searching for the highest chroma in
+/// `into_srgb_in_gamut`.
+const GAMUT_SEARCH_ITERATIONS: usize = 24;
+
+/// The number of Newton-Raphson steps when searching for the CAM16 lightness
+/// that matches a tone.
+const LIGHTNESS_SEARCH_ITERATIONS: usize = 8;
+
+/// Convert relative luminance to L\*.
+fn y_to_tone<T>(y: T) -> T
+where
+    T: Real + Arithmetics + Powi + Cbrt + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let epsilon = T::from_f64(6.0 / 29.0).powi(3);
+    let f_y = lazy_select! {
+        if y.gt(&epsilon) => y.clone().cbrt(),
+        else => T::from_f64(841.0 / 108.0) * &y + T::from_f64(4.0 / 29.0),
+    };
+
+    T::from_f64(116.0) * f_y - T::from_f64(16.0)
+}
+
+/// Convert L\* to relative luminance.
+fn tone_to_y<T>(tone: T) -> T
+where
+    T: Real + Arithmetics + Powi + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let f_y = (tone + T::from_f64(16.0)) / T::from_f64(116.0);
+
+    lazy_select! {
+        if f_y.gt(&T::from_f64(6.0 / 29.0)) => f_y.clone().powi(3),
+        else => (f_y.clone() - T::from_f64(4.0 / 29.0)) * T::from_f64(108.0 / 841.0),
+    }
+}
+
+/// Find the color with the given hue, chroma and tone, by searching for the
+/// CAM16 lightness that gives the luminance of the tone.
+fn hct_to_xyz<T>(
+    hue: Cam16Hue<T>,
+    chroma: T,
+    tone: T,
+    conditions: &BakedViewingConditions<D65, T>,
+) -> Xyz<D65, T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Powi
+        + Sqrt
+        + Abs
+        + MinMax
+        + Trigonometry
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    let y = tone_to_y(tone);
+
+    // The lightness is roughly proportional to the square root of the
+    // luminance, which makes a good initial guess.
+    let mut lightness = y.clone().max(T::zero()).sqrt() * T::from_f64(100.0);
+
+    for _ in 0..LIGHTNESS_SEARCH_ITERATIONS {
+        let xyz = cam16::cam16_to_xyz(lightness.clone(), chroma.clone(), hue.clone(), conditions);
+
+        lightness = lazy_select! {
+            if xyz.y.gt(&T::zero()) => {
+                let step = (xyz.y.clone() - &y) * &lightness / (T::from_f64(2.0) * &xyz.y);
+                (lightness.clone() - step).max(T::zero())
+            },
+            else => lightness.clone(),
+        };
+    }
+
+    cam16::cam16_to_xyz(lightness, chroma, hue, conditions)
+}
+
+fn is_in_srgb_gamut<T>(color: LinSrgb<T>) -> T::Mask
+where
+    T: Real + PartialCmp,
+    T::Mask: BitAnd<Output = T::Mask>,
+{
+    // A small margin for rounding errors. The result is clamped afterwards.
+    let min = T::from_f64(-1e-5);
+    let max = T::from_f64(1.0 + 1e-5);
+
+    color.red.gt_eq(&min)
+        & color.red.lt_eq(&max)
+        & color.green.gt_eq(&min)
+        & color.green.lt_eq(&max)
+        & color.blue.gt_eq(&min)
+        & color.blue.lt_eq(&max)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{convert::FromColorUnclamped, white_point::D65, Hct, Lab, Srgb, Xyz};
+
+    #[test]
+    fn ranges() {
+        // chroma: 0.0 => infinity
+        assert_ranges! {
+            Hct<f64>;
+            clamped {
+                tone: 0.0 => 100.0
+            }
+            clamped_min {}
+            unclamped {
+                hue: 0.0 => 360.0
+            }
+        }
+    }
+
+    #[test]
+    fn tone_is_lab_lightness() {
+        let rgb = Srgb::new(0.2f64, 0.6, 0.9);
+        let hct = Hct::from_color_unclamped(rgb);
+        let lab = Lab::<D65, f64>::from_color_unclamped(rgb);
+
+        assert_relative_eq!(hct.tone, lab.l, epsilon = 0.000001);
+    }
+
+    // Reference values from Material Color Utilities.
+    #[test]
+    fn material_reference() {
+        let blue = Hct::from_color_unclamped(Srgb::new(0.0f64, 0.0, 1.0));
+        assert_relative_eq!(blue.hue.into_positive_degrees(), 282.788, epsilon = 0.05);
+        assert_relative_eq!(blue.chroma, 87.230, epsilon = 0.05);
+        assert_relative_eq!(blue.tone, 32.302, epsilon = 0.05);
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &(red, green, blue) in &[
+            (0.2, 0.6, 0.9),
+            (1.0, 0.0, 0.0),
+            (0.05, 0.02, 0.1),
+            (0.9, 0.9, 0.3),
+            (1.0, 1.0, 1.0),
+            (0.0, 0.0, 0.0),
+        ] {
+            let rgb = Srgb::new(red, green, blue);
+            let xyz: Xyz<_, f64> = Xyz::from_color_unclamped(rgb);
+            let hct = Hct::from_color_unclamped(xyz);
+            assert_relative_eq!(Xyz::from_color_unclamped(hct), xyz, epsilon = 0.000001);
+        }
+    }
+
+    #[test]
+    fn in_gamut_unchanged() {
+        let rgb = Srgb::new(0.2f64, 0.6, 0.9);
+        let hct = Hct::from_color_unclamped(rgb);
+
+        assert_relative_eq!(hct.into_srgb_in_gamut(), rgb, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn out_of_gamut_keeps_hue_and_tone() {
+        let hct = Hct::new(120.0f64, 150.0, 40.0);
+        let rgb = hct.into_srgb_in_gamut();
+        let result = Hct::from_color_unclamped(rgb);
+
+        assert!(result.chroma < 150.0);
+        assert!(result.chroma > 10.0);
+        assert_relative_eq!(result.tone, 40.0, epsilon = 0.01);
+        assert_relative_eq!(result.hue.into_positive_degrees(), 120.0, epsilon = 0.1);
+    }
+}
@@ -0,0 +1,107 @@
+use core::ops::{Add, AddAssign, BitAnd, Sub, SubAssign};
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{
+    angle::{RealAngle, SignedAngle},
+    bool_mask::LazySelect,
+    clamp, clamp_assign, clamp_min, clamp_min_assign,
+    num::{
+        self, Arithmetics, FromScalarArray, IntoScalarArray, MinMax, One, PartialCmp, Real, Zero,
+    },
+    Alpha, Cam16Hue, Clamp, ClampAssign, IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign,
+    SetHue, ShiftHue, ShiftHueAssign, WithHue,
+};
+
+use super::Hct;
+
+impl_is_within_bounds! {
+    Hct {
+        chroma => [Self::min_chroma(), None],
+        tone => [Self::min_tone(), Self::max_tone()]
+    }
+    where T: Real + Zero
+}
+
+impl<T> Clamp for Hct<T>
+where
+    T: num::Clamp + Real + Zero,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            self.hue,
+            clamp_min(self.chroma, Self::min_chroma()),
+            clamp(self.tone, Self::min_tone(), Self::max_tone()),
+        )
+    }
+}
+
+impl<T> ClampAssign for Hct<T>
+where
+    T: num::ClampAssign + Real + Zero,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_min_assign(&mut self.chroma, Self::min_chroma());
+        clamp_assign(&mut self.tone, Self::min_tone(), Self::max_tone());
+    }
+}
+
+impl_mix_hue!(Hct { chroma, tone });
+impl_lighten!(Hct increase {tone => [Self::min_tone(), Self::max_tone()]} other {hue, chroma});
+
+impl<T, H> WithHue<H> for Hct<T>
+where
+    H: Into<Cam16Hue<T>>,
+{
+    #[inline]
+    fn with_hue(mut self, hue: H) -> Self {
+        self.hue = hue.into();
+        self
+    }
+}
+
+impl<T, H> SetHue<H> for Hct<T>
+where
+    H: Into<Cam16Hue<T>>,
+{
+    #[inline]
+    fn set_hue(&mut self, hue: H) {
+        self.hue = hue.into();
+    }
+}
+
+impl<T> ShiftHue for Hct<T>
+where
+    T: Add<Output = T>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue(mut self, amount: Self::Scalar) -> Self {
+        self.hue = self.hue + amount;
+        self
+    }
+}
+
+impl<T> ShiftHueAssign for Hct<T>
+where
+    T: AddAssign,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue_assign(&mut self, amount: Self::Scalar) {
+        self.hue += amount;
+    }
+}
+
+impl_color_add!(Hct<T>, [hue, chroma, tone]);
+impl_color_sub!(Hct<T>, [hue, chroma, tone]);
+
+impl_array_casts!(Hct<T>, [T; 3]);
+impl_simd_array_conversion_hue!(Hct, [chroma, tone]);
+
+impl_eq_hue!(Hct, Cam16Hue, [hue, chroma, tone]);
@@ -0,0 +1,147 @@
+use core::ops::{BitAnd, BitOr};
+
+use crate::{
+    angle::RealAngle,
+    bool_mask::LazySelect,
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    encoding::{self, FromLinear},
+    num::{
+        Abs, Arithmetics, Cbrt, Clamp, Exp, MinMax, One, PartialCmp, Powf, Powi, Real, Sqrt,
+        Trigonometry, Zero,
+    },
+    white_point::D65,
+    Cam16Hue, LinSrgb, Srgb, Xyz,
+};
+
+use super::Hct;
+
+/// A range of tones with the same hue and chroma.
+///
+/// Each tone, from 0.0 (black) to 100.0 (white), is mapped to an sRGB color
+/// with the palette's hue, and a chroma that is as close to the palette's
+/// chroma as the sRGB gamut allows. This is how the tonal palettes in
+/// Material Design are generated from a seed color.
+///
+/// ```
+/// use palette::{hct::TonalPalette, Srgb};
+///
+/// let palette = TonalPalette::from_seed(Srgb::new(0.4f32, 0.3, 0.8));
+///
+/// let tones = [0.0, 10.0, 20.0, 30.0, 40.0, 50.0, 60.0, 70.0, 80.0, 90.0, 95.0, 99.0, 100.0];
+/// let colors = tones.map(|tone| palette.tone(tone));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct TonalPalette<T = f32> {
+    /// The hue of all tones.
+    pub hue: Cam16Hue<T>,
+
+    /// The requested chroma of all tones. It may be reduced for tones where
+    /// it's too high to display.
+    pub chroma: T,
+}
+
+impl<T> TonalPalette<T> {
+    /// Create a tonal palette with a specific hue and chroma.
+    pub fn new<H: Into<Cam16Hue<T>>>(hue: H, chroma: T) -> Self {
+        TonalPalette {
+            hue: hue.into(),
+            chroma,
+        }
+    }
+
+    /// Create a tonal palette with the same hue and chroma as `seed`.
+    pub fn from_seed<C>(seed: C) -> Self
+    where
+        C: IntoColorUnclamped<Hct<T>>,
+    {
+        let seed = seed.into_color_unclamped();
+
+        TonalPalette {
+            hue: seed.hue,
+            chroma: seed.chroma,
+        }
+    }
+}
+
+impl<T> TonalPalette<T>
+where
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Powi
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + MinMax
+        + Trigonometry
+        + Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T> + BitAnd<Output = T::Mask> + BitOr<Output = T::Mask> + Clone,
+    LinSrgb<T>: FromColorUnclamped<Xyz<D65, T>>,
+    encoding::Srgb: FromLinear<T, T>,
+{
+    /// Get the sRGB color for a tone, from 0.0 (black) to 100.0 (white).
+    ///
+    /// See [`Hct::into_srgb_in_gamut`] for how the color is found.
+    #[must_use]
+    pub fn tone(&self, tone: T) -> Srgb<T> {
+        Hct::new(self.hue.clone(), self.chroma.clone(), tone).into_srgb_in_gamut()
+    }
+}
+
+impl<T> From<Hct<T>> for TonalPalette<T> {
+    fn from(color: Hct<T>) -> Self {
+        TonalPalette {
+            hue: color.hue,
+            chroma: color.chroma,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::TonalPalette;
+    use crate::{convert::FromColorUnclamped, white_point::D65, Hct, Lab, Srgb};
+
+    #[test]
+    fn tones_have_expected_lightness() {
+        let palette = TonalPalette::from_seed(Srgb::new(0.4f64, 0.3, 0.8));
+
+        for tone in (0..=100).step_by(10) {
+            let tone = tone as f64;
+            let color = palette.tone(tone);
+            let lab = Lab::<D65, f64>::from_color_unclamped(color);
+            assert_relative_eq!(lab.l, tone, epsilon = 0.05);
+        }
+    }
+
+    #[test]
+    fn black_and_white() {
+        let palette = TonalPalette::new(250.0f64, 40.0);
+
+        assert_relative_eq!(
+            palette.tone(0.0),
+            Srgb::new(0.0, 0.0, 0.0),
+            epsilon = 0.0001
+        );
+        assert_relative_eq!(
+            palette.tone(100.0),
+            Srgb::new(1.0, 1.0, 1.0),
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn seed_hue() {
+        let seed = Hct::from_color_unclamped(Srgb::new(0.4f64, 0.3, 0.8));
+        let palette = TonalPalette::from(seed);
+        let tone = Hct::from_color_unclamped(palette.tone(seed.tone));
+
+        assert_relative_eq!(tone, seed, epsilon = 0.001);
+    }
+}
@@ -423,6 +423,18 @@ make_hues! {
     ///
     /// It's measured in degrees.
     struct OklabHue;
+
+    /// A hue type for the CAM16 color appearance model.
+    ///
+    /// It's measured in degrees and, like [`LabHue`], it's based on the
+    /// opponent red-green and yellow-blue dimensions. The angles are still
+    /// not interchangeable with the angles of other hue types.
+    struct Cam16Hue;
+
+    /// A hue type for the Jzazbz color space.
+    ///
+    /// It's measured in degrees.
+    struct JzazbzHue;
 }
 
 macro_rules! impl_uniform {
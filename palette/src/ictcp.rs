@@ -0,0 +1,539 @@
+//! Types for the ICtCp color space, from ITU-R BT.2100.
+
+use core::{
+    any::TypeId,
+    marker::PhantomData,
+    ops::{Add, AddAssign, BitAnd, Div, DivAssign, Mul, MulAssign, Sub, SubAssign},
+};
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{
+    blend::{PreAlpha, Premultiply},
+    bool_mask::{HasBoolMask, LazySelect},
+    clamp, clamp_assign,
+    color_difference::ColorDifference,
+    convert::FromColorUnclamped,
+    encoding::{FromLinear, Hlg, IntoLinear, Pq},
+    matrix::multiply_xyz,
+    num::{
+        self, Arithmetics, FromScalarArray, IntoScalarArray, IsValidDivisor, MinMax, One,
+        PartialCmp, Real, Sqrt, Zero,
+    },
+    stimulus::Stimulus,
+    white_point::D65,
+    Alpha, Clamp, ClampAssign, IsWithinBounds, Lighten, LightenAssign, Mat3, Mix, MixAssign, Xyz,
+};
+
+/// ICtCp with an alpha component. See the [`ICtCpa` implementation in
+/// `Alpha`](crate::Alpha#ICtCpa).
+pub type ICtCpa<S = Pq, T = f32> = Alpha<ICtCp<S, T>, T>;
+
+/// The ICtCp color space, from ITU-R BT.2100.
+///
+/// ICtCp is a color representation for HDR and wide color gamut video, that
+/// separates intensity (`i`) from the blue-yellow (`ct`) and red-green (`cp`)
+/// chroma components. It's defined in terms of linear ITU-R BT.2020 RGB, and
+/// comes in two variants, decided by `S`:
+///
+/// * [`Pq`] (default), where the components are encoded with the SMPTE ST 2084
+///   perceptual quantizer. This is the variant that's used for color
+///   differences.
+/// * [`Hlg`], where the components are encoded with the hybrid log-gamma
+///   OETF.
+///
+/// The conversion traits, such as [`FromColorUnclamped`], map `Y = 1.0` to
+/// the reference white in ITU-R BT.2408. That's 203 cd/m² for PQ and a 75%
+/// signal level for HLG. Scale the color before converting it to map other
+/// luminances.
+///
+/// ```
+/// use palette::{
+///     encoding::{Hlg, Pq},
+///     rgb::LinRec2020,
+///     ColorDifference, FromColor, ICtCp,
+/// };
+///
+/// let white = LinRec2020::new(1.0f32, 1.0, 1.0);
+/// let pq_white: ICtCp<Pq> = ICtCp::from_color(white);
+/// let hlg_white: ICtCp<Hlg> = ICtCp::from_color(white);
+///
+/// let gray: ICtCp = ICtCp::from_color(white * 0.9);
+/// let difference = pq_white.get_color_difference(gray);
+/// ```
+#[derive(Debug, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz, ICtCp)
+)]
+#[repr(C)]
+pub struct ICtCp<S = Pq, T = f32> {
+    /// `i` is the intensity of the color. 0.0 is black and 1.0 is the peak
+    /// of the transfer function.
+    pub i: T,
+
+    /// `ct` is the blue-yellow chroma of the color. Negative values are
+    /// yellow and positive values are blue.
+    pub ct: T,
+
+    /// `cp` is the red-green chroma of the color. Negative values are green
+    /// and positive values are red.
+    pub cp: T,
+
+    /// The kind of ICtCp standard, decided by its transfer function. PQ is
+    /// the default.
+    #[cfg_attr(feature = "serializing", serde(skip))]
+    #[palette(unsafe_zero_sized)]
+    pub standard: PhantomData<S>,
+}
+
+impl<S, T: Copy> Copy for ICtCp<S, T> {}
+
+impl<S, T: Clone> Clone for ICtCp<S, T> {
+    fn clone(&self) -> ICtCp<S, T> {
+        ICtCp {
+            i: self.i.clone(),
+            ct: self.ct.clone(),
+            cp: self.cp.clone(),
+            standard: PhantomData,
+        }
+    }
+}
+
+impl<S, T> ICtCp<S, T> {
+    /// Create an ICtCp color.
+    pub const fn new(i: T, ct: T, cp: T) -> Self {
+        ICtCp {
+            i,
+            ct,
+            cp,
+            standard: PhantomData,
+        }
+    }
+
+    /// Convert to a `(I, Ct, Cp)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.i, self.ct, self.cp)
+    }
+
+    /// Convert from a `(I, Ct, Cp)` tuple.
+    pub fn from_components((i, ct, cp): (T, T, T)) -> Self {
+        Self::new(i, ct, cp)
+    }
+}
+
+impl<S, T> ICtCp<S, T>
+where
+    T: Zero + One,
+{
+    /// Return the `i` value minimum.
+    pub fn min_i() -> T {
+        T::zero()
+    }
+
+    /// Return the `i` value maximum.
+    pub fn max_i() -> T {
+        T::one()
+    }
+}
+
+///<span id="ICtCpa"></span>[`ICtCpa`](crate::ICtCpa) implementations.
+impl<S, T, A> Alpha<ICtCp<S, T>, A> {
+    /// Create an ICtCp color with transparency.
+    pub const fn new(i: T, ct: T, cp: T, alpha: A) -> Self {
+        Alpha {
+            color: ICtCp::new(i, ct, cp),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(I, Ct, Cp, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.i, self.color.ct, self.color.cp, self.alpha)
+    }
+
+    /// Convert from a `(I, Ct, Cp, alpha)` tuple.
+    pub fn from_components((i, ct, cp, alpha): (T, T, T, A)) -> Self {
+        Self::new(i, ct, cp, alpha)
+    }
+}
+
+/// The transfer function and matrix of an [`ICtCp`] standard.
+///
+/// This is implemented for [`Pq`] and [`Hlg`].
+pub trait ICtCpStandard<T> {
+    /// Encode a linear LMS component, where 1.0 is the reference white.
+    #[must_use]
+    fn encode(linear: T) -> T;
+
+    /// Decode an encoded LMS component, where 1.0 is the reference white.
+    #[must_use]
+    fn decode(encoded: T) -> T;
+
+    /// The matrix that transforms encoded LMS into ICtCp.
+    #[must_use]
+    fn lms_to_ictcp() -> Mat3<T>;
+
+    /// The matrix that transforms ICtCp into encoded LMS.
+    #[must_use]
+    fn ictcp_to_lms() -> Mat3<T>;
+}
+
+/// The reference white of PQ, in cd/m², from ITU-R BT.2408.
+const PQ_REFERENCE_WHITE: f64 = 203.0;
+
+/// The scene light of the HLG reference white, which is encoded as 0.75.
+const HLG_REFERENCE_WHITE: f64 = 0.26496256042100724;
+
+impl<T> ICtCpStandard<T> for Pq
+where
+    T: Real + Arithmetics,
+    Pq: FromLinear<T, T> + IntoLinear<T, T>,
+{
+    #[inline]
+    fn encode(linear: T) -> T {
+        Pq::from_linear(linear * T::from_f64(PQ_REFERENCE_WHITE))
+    }
+
+    #[inline]
+    fn decode(encoded: T) -> T {
+        Pq::into_linear(encoded) / T::from_f64(PQ_REFERENCE_WHITE)
+    }
+
+    #[rustfmt::skip]
+    #[inline]
+    fn lms_to_ictcp() -> Mat3<T> {
+        [
+            T::from_f64(2048.0 / 4096.0), T::from_f64(2048.0 / 4096.0), T::from_f64(0.0),
+            T::from_f64(6610.0 / 4096.0), T::from_f64(-13613.0 / 4096.0), T::from_f64(7003.0 / 4096.0),
+            T::from_f64(17933.0 / 4096.0), T::from_f64(-17390.0 / 4096.0), T::from_f64(-543.0 / 4096.0),
+        ]
+    }
+
+    #[rustfmt::skip]
+    #[inline]
+    fn ictcp_to_lms() -> Mat3<T> {
+        [
+            T::from_f64(1.0), T::from_f64(0.008609037037932756), T::from_f64(0.11102962500302596),
+            T::from_f64(1.0), T::from_f64(-0.008609037037932756), T::from_f64(-0.11102962500302596),
+            T::from_f64(1.0), T::from_f64(0.5600313357106791), T::from_f64(-0.32062717498731885),
+        ]
+    }
+}
+
+impl<T> ICtCpStandard<T> for Hlg
+where
+    T: Real + Arithmetics,
+    Hlg: FromLinear<T, T> + IntoLinear<T, T>,
+{
+    #[inline]
+    fn encode(linear: T) -> T {
+        Hlg::from_linear(linear * T::from_f64(HLG_REFERENCE_WHITE))
+    }
+
+    #[inline]
+    fn decode(encoded: T) -> T {
+        Hlg::into_linear(encoded) / T::from_f64(HLG_REFERENCE_WHITE)
+    }
+
+    #[rustfmt::skip]
+    #[inline]
+    fn lms_to_ictcp() -> Mat3<T> {
+        [
+            T::from_f64(2048.0 / 4096.0), T::from_f64(2048.0 / 4096.0), T::from_f64(0.0),
+            T::from_f64(3625.0 / 4096.0), T::from_f64(-7465.0 / 4096.0), T::from_f64(3840.0 / 4096.0),
+            T::from_f64(9500.0 / 4096.0), T::from_f64(-9212.0 / 4096.0), T::from_f64(-288.0 / 4096.0),
+        ]
+    }
+
+    #[rustfmt::skip]
+    #[inline]
+    fn ictcp_to_lms() -> Mat3<T> {
+        [
+            T::from_f64(1.0), T::from_f64(0.015718580108730413), T::from_f64(0.2095810681164055),
+            T::from_f64(1.0), T::from_f64(-0.015718580108730413), T::from_f64(-0.2095810681164055),
+            T::from_f64(1.0), T::from_f64(1.0212710798422342), T::from_f64(-0.6052744909924315),
+        ]
+    }
+}
+
+impl<S1, S2, T> FromColorUnclamped<ICtCp<S2, T>> for ICtCp<S1, T>
+where
+    S1: 'static,
+    S2: 'static,
+    Xyz<D65, T>: FromColorUnclamped<ICtCp<S2, T>>,
+    Self: FromColorUnclamped<Xyz<D65, T>>,
+{
+    fn from_color_unclamped(color: ICtCp<S2, T>) -> Self {
+        if TypeId::of::<S1>() == TypeId::of::<S2>() {
+            ICtCp::new(color.i, color.ct, color.cp)
+        } else {
+            Self::from_color_unclamped(Xyz::from_color_unclamped(color))
+        }
+    }
+}
+
+impl<S, T> FromColorUnclamped<Xyz<D65, T>> for ICtCp<S, T>
+where
+    S: ICtCpStandard<T>,
+    T: Real + Arithmetics,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        let Xyz {
+            x: long,
+            y: medium,
+            z: short,
+            ..
+        } = multiply_xyz(xyz_to_lms(), color.with_white_point());
+
+        let Xyz {
+            x: i, y: ct, z: cp, ..
+        } = multiply_xyz(
+            S::lms_to_ictcp(),
+            Xyz::new(S::encode(long), S::encode(medium), S::encode(short)),
+        );
+
+        ICtCp::new(i, ct, cp)
+    }
+}
+
+impl<S, T> FromColorUnclamped<ICtCp<S, T>> for Xyz<D65, T>
+where
+    S: ICtCpStandard<T>,
+    T: Real + Arithmetics,
+{
+    fn from_color_unclamped(color: ICtCp<S, T>) -> Self {
+        let Xyz {
+            x: long,
+            y: medium,
+            z: short,
+            ..
+        } = multiply_xyz(S::ictcp_to_lms(), Xyz::new(color.i, color.ct, color.cp));
+
+        multiply_xyz(
+            lms_to_xyz(),
+            Xyz::new(S::decode(long), S::decode(medium), S::decode(short)),
+        )
+        .with_white_point()
+    }
+}
+
+impl<S, T> From<(T, T, T)> for ICtCp<S, T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<S, T> From<ICtCp<S, T>> for (T, T, T) {
+    fn from(color: ICtCp<S, T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<S, T, A> From<(T, T, T, A)> for Alpha<ICtCp<S, T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<S, T, A> From<Alpha<ICtCp<S, T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<ICtCp<S, T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_is_within_bounds! {
+    ICtCp<S> {
+        i => [Self::min_i(), Self::max_i()]
+    }
+    where T: Zero + One
+}
+
+impl<S, T> Clamp for ICtCp<S, T>
+where
+    T: Zero + One + num::Clamp,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.i, Self::min_i(), Self::max_i()),
+            self.ct,
+            self.cp,
+        )
+    }
+}
+
+impl<S, T> ClampAssign for ICtCp<S, T>
+where
+    T: Zero + One + num::ClampAssign,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.i, Self::min_i(), Self::max_i());
+    }
+}
+
+impl_mix!(ICtCp<S>);
+impl_lighten!(ICtCp<S> increase {i => [Self::min_i(), Self::max_i()]} other {ct, cp} phantom: standard where T: One);
+impl_premultiply!(ICtCp<S> {i, ct, cp} phantom: standard);
+
+/// The ΔE<sub>ITP</sub> color difference from ITU-R BT.2124.
+///
+/// A difference of 1.0 is about the smallest difference that can be noticed.
+impl<T> ColorDifference for ICtCp<Pq, T>
+where
+    T: Real + Arithmetics + Sqrt + Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_color_difference(self, other: ICtCp<Pq, T>) -> Self::Scalar {
+        let delta_i = self.i - other.i;
+        let delta_t = (self.ct - other.ct) * T::from_f64(0.5);
+        let delta_p = self.cp - other.cp;
+
+        T::from_f64(720.0)
+            * (delta_i.clone() * delta_i + delta_t.clone() * delta_t + delta_p.clone() * delta_p)
+                .sqrt()
+    }
+}
+
+impl<S, T> HasBoolMask for ICtCp<S, T>
+where
+    T: HasBoolMask,
+{
+    type Mask = T::Mask;
+}
+
+impl<S, T> Default for ICtCp<S, T>
+where
+    T: Zero,
+{
+    fn default() -> ICtCp<S, T> {
+        ICtCp::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_color_add!(ICtCp<S, T>, [i, ct, cp], standard);
+impl_color_sub!(ICtCp<S, T>, [i, ct, cp], standard);
+impl_color_mul!(ICtCp<S, T>, [i, ct, cp], standard);
+impl_color_div!(ICtCp<S, T>, [i, ct, cp], standard);
+
+impl_array_casts!(ICtCp<S, T>, [T; 3]);
+impl_simd_array_conversion!(ICtCp<S>, [i, ct, cp], standard);
+
+impl_eq!(ICtCp<S>, [i, ct, cp]);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S, T> bytemuck::Zeroable for ICtCp<S, T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<S: 'static, T> bytemuck::Pod for ICtCp<S, T> where T: bytemuck::Pod {}
+
+/// XYZ to LMS transformation matrix, through linear ITU-R BT.2020 RGB.
+#[rustfmt::skip]
+fn xyz_to_lms<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(0.3592210603948667), T::from_f64(0.6976275140082041), T::from_f64(-0.03587002129047981),
+        T::from_f64(-0.19211008250291267), T::from_f64(1.1004957268309834), T::from_f64(0.07540125022782257),
+        T::from_f64(0.0070791403113753155), T::from_f64(0.07483369683961767), T::from_f64(0.8435088973197189),
+    ]
+}
+
+/// LMS to XYZ transformation matrix, through linear ITU-R BT.2020 RGB.
+#[rustfmt::skip]
+fn lms_to_xyz<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(2.070333837663604), T::from_f64(-1.3264781747047893), T::from_f64(0.20661433704118584),
+        T::from_f64(0.3648197167597156), T::from_f64(0.6804959321611155), T::from_f64(-0.0453156489208312),
+        T::from_f64(-0.04974101867406548), T::from_f64(-0.04923919747758515), T::from_f64(1.1878102161516506),
+    ]
+}
+
+#[cfg(test)]
+mod test {
+    use super::ICtCp;
+    use crate::{
+        convert::FromColorUnclamped,
+        encoding::{Hlg, Pq},
+        rgb::LinRec2020,
+        ColorDifference, Srgb, Xyz,
+    };
+
+    #[test]
+    fn ranges() {
+        assert_ranges! {
+            ICtCp<Pq, f64>;
+            clamped {
+                i: 0.0 => 1.0
+            }
+            clamped_min {}
+            unclamped {
+                ct: -1.0 => 1.0,
+                cp: -1.0 => 1.0
+            }
+        }
+    }
+
+    #[test]
+    fn reference_white() {
+        let white = LinRec2020::new(1.0f64, 1.0, 1.0);
+
+        let pq = ICtCp::<Pq, f64>::from_color_unclamped(white);
+        assert_relative_eq!(pq, ICtCp::new(0.5806889, 0.0, 0.0), epsilon = 0.0000001);
+
+        let hlg = ICtCp::<Hlg, f64>::from_color_unclamped(white);
+        assert_relative_eq!(hlg, ICtCp::new(0.75, 0.0, 0.0), epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let rgb = Srgb::new(0.8f64, 0.3, 0.5);
+
+        let pq = ICtCp::<Pq, f64>::from_color_unclamped(rgb);
+        assert_relative_eq!(Srgb::from_color_unclamped(pq), rgb, epsilon = 0.0000001);
+
+        let hlg = ICtCp::<Hlg, f64>::from_color_unclamped(rgb);
+        assert_relative_eq!(Srgb::from_color_unclamped(hlg), rgb, epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn between_variants() {
+        let xyz = Xyz::new(0.3f64, 0.2, 0.5);
+        let pq = ICtCp::<Pq, f64>::from_color_unclamped(xyz);
+        let hlg = ICtCp::<Hlg, f64>::from_color_unclamped(pq);
+
+        assert_relative_eq!(Xyz::from_color_unclamped(hlg), xyz, epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn color_difference() {
+        let a = ICtCp::<Pq, f64>::new(0.5, 0.01, -0.02);
+        let b = ICtCp::<Pq, f64>::new(0.52, -0.01, 0.01);
+
+        assert_relative_eq!(a.get_color_difference(b), 26.9399332, epsilon = 0.0000001);
+        assert_relative_eq!(b.get_color_difference(a), 26.9399332, epsilon = 0.0000001);
+        assert_relative_eq!(a.get_color_difference(a), 0.0);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn serialize() {
+        let serialized = ::serde_json::to_string(&ICtCp::<Pq>::new(0.3, 0.1, -0.1)).unwrap();
+
+        assert_eq!(serialized, r#"{"i":0.3,"ct":0.1,"cp":-0.1}"#);
+    }
+
+    #[cfg(feature = "serializing")]
+    #[test]
+    fn deserialize() {
+        let deserialized: ICtCp =
+            ::serde_json::from_str(r#"{"i":0.3,"ct":0.1,"cp":-0.1}"#).unwrap();
+
+        assert_eq!(deserialized, ICtCp::new(0.3, 0.1, -0.1));
+    }
+}
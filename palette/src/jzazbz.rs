@@ -0,0 +1,530 @@
+use core::ops::{Add, AddAssign, BitAnd, Div, DivAssign, Mul, MulAssign, Sub, SubAssign};
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{
+    angle::RealAngle,
+    blend::{PreAlpha, Premultiply},
+    bool_mask::{HasBoolMask, LazySelect},
+    clamp, clamp_assign,
+    color_difference::ColorDifference,
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    matrix::multiply_xyz,
+    num::{
+        self, Arithmetics, FromScalarArray, IntoScalarArray, IsValidDivisor, MinMax, One,
+        PartialCmp, Powf, Real, Trigonometry, Zero,
+    },
+    stimulus::Stimulus,
+    white_point::D65,
+    Alpha, Clamp, ClampAssign, GetHue, IsWithinBounds, JzazbzHue, Jzczhz, Lighten, LightenAssign,
+    Mat3, Mix, MixAssign, Xyz,
+};
+
+/// Jzazbz with an alpha component. See the [`Jzazbza` implementation in
+/// `Alpha`](crate::Alpha#Jzazbza).
+pub type Jzazbza<T = f32> = Alpha<Jzazbz<T>, T>;
+
+/// The luminance of `Y = 1.0` when converting with the conversion traits, in
+/// cd/m². It's the reference white of HDR video in ITU-R BT.2408.
+const DEFAULT_REFERENCE_LUMINANCE: f64 = 203.0;
+
+/// The [Jzazbz color space](https://doi.org/10.1364/OE.25.015131).
+///
+/// Jzazbz is a perceptually uniform color space, like
+/// [`Oklab`](crate::Oklab) and [CIE L\*a\*b\*](crate::Lab), but it's based on
+/// absolute luminance instead of relative luminance. Its lightness, `jz`,
+/// uses the same kind of transfer function as HDR video (PQ), which makes it
+/// suitable for colors from about 0 to 10000 cd/m².
+///
+/// The conversion from [`Xyz`] needs to know the absolute luminance of the
+/// color. The conversion traits, such as [`FromColorUnclamped`], assume that
+/// `Y = 1.0` is 203 cd/m², which is the reference white of HDR video.
+/// [`Jzazbz::from_xyz`] and [`Jzazbz::into_xyz`] take an explicit reference
+/// white luminance for `Y = 1.0`.
+///
+/// It assumes a D65 white point.
+#[derive(Debug, Copy, Clone, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Xyz, Jzazbz, Jzczhz)
+)]
+#[repr(C)]
+pub struct Jzazbz<T = f32> {
+    /// `jz` is the lightness of the color. 0.0 is black and 1.0 is about
+    /// 10000 cd/m².
+    pub jz: T,
+
+    /// `az` is the redness-greenness of the color. Negative values are green
+    /// and positive values are red.
+    pub az: T,
+
+    /// `bz` is the yellowness-blueness of the color. Negative values are blue
+    /// and positive values are yellow.
+    pub bz: T,
+}
+
+impl<T> Jzazbz<T> {
+    /// Create a Jzazbz color.
+    pub const fn new(jz: T, az: T, bz: T) -> Self {
+        Jzazbz { jz, az, bz }
+    }
+
+    /// Convert to a `(Jz, az, bz)` tuple.
+    pub fn into_components(self) -> (T, T, T) {
+        (self.jz, self.az, self.bz)
+    }
+
+    /// Convert from a `(Jz, az, bz)` tuple.
+    pub fn from_components((jz, az, bz): (T, T, T)) -> Self {
+        Self::new(jz, az, bz)
+    }
+}
+
+impl<T> Jzazbz<T>
+where
+    T: Zero + One,
+{
+    /// Return the `jz` value minimum.
+    pub fn min_jz() -> T {
+        T::zero()
+    }
+
+    /// Return the `jz` value maximum.
+    pub fn max_jz() -> T {
+        T::one()
+    }
+}
+
+impl<T> Jzazbz<T>
+where
+    T: Real + Zero + One + MinMax + Arithmetics + Powf + Clone,
+{
+    /// Convert from `Xyz`, where `Y = 1.0` is the reference white, with an
+    /// absolute luminance of `reference_luminance` cd/m².
+    ///
+    /// ```
+    /// use palette::{Jzazbz, Xyz};
+    ///
+    /// // An SDR white at 100 cd/m² and an HDR highlight at 1000 cd/m².
+    /// let sdr_white = Jzazbz::from_xyz(Xyz::new(0.95047f32, 1.0, 1.08883), 100.0);
+    /// let hdr_highlight = Jzazbz::from_xyz(Xyz::new(0.95047f32, 1.0, 1.08883), 1000.0);
+    ///
+    /// assert!(hdr_highlight.jz > sdr_white.jz);
+    /// ```
+    #[must_use]
+    pub fn from_xyz(color: Xyz<D65, T>, reference_luminance: T) -> Self {
+        let Xyz { x, y, z, .. } = color * reference_luminance;
+
+        let x_prime = T::from_f64(B) * &x - T::from_f64(B - 1.0) * &z;
+        let y_prime = T::from_f64(G) * y - T::from_f64(G - 1.0) * x;
+
+        let Xyz {
+            x: long,
+            y: medium,
+            z: short,
+            ..
+        } = multiply_xyz(m1(), Xyz::new(x_prime, y_prime, z));
+
+        let Xyz {
+            x: iz,
+            y: az,
+            z: bz,
+            ..
+        } = multiply_xyz(
+            m2(),
+            Xyz::new(
+                perceptual_quantizer(long),
+                perceptual_quantizer(medium),
+                perceptual_quantizer(short),
+            ),
+        );
+
+        let jz =
+            (T::one() + T::from_f64(D)) * &iz / (T::one() + T::from_f64(D) * iz) - T::from_f64(D_0);
+
+        Jzazbz { jz, az, bz }
+    }
+
+    /// Convert to `Xyz`, where `Y = 1.0` is the reference white, with an
+    /// absolute luminance of `reference_luminance` cd/m².
+    ///
+    /// ```
+    /// use palette::{Jzazbz, Xyz};
+    ///
+    /// let xyz: Xyz = Jzazbz::new(0.15f32, 0.01, -0.02).into_xyz(1000.0);
+    /// ```
+    #[must_use]
+    pub fn into_xyz(self, reference_luminance: T) -> Xyz<D65, T> {
+        let jz = self.jz + T::from_f64(D_0);
+        let iz = jz.clone() / (T::one() + T::from_f64(D) - T::from_f64(D) * jz);
+
+        let Xyz {
+            x: long,
+            y: medium,
+            z: short,
+            ..
+        } = multiply_xyz(m2_inv(), Xyz::new(iz, self.az, self.bz));
+
+        let Xyz {
+            x: x_prime,
+            y: y_prime,
+            z,
+            ..
+        } = multiply_xyz(
+            m1_inv(),
+            Xyz::new(
+                perceptual_quantizer_inv(long),
+                perceptual_quantizer_inv(medium),
+                perceptual_quantizer_inv(short),
+            ),
+        );
+
+        let x = (x_prime + T::from_f64(B - 1.0) * &z) / T::from_f64(B);
+        let y = (y_prime + T::from_f64(G - 1.0) * &x) / T::from_f64(G);
+
+        Xyz::new(x, y, z) / reference_luminance
+    }
+}
+
+///<span id="Jzazbza"></span>[`Jzazbza`](crate::Jzazbza) implementations.
+impl<T, A> Alpha<Jzazbz<T>, A> {
+    /// Create a Jzazbz color with transparency.
+    pub const fn new(jz: T, az: T, bz: T, alpha: A) -> Self {
+        Alpha {
+            color: Jzazbz::new(jz, az, bz),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(Jz, az, bz, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, T, A) {
+        (self.color.jz, self.color.az, self.color.bz, self.alpha)
+    }
+
+    /// Convert from a `(Jz, az, bz, alpha)` tuple.
+    pub fn from_components((jz, az, bz, alpha): (T, T, T, A)) -> Self {
+        Self::new(jz, az, bz, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Jzazbz<T>> for Jzazbz<T> {
+    fn from_color_unclamped(color: Jzazbz<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Xyz<D65, T>> for Jzazbz<T>
+where
+    T: Real + Zero + One + MinMax + Arithmetics + Powf + Clone,
+{
+    fn from_color_unclamped(color: Xyz<D65, T>) -> Self {
+        Self::from_xyz(color, T::from_f64(DEFAULT_REFERENCE_LUMINANCE))
+    }
+}
+
+impl<T> FromColorUnclamped<Jzazbz<T>> for Xyz<D65, T>
+where
+    T: Real + Zero + One + MinMax + Arithmetics + Powf + Clone,
+{
+    fn from_color_unclamped(color: Jzazbz<T>) -> Self {
+        color.into_xyz(T::from_f64(DEFAULT_REFERENCE_LUMINANCE))
+    }
+}
+
+impl<T> FromColorUnclamped<Jzczhz<T>> for Jzazbz<T>
+where
+    T: RealAngle + Zero + MinMax + Trigonometry + Mul<Output = T> + Clone,
+{
+    fn from_color_unclamped(color: Jzczhz<T>) -> Self {
+        let (hue_sin, hue_cos) = color.hue.into_raw_radians().sin_cos();
+        let chroma = color.chroma.max(T::zero());
+
+        Jzazbz {
+            jz: color.jz,
+            az: hue_cos * chroma.clone(),
+            bz: hue_sin * chroma,
+        }
+    }
+}
+
+impl<T> From<(T, T, T)> for Jzazbz<T> {
+    fn from(components: (T, T, T)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<Jzazbz<T>> for (T, T, T) {
+    fn from(color: Jzazbz<T>) -> (T, T, T) {
+        color.into_components()
+    }
+}
+
+impl<T, A> From<(T, T, T, A)> for Alpha<Jzazbz<T>, A> {
+    fn from(components: (T, T, T, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T, A> From<Alpha<Jzazbz<T>, A>> for (T, T, T, A) {
+    fn from(color: Alpha<Jzazbz<T>, A>) -> (T, T, T, A) {
+        color.into_components()
+    }
+}
+
+impl_is_within_bounds! {
+    Jzazbz {
+        jz => [Self::min_jz(), Self::max_jz()]
+    }
+    where T: Zero + One
+}
+
+impl<T> Clamp for Jzazbz<T>
+where
+    T: Zero + One + num::Clamp,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.jz, Self::min_jz(), Self::max_jz()),
+            self.az,
+            self.bz,
+        )
+    }
+}
+
+impl<T> ClampAssign for Jzazbz<T>
+where
+    T: Zero + One + num::ClampAssign,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.jz, Self::min_jz(), Self::max_jz());
+    }
+}
+
+impl_mix!(Jzazbz);
+impl_lighten!(Jzazbz increase {jz => [Self::min_jz(), Self::max_jz()]} other {az, bz} where T: One);
+impl_premultiply!(Jzazbz { jz, az, bz });
+
+impl<T> GetHue for Jzazbz<T>
+where
+    T: RealAngle + Trigonometry + Clone,
+{
+    type Hue = JzazbzHue<T>;
+
+    fn get_hue(&self) -> JzazbzHue<T> {
+        JzazbzHue::from_radians(self.bz.clone().atan2(self.az.clone()))
+    }
+}
+
+impl<T> ColorDifference for Jzazbz<T>
+where
+    Self: IntoColorUnclamped<Jzczhz<T>>,
+    Jzczhz<T>: ColorDifference<Scalar = T>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_color_difference(self, other: Jzazbz<T>) -> Self::Scalar {
+        let lhs: Jzczhz<T> = self.into_color_unclamped();
+        lhs.get_color_difference(other.into_color_unclamped())
+    }
+}
+
+impl<T> HasBoolMask for Jzazbz<T>
+where
+    T: HasBoolMask,
+{
+    type Mask = T::Mask;
+}
+
+impl<T> Default for Jzazbz<T>
+where
+    T: Zero,
+{
+    fn default() -> Jzazbz<T> {
+        Jzazbz::new(T::zero(), T::zero(), T::zero())
+    }
+}
+
+impl_color_add!(Jzazbz<T>, [jz, az, bz]);
+impl_color_sub!(Jzazbz<T>, [jz, az, bz]);
+impl_color_mul!(Jzazbz<T>, [jz, az, bz]);
+impl_color_div!(Jzazbz<T>, [jz, az, bz]);
+
+impl_array_casts!(Jzazbz<T>, [T; 3]);
+impl_simd_array_conversion!(Jzazbz, [jz, az, bz]);
+
+impl_eq!(Jzazbz, [jz, az, bz]);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Jzazbz<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Jzazbz<T> where T: bytemuck::Pod {}
+
+const B: f64 = 1.15;
+const G: f64 = 0.66;
+const D: f64 = -0.56;
+const D_0: f64 = 1.6295499532821566e-11;
+
+const C_1: f64 = 3424.0 / 4096.0;
+const C_2: f64 = 2413.0 / 128.0;
+const C_3: f64 = 2392.0 / 128.0;
+const N: f64 = 2610.0 / 16384.0;
+const P: f64 = 1.7 * 2523.0 / 32.0;
+
+/// The maximum luminance of the perceptual quantizer, in cd/m².
+const MAX_LUMINANCE: f64 = 10000.0;
+
+/// Adjusted XYZ to LMS transformation matrix.
+#[rustfmt::skip]
+fn m1<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(0.41478972), T::from_f64(0.579999), T::from_f64(0.0146480),
+        T::from_f64(-0.2015100), T::from_f64(1.120649), T::from_f64(0.0531008),
+        T::from_f64(-0.0166008), T::from_f64(0.264800), T::from_f64(0.6684799),
+    ]
+}
+
+/// LMS to adjusted XYZ transformation matrix.
+#[rustfmt::skip]
+fn m1_inv<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(1.924226435787607), T::from_f64(-1.004792312595366), T::from_f64(0.037651404030618014),
+        T::from_f64(0.3503167620949992), T::from_f64(0.7264811939316554), T::from_f64(-0.06538442294808504),
+        T::from_f64(-0.09098281098284759), T::from_f64(-0.31272829052307405), T::from_f64(1.5227665613052608),
+    ]
+}
+
+/// Non-linear LMS to Izazbz transformation matrix.
+#[rustfmt::skip]
+fn m2<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(0.5), T::from_f64(0.5), T::from_f64(0.0),
+        T::from_f64(3.524000), T::from_f64(-4.066708), T::from_f64(0.542708),
+        T::from_f64(0.199076), T::from_f64(1.096799), T::from_f64(-1.295875),
+    ]
+}
+
+/// Izazbz to non-linear LMS transformation matrix.
+#[rustfmt::skip]
+fn m2_inv<T: Real>() -> Mat3<T> {
+    [
+        T::from_f64(1.0), T::from_f64(0.13860504327153927), T::from_f64(0.058047316156118856),
+        T::from_f64(1.0), T::from_f64(-0.13860504327153927), T::from_f64(-0.058047316156118856),
+        T::from_f64(1.0), T::from_f64(-0.09601924202631894), T::from_f64(-0.8118918960560388),
+    ]
+}
+
+/// The PQ curve, with the modified exponent from the Jzazbz paper. Negative
+/// LMS values, from colors outside of the spectral locus, are clamped to 0.0.
+fn perceptual_quantizer<T>(luminance: T) -> T
+where
+    T: Real + Zero + One + MinMax + Arithmetics + Powf + Clone,
+{
+    let luminance = (luminance / T::from_f64(MAX_LUMINANCE))
+        .max(T::zero())
+        .powf(T::from_f64(N));
+
+    ((T::from_f64(C_1) + T::from_f64(C_2) * &luminance) / (T::one() + T::from_f64(C_3) * luminance))
+        .powf(T::from_f64(P))
+}
+
+/// The inverse of `perceptual_quantizer`.
+fn perceptual_quantizer_inv<T>(value: T) -> T
+where
+    T: Real + Arithmetics + Powf + Clone,
+{
+    let value = value.powf(T::from_f64(1.0 / P));
+
+    T::from_f64(MAX_LUMINANCE)
+        * ((T::from_f64(C_1) - &value) / (T::from_f64(C_3) * value - T::from_f64(C_2)))
+            .powf(T::from_f64(1.0 / N))
+}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        convert::FromColorUnclamped, white_point::D65, ColorDifference, Jzazbz, Srgb, Xyz,
+    };
+
+    #[test]
+    fn ranges() {
+        assert_ranges! {
+            Jzazbz<f64>;
+            clamped {
+                jz: 0.0 => 1.0
+            }
+            clamped_min {}
+            unclamped {
+                az: -0.5 => 0.5,
+                bz: -0.5 => 0.5
+            }
+        }
+    }
+
+    // Reference value from the colour-science Python package, which takes
+    // XYZ in cd/m².
+    #[test]
+    fn absolute_xyz() {
+        let xyz = Xyz::<D65, f64>::new(0.20654008, 0.12197225, 0.05136952);
+        let jzazbz = Jzazbz::from_xyz(xyz, 1.0);
+
+        assert_relative_eq!(jzazbz.jz, 0.0053504, epsilon = 0.0000001);
+        assert_relative_eq!(jzazbz.az, 0.0092430, epsilon = 0.0000001);
+        assert_relative_eq!(jzazbz.bz, 0.0052600, epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn reference_luminance() {
+        let white = Xyz::<D65, f64>::new(0.95047, 1.0, 1.08883);
+
+        assert_relative_eq!(
+            Jzazbz::from_xyz(white, 203.0).jz,
+            0.22207,
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(
+            Jzazbz::from_xyz(white, 10000.0).jz,
+            0.98861,
+            epsilon = 0.00001
+        );
+        assert_relative_eq!(
+            Jzazbz::from_color_unclamped(white),
+            Jzazbz::from_xyz(white, 203.0)
+        );
+    }
+
+    #[test]
+    fn roundtrip() {
+        for &luminance in &[1.0, 100.0, 203.0, 1000.0, 4000.0] {
+            let xyz: Xyz<D65, f64> = Xyz::from_color_unclamped(Srgb::new(0.8, 0.3, 0.5));
+            let jzazbz = Jzazbz::from_xyz(xyz, luminance);
+            assert_relative_eq!(jzazbz.into_xyz(luminance), xyz, epsilon = 0.0000001);
+        }
+    }
+
+    #[test]
+    fn negative_lms() {
+        // Far outside of the spectral locus, with negative LMS values.
+        let xyz = Xyz::<D65, f64>::new(0.0, 0.0, 1.0);
+        let jzazbz = Jzazbz::from_xyz(xyz, 203.0);
+
+        assert!(!jzazbz.jz.is_nan() && !jzazbz.az.is_nan() && !jzazbz.bz.is_nan());
+    }
+
+    #[test]
+    fn color_difference() {
+        let a = Jzazbz::<f64>::new(0.1, 0.02, -0.01);
+        let b = Jzazbz::<f64>::new(0.13, -0.02, 0.02);
+
+        assert_relative_eq!(a.get_color_difference(a), 0.0);
+        // ΔEz is the same as the Euclidean distance in Jzazbz.
+        assert_relative_eq!(a.get_color_difference(b), 0.0583095, epsilon = 0.0000001);
+    }
+}
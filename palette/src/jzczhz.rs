@@ -0,0 +1,390 @@
+use core::ops::{Add, AddAssign, BitAnd, Sub, SubAssign};
+
+#[cfg(feature = "approx")]
+use approx::{AbsDiffEq, RelativeEq, UlpsEq};
+
+use crate::{
+    angle::{RealAngle, SignedAngle},
+    bool_mask::{HasBoolMask, LazySelect},
+    clamp, clamp_assign, clamp_min, clamp_min_assign,
+    color_difference::ColorDifference,
+    convert::FromColorUnclamped,
+    num::{
+        self, Arithmetics, FromScalarArray, Hypot, IntoScalarArray, MinMax, One, PartialCmp, Real,
+        Sqrt, Trigonometry, Zero,
+    },
+    white_point::D65,
+    Alpha, Clamp, ClampAssign, GetHue, IsWithinBounds, Jzazbz, JzazbzHue, Lighten, LightenAssign,
+    Mix, MixAssign, Saturate, SaturateAssign, SetHue, ShiftHue, ShiftHueAssign, WithHue,
+};
+
+/// Jzczhz with an alpha component. See the [`Jzczhza` implementation in
+/// `Alpha`](crate::Alpha#Jzczhza).
+pub type Jzczhza<T = f32> = Alpha<Jzczhz<T>, T>;
+
+/// Jzczhz, a polar version of [Jzazbz](crate::Jzazbz).
+///
+/// It's Jzazbz's equivalent of [CIE L\*C\*h°](crate::Lch), and it has the
+/// same relation to absolute luminance as Jzazbz.
+///
+/// It's a cylindrical color space, like [HSL](crate::Hsl) and
+/// [HSV](crate::Hsv). This gives it the same ability to directly change
+/// the hue and colorfulness of a color, while preserving other visual aspects.
+#[derive(Debug, Copy, Clone, ArrayCast, FromColorUnclamped, WithAlpha)]
+#[cfg_attr(feature = "serializing", derive(Serialize, Deserialize))]
+#[palette(
+    palette_internal,
+    white_point = "D65",
+    component = "T",
+    skip_derives(Jzazbz, Jzczhz)
+)]
+#[repr(C)]
+pub struct Jzczhz<T = f32> {
+    /// `jz` is the lightness of the color. 0.0 is black and 1.0 is about
+    /// 10000 cd/m².
+    pub jz: T,
+
+    /// `chroma` is the colorfulness of the color. A color with `chroma == 0`
+    /// is a shade of grey.
+    pub chroma: T,
+
+    /// `hue` is the hue of the color, in degrees. Decides if it's red, blue,
+    /// purple, etc.
+    #[palette(unsafe_same_layout_as = "T")]
+    pub hue: JzazbzHue<T>,
+}
+
+impl<T> Jzczhz<T> {
+    /// Create a Jzczhz color.
+    pub fn new<H: Into<JzazbzHue<T>>>(jz: T, chroma: T, hue: H) -> Self {
+        Jzczhz {
+            jz,
+            chroma,
+            hue: hue.into(),
+        }
+    }
+
+    /// Create a `Jzczhz` color. This is the same as `Jzczhz::new` without the
+    /// generic hue type. It's temporary until `const fn` supports traits.
+    pub const fn new_const(jz: T, chroma: T, hue: JzazbzHue<T>) -> Self {
+        Jzczhz { jz, chroma, hue }
+    }
+
+    /// Convert to a `(Jz, Cz, hz)` tuple.
+    pub fn into_components(self) -> (T, T, JzazbzHue<T>) {
+        (self.jz, self.chroma, self.hue)
+    }
+
+    /// Convert from a `(Jz, Cz, hz)` tuple.
+    pub fn from_components<H: Into<JzazbzHue<T>>>((jz, chroma, hue): (T, T, H)) -> Self {
+        Self::new(jz, chroma, hue)
+    }
+}
+
+impl<T> Jzczhz<T>
+where
+    T: Zero + One + Real,
+{
+    /// Return the `jz` value minimum.
+    pub fn min_jz() -> T {
+        T::zero()
+    }
+
+    /// Return the `jz` value maximum.
+    pub fn max_jz() -> T {
+        T::one()
+    }
+
+    /// Return the `chroma` value minimum.
+    pub fn min_chroma() -> T {
+        T::zero()
+    }
+
+    /// Return the `chroma` value maximum. This value does not cover the
+    /// entire color space, but covers the colors of common displays, even
+    /// at high luminance.
+    pub fn max_chroma() -> T {
+        T::from_f64(0.5)
+    }
+}
+
+///<span id="Jzczhza"></span>[`Jzczhza`](crate::Jzczhza) implementations.
+impl<T, A> Alpha<Jzczhz<T>, A> {
+    /// Create a Jzczhz color with transparency.
+    pub fn new<H: Into<JzazbzHue<T>>>(jz: T, chroma: T, hue: H, alpha: A) -> Self {
+        Alpha {
+            color: Jzczhz::new(jz, chroma, hue),
+            alpha,
+        }
+    }
+
+    /// Create a `Jzczhza` color. This is the same as `Jzczhza::new` without
+    /// the generic hue type. It's temporary until `const fn` supports traits.
+    pub const fn new_const(jz: T, chroma: T, hue: JzazbzHue<T>, alpha: A) -> Self {
+        Alpha {
+            color: Jzczhz::new_const(jz, chroma, hue),
+            alpha,
+        }
+    }
+
+    /// Convert to a `(Jz, Cz, hz, alpha)` tuple.
+    pub fn into_components(self) -> (T, T, JzazbzHue<T>, A) {
+        (self.color.jz, self.color.chroma, self.color.hue, self.alpha)
+    }
+
+    /// Convert from a `(Jz, Cz, hz, alpha)` tuple.
+    pub fn from_components<H: Into<JzazbzHue<T>>>((jz, chroma, hue, alpha): (T, T, H, A)) -> Self {
+        Self::new(jz, chroma, hue, alpha)
+    }
+}
+
+impl<T> FromColorUnclamped<Jzczhz<T>> for Jzczhz<T> {
+    fn from_color_unclamped(color: Jzczhz<T>) -> Self {
+        color
+    }
+}
+
+impl<T> FromColorUnclamped<Jzazbz<T>> for Jzczhz<T>
+where
+    T: Hypot + Clone,
+    Jzazbz<T>: GetHue<Hue = JzazbzHue<T>>,
+{
+    fn from_color_unclamped(color: Jzazbz<T>) -> Self {
+        let hue = color.get_hue();
+        let chroma = color.az.hypot(color.bz);
+        Jzczhz::new(color.jz, chroma, hue)
+    }
+}
+
+impl<T, H: Into<JzazbzHue<T>>> From<(T, T, H)> for Jzczhz<T> {
+    fn from(components: (T, T, H)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T> From<Jzczhz<T>> for (T, T, JzazbzHue<T>) {
+    fn from(color: Jzczhz<T>) -> (T, T, JzazbzHue<T>) {
+        color.into_components()
+    }
+}
+
+impl<T, H: Into<JzazbzHue<T>>, A> From<(T, T, H, A)> for Alpha<Jzczhz<T>, A> {
+    fn from(components: (T, T, H, A)) -> Self {
+        Self::from_components(components)
+    }
+}
+
+impl<T, A> From<Alpha<Jzczhz<T>, A>> for (T, T, JzazbzHue<T>, A) {
+    fn from(color: Alpha<Jzczhz<T>, A>) -> (T, T, JzazbzHue<T>, A) {
+        color.into_components()
+    }
+}
+
+impl_is_within_bounds! {
+    Jzczhz {
+        jz => [Self::min_jz(), Self::max_jz()],
+        chroma => [Self::min_chroma(), None]
+    }
+    where T: Zero + One + Real
+}
+
+impl<T> Clamp for Jzczhz<T>
+where
+    T: Zero + One + Real + num::Clamp,
+{
+    #[inline]
+    fn clamp(self) -> Self {
+        Self::new(
+            clamp(self.jz, Self::min_jz(), Self::max_jz()),
+            clamp_min(self.chroma, Self::min_chroma()),
+            self.hue,
+        )
+    }
+}
+
+impl<T> ClampAssign for Jzczhz<T>
+where
+    T: Zero + One + Real + num::ClampAssign,
+{
+    #[inline]
+    fn clamp_assign(&mut self) {
+        clamp_assign(&mut self.jz, Self::min_jz(), Self::max_jz());
+        clamp_min_assign(&mut self.chroma, Self::min_chroma());
+    }
+}
+
+impl_mix_hue!(Jzczhz { jz, chroma });
+impl_lighten!(Jzczhz increase {jz => [Self::min_jz(), Self::max_jz()]} other {hue, chroma} where T: One);
+impl_saturate!(Jzczhz increase {chroma => [Self::min_chroma(), Self::max_chroma()]} other {hue, jz} where T: One);
+
+impl<T> GetHue for Jzczhz<T>
+where
+    T: Clone,
+{
+    type Hue = JzazbzHue<T>;
+
+    #[inline]
+    fn get_hue(&self) -> JzazbzHue<T> {
+        self.hue.clone()
+    }
+}
+
+impl<T, H> WithHue<H> for Jzczhz<T>
+where
+    H: Into<JzazbzHue<T>>,
+{
+    #[inline]
+    fn with_hue(mut self, hue: H) -> Self {
+        self.hue = hue.into();
+        self
+    }
+}
+
+impl<T, H> SetHue<H> for Jzczhz<T>
+where
+    H: Into<JzazbzHue<T>>,
+{
+    #[inline]
+    fn set_hue(&mut self, hue: H) {
+        self.hue = hue.into();
+    }
+}
+
+impl<T> ShiftHue for Jzczhz<T>
+where
+    T: Add<Output = T>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue(mut self, amount: Self::Scalar) -> Self {
+        self.hue = self.hue + amount;
+        self
+    }
+}
+
+impl<T> ShiftHueAssign for Jzczhz<T>
+where
+    T: AddAssign,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn shift_hue_assign(&mut self, amount: Self::Scalar) {
+        self.hue += amount;
+    }
+}
+
+/// The ΔEz color difference, as defined by Safdar et al.
+impl<T> ColorDifference for Jzczhz<T>
+where
+    T: Real + RealAngle + Arithmetics + Trigonometry + Sqrt + Clone,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_color_difference(self, other: Jzczhz<T>) -> Self::Scalar {
+        let delta_jz = self.jz - other.jz;
+        let delta_chroma = self.chroma.clone() - &other.chroma;
+
+        // ΔHz = 2 * sqrt(Cz1 * Cz2) * sin(Δhz / 2), but only its square is
+        // needed, so the sign of the hue difference doesn't matter.
+        let half_delta_hue = (self.hue - other.hue).into_raw_radians() / T::from_f64(2.0);
+        let sin_half_delta_hue = half_delta_hue.sin();
+        let delta_hue_squared = T::from_f64(4.0)
+            * self.chroma
+            * other.chroma
+            * sin_half_delta_hue.clone()
+            * sin_half_delta_hue;
+
+        (delta_jz.clone() * delta_jz + delta_chroma.clone() * delta_chroma + delta_hue_squared)
+            .sqrt()
+    }
+}
+
+impl<T> HasBoolMask for Jzczhz<T>
+where
+    T: HasBoolMask,
+{
+    type Mask = T::Mask;
+}
+
+impl<T> Default for Jzczhz<T>
+where
+    T: Zero + One + Real,
+    JzazbzHue<T>: Default,
+{
+    fn default() -> Jzczhz<T> {
+        Jzczhz::new(Self::min_jz(), Self::min_chroma(), JzazbzHue::default())
+    }
+}
+
+impl_color_add!(Jzczhz<T>, [jz, chroma, hue]);
+impl_color_sub!(Jzczhz<T>, [jz, chroma, hue]);
+
+impl_array_casts!(Jzczhz<T>, [T; 3]);
+impl_simd_array_conversion_hue!(Jzczhz, [jz, chroma]);
+
+impl_eq_hue!(Jzczhz, JzazbzHue, [jz, chroma, hue]);
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Zeroable for Jzczhz<T> where T: bytemuck::Zeroable {}
+
+#[cfg(feature = "bytemuck")]
+unsafe impl<T> bytemuck::Pod for Jzczhz<T> where T: bytemuck::Pod {}
+
+#[cfg(test)]
+mod test {
+    use crate::{
+        convert::FromColorUnclamped, ColorDifference, Jzazbz, Jzczhz, Saturate, ShiftHue, Srgb,
+    };
+
+    #[test]
+    fn ranges() {
+        // chroma: 0.0 => infinity
+        assert_ranges! {
+            Jzczhz<f64>;
+            clamped {
+                jz: 0.0 => 1.0
+            }
+            clamped_min {}
+            unclamped {
+                hue: 0.0 => 360.0
+            }
+        }
+    }
+
+    #[test]
+    fn check_min_max_components() {
+        assert_relative_eq!(Jzczhz::<f64>::min_jz(), 0.0);
+        assert_relative_eq!(Jzczhz::<f64>::max_jz(), 1.0);
+        assert_relative_eq!(Jzczhz::<f64>::min_chroma(), 0.0);
+        assert_relative_eq!(Jzczhz::<f64>::max_chroma(), 0.5);
+    }
+
+    #[test]
+    fn roundtrip() {
+        let rgb = Srgb::new(0.8f64, 0.3, 0.5);
+        let jzczhz = Jzczhz::from_color_unclamped(rgb);
+
+        assert_relative_eq!(Srgb::from_color_unclamped(jzczhz), rgb, epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn color_difference_matches_jzazbz_distance() {
+        let a = Jzazbz::<f64>::new(0.1, 0.02, -0.01);
+        let b = Jzazbz::<f64>::new(0.13, -0.02, 0.02);
+        let difference =
+            Jzczhz::from_color_unclamped(a).get_color_difference(Jzczhz::from_color_unclamped(b));
+
+        assert_relative_eq!(difference, 0.0583095, epsilon = 0.0000001);
+    }
+
+    #[test]
+    fn saturate_and_shift_hue() {
+        let color = Jzczhz::new(0.1f64, 0.1, 40.0);
+
+        assert_relative_eq!(color.saturate(0.5).chroma, 0.3);
+        assert_relative_eq!(color.shift_hue(30.0).hue, 70.0.into());
+    }
+}
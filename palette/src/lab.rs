@@ -26,6 +26,7 @@ use crate::{
         self, Abs, Arithmetics, Cbrt, Exp, FromScalarArray, IntoScalarArray, IsValidDivisor,
         MinMax, One, PartialCmp, Powi, Real, Sqrt, Trigonometry, Zero,
     },
+    rgb::Rgb,
     stimulus::Stimulus,
     white_point::{WhitePoint, D65},
     Alpha, Clamp, ClampAssign, FromColor, GetHue, IsWithinBounds, LabHue, Lch, Lighten,
@@ -59,7 +60,7 @@ pub type Laba<Wp = D65, T = f32> = Alpha<Lab<Wp, T>, T>;
     palette_internal,
     white_point = "Wp",
     component = "T",
-    skip_derives(Xyz, Lab, Lch)
+    skip_derives(Xyz, Rgb, Lab, Lch)
 )]
 #[repr(C)]
 pub struct Lab<Wp = D65, T = f32> {
@@ -213,6 +214,18 @@ where
     }
 }
 
+impl<Wp, T, S> FromColorUnclamped<Rgb<S, T>> for Lab<Wp, T>
+where
+    Xyz<Wp, T>: FromColorUnclamped<Rgb<S, T>>,
+    Self: FromColorUnclamped<Xyz<Wp, T>>,
+{
+    fn from_color_unclamped(color: Rgb<S, T>) -> Self {
+        // The white points may be different, in which case the color is
+        // adapted while converting to `Xyz`.
+        Self::from_color_unclamped(Xyz::<Wp, T>::from_color_unclamped(color))
+    }
+}
+
 impl<Wp, T> FromColorUnclamped<Lch<Wp, T>> for Lab<Wp, T>
 where
     T: RealAngle + Zero + MinMax + Trigonometry + Mul<Output = T> + Clone,
@@ -471,21 +484,21 @@ mod test {
 
     #[test]
     fn red() {
-        let a = Lab::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+        let a = Lab::<D65, _>::from_color(LinSrgb::new(1.0, 0.0, 0.0));
         let b = Lab::new(53.23288, 80.09246, 67.2031);
         assert_relative_eq!(a, b, epsilon = 0.01);
     }
 
     #[test]
     fn green() {
-        let a = Lab::from_color(LinSrgb::new(0.0, 1.0, 0.0));
+        let a = Lab::<D65, _>::from_color(LinSrgb::new(0.0, 1.0, 0.0));
         let b = Lab::new(87.73704, -86.184654, 83.18117);
         assert_relative_eq!(a, b, epsilon = 0.01);
     }
 
     #[test]
     fn blue() {
-        let a = Lab::from_color(LinSrgb::new(0.0, 0.0, 1.0));
+        let a = Lab::<D65, _>::from_color(LinSrgb::new(0.0, 0.0, 1.0));
         let b = Lab::new(32.302586, 79.19668, -107.863686);
         assert_relative_eq!(a, b, epsilon = 0.01);
     }
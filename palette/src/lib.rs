@@ -230,10 +230,15 @@ pub use alpha::{Alpha, WithAlpha};
 #[cfg(feature = "std")]
 pub use gradient::Gradient;
 
+pub use cam16::{Cam16, Cam16Ucs, Cam16Ucsa, Cam16a};
+pub use hct::{Hct, Hcta};
 pub use hsl::{Hsl, Hsla};
 pub use hsluv::{Hsluv, Hsluva};
 pub use hsv::{Hsv, Hsva};
 pub use hwb::{Hwb, Hwba};
+pub use ictcp::{ICtCp, ICtCpa};
+pub use jzazbz::{Jzazbz, Jzazbza};
+pub use jzczhz::{Jzczhz, Jzczhza};
 pub use lab::{Lab, Laba};
 pub use lch::{Lch, Lcha};
 pub use lchuv::{Lchuv, Lchuva};
@@ -250,9 +255,11 @@ pub use yxy::{Yxy, Yxya};
 
 pub use color_difference::ColorDifference;
 pub use convert::{FromColor, FromColorMut, FromColorMutGuard, IntoColor, IntoColorMut};
-pub use hues::{LabHue, LuvHue, OklabHue, RgbHue};
+pub use hues::{Cam16Hue, JzazbzHue, LabHue, LuvHue, OklabHue, RgbHue};
 pub use matrix::Mat3;
-pub use relative_contrast::{contrast_ratio, RelativeContrast};
+pub use relative_contrast::{
+    contrast_ratio, lightness_contrast, min_lightness_contrast, LightnessContrast, RelativeContrast,
+};
 
 //Helper macro for checking ranges and clamping.
 #[cfg(test)]
@@ -426,16 +433,25 @@ mod alpha;
 pub mod angle;
 pub mod blend;
 pub mod bool_mask;
+pub mod cam16;
 pub mod cast;
+pub mod cct;
 pub mod chromatic_adaptation;
-mod color_difference;
+pub mod color_difference;
+pub mod contrast;
 pub mod convert;
+pub mod cvd;
 pub mod encoding;
+pub mod gamut;
+pub mod hct;
 mod hsl;
 mod hsluv;
 mod hsv;
 mod hues;
 mod hwb;
+pub mod ictcp;
+mod jzazbz;
+mod jzczhz;
 mod lab;
 mod lch;
 mod lchuv;
@@ -449,8 +465,10 @@ mod okhsv;
 mod okhwb;
 mod oklab;
 mod oklch;
+pub mod pigment;
 mod relative_contrast;
 pub mod rgb;
+pub mod spectral;
 pub mod stimulus;
 pub mod white_point;
 mod xyz;
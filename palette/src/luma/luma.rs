@@ -23,15 +23,18 @@ use crate::{
     cast::{ComponentOrder, Packed, UintCast},
     clamp, clamp_assign, contrast_ratio,
     convert::FromColorUnclamped,
-    encoding::{linear::LinearFn, FromLinear, IntoLinear, Linear, Srgb},
+    encoding::{
+        linear::LinearFn, FromLinear, IntoLinear, Linear, Parametric, ParametricCurve, Srgb,
+    },
+    lightness_contrast,
     luma::LumaStandard,
     num::{
-        self, Arithmetics, FromScalarArray, IntoScalarArray, IsValidDivisor, MinMax, One,
-        PartialCmp, Real, Zero,
+        self, Abs, Arithmetics, FromScalarArray, IntoScalarArray, IsValidDivisor, MinMax, One,
+        PartialCmp, Powf, Real, Zero,
     },
     stimulus::{FromStimulus, Stimulus, StimulusColor},
-    Alpha, Clamp, ClampAssign, IsWithinBounds, Lighten, LightenAssign, Mix, MixAssign,
-    RelativeContrast, Xyz, Yxy,
+    Alpha, Clamp, ClampAssign, FromColor, IsWithinBounds, Lighten, LightenAssign,
+    LightnessContrast, Mix, MixAssign, RelativeContrast, Xyz, Yxy,
 };
 
 /// Luminance with an alpha component. See the [`Lumaa` implementation
@@ -247,6 +250,52 @@ where
     }
 }
 
+impl<S, T> Luma<S, T>
+where
+    S: LumaStandard<TransferFn = Parametric>,
+    T: Zero + One + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    /// Convert the color to linear luminance, using a transfer function with
+    /// runtime parameters.
+    ///
+    /// ```
+    /// use palette::{
+    ///     encoding::{Parametric, ParametricCurve},
+    ///     luma::Luma,
+    ///     white_point::D65,
+    ///     LinLuma,
+    /// };
+    ///
+    /// let curve = ParametricCurve::gamma(1.8);
+    /// let linear: LinLuma = Luma::<(D65, Parametric)>::new(0.5).into_linear_with(&curve);
+    /// ```
+    pub fn into_linear_with(self, curve: &ParametricCurve<T>) -> Luma<Linear<S::WhitePoint>, T> {
+        Luma::new(curve.into_linear(self.luma))
+    }
+
+    /// Convert linear luminance to non-linear luminance, using a transfer
+    /// function with runtime parameters.
+    ///
+    /// ```
+    /// use palette::{
+    ///     encoding::{Parametric, ParametricCurve},
+    ///     luma::Luma,
+    ///     white_point::D65,
+    ///     LinLuma,
+    /// };
+    ///
+    /// let curve = ParametricCurve::gamma(1.8);
+    /// let encoded = Luma::<(D65, Parametric)>::from_linear_with(LinLuma::new(0.95), &curve);
+    /// ```
+    pub fn from_linear_with(
+        color: Luma<Linear<S::WhitePoint>, T>,
+        curve: &ParametricCurve<T>,
+    ) -> Luma<S, T> {
+        Luma::new(curve.from_linear(color.luma))
+    }
+}
+
 impl<Wp, T> Luma<Linear<Wp>, T> {
     /// Convert a linear color to a different encoding.
     ///
@@ -1105,6 +1154,28 @@ where
     }
 }
 
+impl<S, T> LightnessContrast for Luma<S, T>
+where
+    T: Real + Zero + Powf + Abs + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+    Luma<Srgb, T>: FromColor<Self>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_lightness_contrast(self, background: Self) -> T {
+        // The sRGB luminance coefficients sum up to 1 for gray.
+        let text = Luma::<Srgb, T>::from_color(self)
+            .luma
+            .powf(T::from_f64(2.4));
+        let background = Luma::<Srgb, T>::from_color(background)
+            .luma
+            .powf(T::from_f64(2.4));
+
+        lightness_contrast(text, background)
+    }
+}
+
 #[cfg(feature = "random")]
 impl<S, T> Distribution<Luma<S, T>> for Standard
 where
@@ -88,6 +88,33 @@ where
     }
 }
 
+/// Multiply the 3x3 matrix with a linear RGB color, in the same RGB space.
+#[inline]
+pub fn multiply_rgb<S, T>(c: Mat3<T>, f: Rgb<Linear<S>, T>) -> Rgb<Linear<S>, T>
+where
+    T: Arithmetics,
+{
+    // Input Mat3 is destructured to avoid panic paths
+    let [c0, c1, c2, c3, c4, c5, c6, c7, c8] = c;
+
+    let red1 = c0 * &f.red;
+    let green1 = c3 * &f.red;
+    let blue1 = c6 * f.red;
+    let red2 = c1 * &f.green;
+    let green2 = c4 * &f.green;
+    let blue2 = c7 * f.green;
+    let red3 = c2 * &f.blue;
+    let green3 = c5 * &f.blue;
+    let blue3 = c8 * f.blue;
+
+    Rgb {
+        red: red1 + red2 + red3,
+        green: green1 + green2 + green3,
+        blue: blue1 + blue2 + blue3,
+        standard: PhantomData,
+    }
+}
+
 /// Multiply two 3x3 matrices.
 #[inline]
 pub fn multiply_3x3<T>(c: Mat3<T>, f: Mat3<T>) -> Mat3<T>
@@ -162,16 +189,38 @@ where
     T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
     Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
 {
-    let r = S::Primaries::red().into_color_unclamped();
-    let g = S::Primaries::green().into_color_unclamped();
-    let b = S::Primaries::blue().into_color_unclamped();
+    rgb_to_xyz_matrix_from_primaries(
+        S::Primaries::red(),
+        S::Primaries::green(),
+        S::Primaries::blue(),
+        S::WhitePoint::get_xyz(),
+    )
+}
+
+/// Generates the RGB to XYZ transformation matrix for primaries and a white
+/// point that are only known at runtime.
+///
+/// Only the chromaticity of the primaries is used, so their luminance can be
+/// anything but 0.0.
+#[inline]
+pub fn rgb_to_xyz_matrix_from_primaries<T>(
+    red: Yxy<Any, T>,
+    green: Yxy<Any, T>,
+    blue: Yxy<Any, T>,
+    white_point: Xyz<Any, T>,
+) -> Mat3<T>
+where
+    T: Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T>,
+    Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+{
+    let r = red.into_color_unclamped();
+    let g = green.into_color_unclamped();
+    let b = blue.into_color_unclamped();
 
     let matrix = mat3_from_primaries(r, g, b);
 
-    let s_matrix: Rgb<Linear<S>, T> = multiply_xyz_to_rgb(
-        matrix_inverse(matrix.clone()),
-        S::WhitePoint::get_xyz().with_white_point(),
-    );
+    let s_matrix: Rgb<Linear<(Any, Any)>, T> =
+        multiply_xyz_to_rgb(matrix_inverse(matrix.clone()), white_point);
 
     // Destructuring has some performance benefits, don't change unless measured
     let [t0, t1, t2, t3, t4, t5, t6, t7, t8] = matrix;
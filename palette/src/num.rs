@@ -211,6 +211,13 @@ pub trait Exp {
     fn exp(self) -> Self;
 }
 
+/// Methods for calculating the natural logarithm, `ln(x)`.
+pub trait Ln {
+    /// Return the natural logarithm of `self`.
+    #[must_use]
+    fn ln(self) -> Self;
+}
+
 /// Methods for checking if a number can be used as a divisor.
 pub trait IsValidDivisor: HasBoolMask {
     /// Return `true` if `self` can be used as a divisor in `x / self`.
@@ -595,6 +602,14 @@ macro_rules! impl_float {
                 }
             }
 
+            #[cfg(feature = "std")]
+            impl Ln for $ty {
+                #[inline]
+                fn ln(self) -> Self {
+                    $ty::ln(self)
+                }
+            }
+
             #[cfg(feature = "std")]
             impl Hypot for $ty {
                 #[inline]
@@ -192,6 +192,20 @@ impl Exp for f64 {
     }
 }
 
+impl Ln for f32 {
+    #[inline]
+    fn ln(self) -> Self {
+        ::libm::logf(self)
+    }
+}
+
+impl Ln for f64 {
+    #[inline]
+    fn ln(self) -> Self {
+        ::libm::log(self)
+    }
+}
+
 impl Hypot for f32 {
     #[inline]
     fn hypot(self, other: Self) -> Self {
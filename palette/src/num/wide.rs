@@ -183,6 +183,13 @@ macro_rules! impl_wide_float {
                 }
             }
 
+            impl Ln for $ty {
+                #[inline]
+                fn ln(self) -> Self {
+                    $ty::ln(self)
+                }
+            }
+
             impl Hypot for $ty {
                 #[inline]
                 fn hypot(self, other: Self) -> Self {
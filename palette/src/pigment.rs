@@ -0,0 +1,435 @@
+//! Subtractive, pigment-like color mixing, with the Kubelka-Munk model.
+//!
+//! [`Mix`](crate::Mix) interpolates the components of two colors, which is
+//! how light mixes. Paint mixes differently, since each pigment absorbs and
+//! scatters some of the light, so blue and yellow paint gives green instead of
+//! gray. The Kubelka-Munk model describes this with an absorption spectrum,
+//! `K`, and a scattering spectrum, `S`, for each [`Pigment`]. The spectra of a
+//! mix are the weighted averages of the spectra of its pigments, and the
+//! resulting reflectance is given by the ratio between them.
+//!
+//! ```
+//! use palette::{pigment::PigmentMix, Srgb};
+//!
+//! let blue = Srgb::new(0.1f64, 0.35, 0.6);
+//! let yellow = Srgb::new(1.0f64, 0.9, 0.0);
+//!
+//! let green = blue.pigment_mix(yellow, 0.5);
+//! assert!(green.green > green.red && green.green > green.blue);
+//! ```
+//!
+//! Colors are turned into pigments by finding a smooth reflectance spectrum
+//! for them, with [`SigmoidPolynomial`], and assuming that all pigments
+//! scatter light equally. Pigments with measured absorption and scattering
+//! can be created with [`Pigment::new`].
+
+use crate::{
+    bool_mask::{HasBoolMask, LazySelect},
+    convert::FromColorUnclamped,
+    encoding::{FromLinear, IntoLinear, Srgb as SrgbEncoding},
+    num::{
+        Abs, Arithmetics, Cbrt, Clamp, IsValidDivisor, MinMax, One, PartialCmp, Powi, Real, Recip,
+        Sqrt, Zero,
+    },
+    spectral::{Cie1931, SigmoidPolynomial, Spectrum, REFLECTANCE_EPSILON},
+    white_point::D65,
+    LinSrgb, Srgb, Xyz,
+};
+
+/// A pigment, described by its absorption and scattering spectra.
+///
+/// The spectra are sampled from 380 nm to 780 nm in 10 nm steps, like the
+/// embedded [`spectral`](crate::spectral) data.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Pigment<T> {
+    absorption: Spectrum<[T; 41]>,
+    scattering: Spectrum<[T; 41]>,
+}
+
+impl<T> Pigment<T> {
+    /// Create a pigment from its absorption spectrum, `K`, and its scattering
+    /// spectrum, `S`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the spectra aren't sampled from 380 nm in 10 nm steps.
+    pub fn new(absorption: Spectrum<[T; 41]>, scattering: Spectrum<[T; 41]>) -> Self {
+        assert!(
+            absorption.start() == 380.0 && absorption.step() == 10.0,
+            "the absorption spectrum must be sampled from 380 nm in 10 nm steps"
+        );
+        assert!(
+            scattering.start() == 380.0 && scattering.step() == 10.0,
+            "the scattering spectrum must be sampled from 380 nm in 10 nm steps"
+        );
+
+        Pigment {
+            absorption,
+            scattering,
+        }
+    }
+
+    /// Get the absorption spectrum, `K`.
+    pub fn absorption(&self) -> &Spectrum<[T; 41]> {
+        &self.absorption
+    }
+
+    /// Get the scattering spectrum, `S`.
+    pub fn scattering(&self) -> &Spectrum<[T; 41]> {
+        &self.scattering
+    }
+}
+
+impl<T> Pigment<T>
+where
+    T: Real + Zero + One + Sqrt + Arithmetics + Clone,
+{
+    /// Create a pigment that scatters light equally at all wavelengths, and
+    /// has the reflectance `reflectance`.
+    ///
+    /// The reflectance values should not be above 1.0. Values that are close
+    /// to or below 0.0 are raised to a small positive value, the same as for
+    /// the darkest grays in [`SigmoidPolynomial`], since a reflectance of 0.0
+    /// would need an infinite absorption.
+    #[must_use]
+    pub fn from_reflectance(reflectance: &Spectrum<[T; 41]>) -> Self
+    where
+        T: MinMax,
+    {
+        let absorption = reflectance.values().clone().map(|reflectance| {
+            let reflectance = reflectance.max(T::from_f64(REFLECTANCE_EPSILON));
+            let absorbed = T::one() - &reflectance;
+            absorbed.clone() * absorbed / (T::from_f64(2.0) * reflectance)
+        });
+        let scattering = core::array::from_fn(|_| T::one());
+
+        Pigment::new(
+            Spectrum::new(380.0, 10.0, absorption),
+            Spectrum::new(380.0, 10.0, scattering),
+        )
+    }
+
+    /// Get the reflectance spectrum of an opaque layer of the pigment.
+    #[must_use]
+    pub fn reflectance(&self) -> Spectrum<[T; 41]> {
+        let absorption = self.absorption.values();
+        let scattering = self.scattering.values();
+
+        let values = core::array::from_fn(|index| {
+            let ratio = absorption[index].clone() / &scattering[index];
+            T::one() + &ratio - (ratio.clone() * &ratio + T::from_f64(2.0) * ratio).sqrt()
+        });
+
+        Spectrum::new(380.0, 10.0, values)
+    }
+
+    /// Mix a weighted set of pigments.
+    ///
+    /// Each pigment's share of the mix is its weight divided by the sum of
+    /// all weights. The weights should not be negative. Returns `None` if the
+    /// sum of the weights is 0.0, or can't be divided by, such as when there
+    /// are no pigments.
+    ///
+    /// ```
+    /// use palette::{pigment::Pigment, LinSrgb};
+    ///
+    /// let red = Pigment::from_linear_srgb(LinSrgb::new(0.7f64, 0.05, 0.05));
+    /// let blue = Pigment::from_linear_srgb(LinSrgb::new(0.05f64, 0.05, 0.6));
+    /// let white = Pigment::from_linear_srgb(LinSrgb::new(0.9f64, 0.9, 0.9));
+    ///
+    /// let pastel_purple = Pigment::mix([(&red, 1.0), (&blue, 1.0), (&white, 2.0)]).unwrap();
+    /// let color = pastel_purple.to_linear_srgb();
+    ///
+    /// assert_eq!(Pigment::mix([(&red, 0.0), (&blue, 0.0)]), None);
+    /// ```
+    #[must_use]
+    pub fn mix<'a, I>(pigments: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (&'a Pigment<T>, T)>,
+        T: IsValidDivisor<Mask = bool> + 'a,
+    {
+        let (sum, total_weight) = Self::weighted_sum(pigments);
+        if !total_weight.is_valid_divisor() {
+            return None;
+        }
+
+        let Pigment {
+            absorption,
+            scattering,
+        } = sum;
+
+        Some(Pigment::new(
+            Spectrum::new(
+                380.0,
+                10.0,
+                absorption.into_values().map(|k| k / &total_weight),
+            ),
+            Spectrum::new(
+                380.0,
+                10.0,
+                scattering.into_values().map(|s| s / &total_weight),
+            ),
+        ))
+    }
+
+    /// Add up the weighted spectra of `pigments`, and get the total weight.
+    fn weighted_sum<'a, I>(pigments: I) -> (Self, T)
+    where
+        I: IntoIterator<Item = (&'a Pigment<T>, T)>,
+        T: 'a,
+    {
+        let mut total_weight = T::zero();
+        let mut absorption: [T; 41] = core::array::from_fn(|_| T::zero());
+        let mut scattering: [T; 41] = core::array::from_fn(|_| T::zero());
+
+        for (pigment, weight) in pigments {
+            let samples = pigment.absorption.values().iter();
+            for (mixed, sample) in absorption.iter_mut().zip(samples) {
+                *mixed = mixed.clone() + weight.clone() * sample;
+            }
+
+            let samples = pigment.scattering.values().iter();
+            for (mixed, sample) in scattering.iter_mut().zip(samples) {
+                *mixed = mixed.clone() + weight.clone() * sample;
+            }
+
+            total_weight = total_weight + weight;
+        }
+
+        let sum = Pigment::new(
+            Spectrum::new(380.0, 10.0, absorption),
+            Spectrum::new(380.0, 10.0, scattering),
+        );
+
+        (sum, total_weight)
+    }
+
+    /// Convert the color of an opaque layer of the pigment under D65 light to
+    /// linear sRGB.
+    ///
+    /// Mixes of saturated pigments may end up outside the sRGB gamut, so the
+    /// result isn't clamped.
+    #[must_use]
+    pub fn to_linear_srgb(&self) -> LinSrgb<T>
+    where
+        LinSrgb<T>: FromColorUnclamped<Xyz<D65, T>>,
+    {
+        let xyz: Xyz<D65, T> = self.reflectance().reflective_to_xyz(Cie1931, D65);
+        LinSrgb::from_color_unclamped(xyz)
+    }
+
+    /// Convert the color of an opaque layer of the pigment under D65 light to
+    /// non-linear sRGB.
+    #[must_use]
+    pub fn to_srgb(&self) -> Srgb<T>
+    where
+        LinSrgb<T>: FromColorUnclamped<Xyz<D65, T>>,
+        SrgbEncoding: FromLinear<T, T>,
+    {
+        Srgb::from_linear(self.to_linear_srgb())
+    }
+}
+
+impl<T> Pigment<T>
+where
+    T: Real
+        + Zero
+        + One
+        + Sqrt
+        + Abs
+        + MinMax
+        + Clamp
+        + Powi
+        + Cbrt
+        + Recip
+        + IsValidDivisor<Mask = bool>
+        + PartialCmp
+        + HasBoolMask<Mask = bool>
+        + Arithmetics
+        + Clone,
+    T::Mask: LazySelect<T>,
+    Xyz<D65, T>: FromColorUnclamped<LinSrgb<T>>,
+{
+    /// Create a pigment with the color of `color`, under D65 light.
+    ///
+    /// The pigment gets a smooth reflectance spectrum, from
+    /// [`SigmoidPolynomial::from_linear_srgb`], and scatters light equally at
+    /// all wavelengths.
+    #[must_use]
+    pub fn from_linear_srgb(color: LinSrgb<T>) -> Self {
+        let reflectance = SigmoidPolynomial::from_linear_srgb(color).to_spectrum();
+        Self::from_reflectance(&reflectance)
+    }
+
+    /// Create a pigment with the color of `color`, under D65 light.
+    ///
+    /// See [`Pigment::from_linear_srgb`].
+    #[must_use]
+    pub fn from_srgb(color: Srgb<T>) -> Self
+    where
+        SrgbEncoding: IntoLinear<T, T>,
+    {
+        Self::from_linear_srgb(color.into_linear())
+    }
+}
+
+/// Subtractive color mixing of two colors, as if they were paint.
+///
+/// The colors are turned into [`Pigment`]s and mixed with the Kubelka-Munk
+/// model, which is much slower than [`Mix`](crate::Mix), so prefer creating
+/// the pigments once when mixing the same colors many times.
+///
+/// ```
+/// use palette::{pigment::PigmentMix, LinSrgb, Mix};
+///
+/// let blue = LinSrgb::new(0.01f64, 0.1, 0.32);
+/// let yellow = LinSrgb::new(1.0f64, 0.79, 0.0);
+///
+/// let paint = blue.pigment_mix(yellow, 0.5);
+/// let light = blue.mix(yellow, 0.5);
+/// assert!(paint.green > paint.blue);
+/// assert!(light.red > paint.red);
+/// ```
+pub trait PigmentMix {
+    /// The type of the mixing factor.
+    type Scalar;
+
+    /// Mix the color with an other color, by `factor`.
+    ///
+    /// `factor` should be between `0.0` and `1.0`, where `0.0` will result in
+    /// the same color as `self` and `1.0` will result in the same color as
+    /// `other`.
+    #[must_use]
+    fn pigment_mix(self, other: Self, factor: Self::Scalar) -> Self;
+}
+
+impl<T> PigmentMix for LinSrgb<T>
+where
+    T: Real
+        + Zero
+        + One
+        + Sqrt
+        + Abs
+        + MinMax
+        + Clamp
+        + Powi
+        + Cbrt
+        + Recip
+        + IsValidDivisor<Mask = bool>
+        + PartialCmp
+        + HasBoolMask<Mask = bool>
+        + Arithmetics
+        + Clone,
+    T::Mask: LazySelect<T>,
+    Xyz<D65, T>: FromColorUnclamped<LinSrgb<T>>,
+    LinSrgb<T>: FromColorUnclamped<Xyz<D65, T>>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn pigment_mix(self, other: Self, factor: T) -> Self {
+        let factor = factor.clamp(T::zero(), T::one());
+        let this = Pigment::from_linear_srgb(self);
+        let other = Pigment::from_linear_srgb(other);
+
+        // The weights add up to 1.0, so the weighted sum is already the mix.
+        let (mixed, _) = Pigment::weighted_sum([(&this, T::one() - &factor), (&other, factor)]);
+        mixed.to_linear_srgb()
+    }
+}
+
+impl<T> PigmentMix for Srgb<T>
+where
+    LinSrgb<T>: PigmentMix<Scalar = T>,
+    SrgbEncoding: IntoLinear<T, T> + FromLinear<T, T>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn pigment_mix(self, other: Self, factor: T) -> Self {
+        let mixed = self.into_linear().pigment_mix(other.into_linear(), factor);
+        Srgb::from_linear(mixed)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::{Pigment, PigmentMix};
+    use crate::{spectral::Spectrum, LinSrgb, Mix, Srgb};
+
+    #[test]
+    fn roundtrip() {
+        let color = LinSrgb::new(0.6f64, 0.3, 0.2);
+        let pigment = Pigment::from_linear_srgb(color);
+        assert_relative_eq!(pigment.to_linear_srgb(), color, epsilon = 0.0001);
+
+        let color = Srgb::new(0.2f64, 0.5, 0.7);
+        let pigment = Pigment::from_srgb(color);
+        assert_relative_eq!(pigment.to_srgb(), color, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn mix_ends() {
+        let a = LinSrgb::new(0.6f64, 0.3, 0.2);
+        let b = LinSrgb::new(0.1f64, 0.2, 0.5);
+
+        assert_relative_eq!(a.pigment_mix(b, 0.0), a, epsilon = 0.0001);
+        assert_relative_eq!(a.pigment_mix(b, 1.0), b, epsilon = 0.0001);
+    }
+
+    #[test]
+    fn same_pigment() {
+        let pigment = Pigment::from_linear_srgb(LinSrgb::new(0.6f64, 0.3, 0.2));
+        let mixed = Pigment::mix([(&pigment, 2.0), (&pigment, 3.0)]).unwrap();
+
+        assert_relative_eq!(
+            mixed.to_linear_srgb(),
+            pigment.to_linear_srgb(),
+            epsilon = 0.0000001
+        );
+    }
+
+    #[test]
+    fn zero_total_weight() {
+        let pigment = Pigment::from_linear_srgb(LinSrgb::new(0.6f64, 0.3, 0.2));
+
+        assert_eq!(Pigment::mix([(&pigment, 0.0), (&pigment, 0.0)]), None);
+        assert_eq!(Pigment::<f64>::mix([]), None);
+    }
+
+    #[test]
+    fn zero_reflectance() {
+        let black = Pigment::from_reflectance(&Spectrum::new(380.0, 10.0, [0.0f64; 41]));
+
+        for &reflectance in black.reflectance().values() {
+            assert!(reflectance.is_finite());
+            assert_relative_eq!(reflectance, 0.0, epsilon = 0.00001);
+        }
+    }
+
+    #[test]
+    fn blue_and_yellow_make_green() {
+        let blue = Srgb::new(0.1f64, 0.35, 0.6);
+        let yellow = Srgb::new(1.0f64, 0.9, 0.0);
+
+        let paint = blue.pigment_mix(yellow, 0.5);
+        assert!(paint.green > paint.red);
+        assert!(paint.green > paint.blue);
+
+        // Linear mixing gives a grayish orange instead.
+        let light = blue.into_linear().mix(yellow.into_linear(), 0.5);
+        assert!(light.red > light.green);
+    }
+
+    #[test]
+    fn darker_than_light_mixing() {
+        let red = LinSrgb::new(0.7f64, 0.05, 0.05);
+        let green = LinSrgb::new(0.05f64, 0.6, 0.05);
+
+        let paint = red.pigment_mix(green, 0.5);
+        let light = red.mix(green, 0.5);
+
+        assert!(paint.red < light.red);
+        assert!(paint.green < light.green);
+    }
+}
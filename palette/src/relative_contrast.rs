@@ -1,6 +1,6 @@
 use crate::{
-    bool_mask::{HasBoolMask, LazySelect},
-    num::{Arithmetics, PartialCmp, Real},
+    bool_mask::{BoolMask, HasBoolMask, LazySelect},
+    num::{Abs, Arithmetics, PartialCmp, Powf, Real, Zero},
 };
 
 /// A trait for calculating relative contrast between two colors.
@@ -116,12 +116,208 @@ where
     }
 }
 
+/// A trait for calculating the APCA lightness contrast between text and its
+/// background.
+///
+/// The Accessible Perceptual Contrast Algorithm (APCA) is the contrast method
+/// in the WCAG 3 working draft. Unlike the WCAG 2.1 contrast ratio in
+/// [`RelativeContrast`], it's perceptually uniform and takes the polarity of
+/// the colors into account. Light text on a dark background needs a different
+/// luminance difference than dark text on a light background, for the same
+/// readability. This makes it better at judging dark mode color pairs.
+///
+/// The lightness contrast, `Lc`, is roughly between -108 and 106. It's positive
+/// for dark text on a light background and negative for light text on a dark
+/// background. Differences that are too small to be useful for text are
+/// reported as 0. The minimum magnitude for readable text depends on the font
+/// size and weight, and can be looked up with [`min_lightness_contrast`].
+///
+/// ```rust
+/// use palette::{LightnessContrast, Srgb};
+///
+/// let text: Srgb<f32> = Srgb::from(0x888888).into_format();
+/// let background: Srgb<f32> = Srgb::from(0xffffff).into_format();
+///
+/// let contrast = text.get_lightness_contrast(background);
+/// assert!((contrast - 63.06).abs() < 0.01);
+///
+/// // Enough for 24px bold text, but not 16px body text.
+/// assert!(text.has_min_lightness_contrast(background, 24.0, 700));
+/// assert!(!text.has_min_lightness_contrast(background, 16.0, 400));
+/// ```
+///
+/// This implements version 0.0.98G-4g of the APCA-W3 constants. The colors are
+/// converted to sRGB before the luminance is estimated, so other RGB spaces
+/// are clamped to the sRGB gamut.
+///
+/// For more details, visit the following links:
+///
+/// [APCA Readability Criterion](https://readtech.org/ARC/)
+///
+/// [APCA reference implementation](https://github.com/Myndex/apca-w3)
+#[doc(alias = "apca")]
+#[doc(alias = "wcag3")]
+pub trait LightnessContrast: Sized {
+    /// The type of the lightness contrast.
+    type Scalar: Real + PartialCmp + Abs;
+
+    /// Calculate the lightness contrast, `Lc`, of `self` as text on a
+    /// `background`.
+    #[must_use]
+    fn get_lightness_contrast(self, background: Self) -> Self::Scalar;
+
+    /// Verify that the lightness contrast of `self` as text on `background` is
+    /// enough for a font with `font_size` in CSS pixels and `font_weight`,
+    /// according to [`min_lightness_contrast`]. Fonts that are too small or
+    /// thin to be used at any contrast never pass.
+    #[must_use]
+    #[inline]
+    fn has_min_lightness_contrast(
+        self,
+        background: Self,
+        font_size: f64,
+        font_weight: u16,
+    ) -> <Self::Scalar as HasBoolMask>::Mask {
+        match min_lightness_contrast(font_size, font_weight) {
+            Some(minimum) => self
+                .get_lightness_contrast(background)
+                .abs()
+                .gt_eq(&Self::Scalar::from_f64(minimum)),
+            None => BoolMask::from_bool(false),
+        }
+    }
+}
+
+/// Calculate the APCA lightness contrast, `Lc`, from the estimated screen
+/// luminance of the text and background colors.
+///
+/// See [`LightnessContrast`] for more details.
+#[rustfmt::skip]
+#[inline]
+pub fn lightness_contrast<T>(text_luminance: T, background_luminance: T) -> T
+where
+    T: Real + Zero + Powf + Abs + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+{
+    // Soft clamp of near-black colors, to account for flare.
+    let black_threshold = T::from_f64(0.022);
+    let clamp_black = |luminance: T| {
+        lazy_select! {
+            if luminance.lt(&black_threshold) => {
+                luminance.clone() + (black_threshold.clone() - &luminance).powf(T::from_f64(1.414))
+            },
+            else => luminance.clone(),
+        }
+    };
+    let text = clamp_black(text_luminance);
+    let background = clamp_black(background_luminance);
+
+    let scale = T::from_f64(1.14);
+    let low_clip = T::from_f64(0.1);
+    let offset = T::from_f64(0.027);
+
+    let contrast = lazy_select! {
+        if (background.clone() - &text).abs().lt(&T::from_f64(0.0005)) => T::zero(),
+        if background.gt(&text) => {
+            // Dark text on a light background.
+            let contrast = (background.clone().powf(T::from_f64(0.56)) - text.clone().powf(T::from_f64(0.57))) * &scale;
+            lazy_select! {
+                if contrast.lt(&low_clip) => T::zero(),
+                else => contrast.clone() - &offset,
+            }
+        },
+        else => {
+            // Light text on a dark background.
+            let contrast = (background.clone().powf(T::from_f64(0.65)) - text.clone().powf(T::from_f64(0.62))) * &scale;
+            lazy_select! {
+                if contrast.gt(&T::from_f64(-0.1)) => T::zero(),
+                else => contrast.clone() + &offset,
+            }
+        },
+    };
+
+    contrast * T::from_f64(100.0)
+}
+
+/// Estimate the screen luminance of an sRGB color, for APCA.
+///
+/// APCA uses a simple 2.4 exponent instead of the piecewise sRGB transfer
+/// function.
+#[inline]
+pub(crate) fn apca_screen_luminance<T>(red: T, green: T, blue: T) -> T
+where
+    T: Real + Powf + Arithmetics,
+{
+    T::from_f64(0.2126729) * red.powf(T::from_f64(2.4))
+        + T::from_f64(0.7151522) * green.powf(T::from_f64(2.4))
+        + T::from_f64(0.0721750) * blue.powf(T::from_f64(2.4))
+}
+
+/// The font sizes, in CSS pixels, of the rows in [`FONT_LOOKUP`].
+const FONT_SIZES: [f64; 14] = [
+    14.0, 15.0, 16.0, 18.0, 21.0, 24.0, 28.0, 32.0, 36.0, 42.0, 48.0, 60.0, 72.0, 96.0,
+];
+
+/// Minimum `Lc` for each font size and weight, from 100 to 900. A 0 means that
+/// the combination should not be used for text. The last row applies to all
+/// larger sizes.
+#[rustfmt::skip]
+const FONT_LOOKUP: [[u8; 9]; 14] = [
+    //100  200  300  400  500  600  700  800  900
+    [   0,   0,   0, 100, 100,  90,  75,   0,   0], // 14px
+    [   0,   0,   0, 100,  90,  75,  70,   0,   0], // 15px
+    [   0,   0,   0,  90,  75,  70,  60,  60,   0], // 16px
+    [   0,   0, 100,  75,  70,  60,  55,  55,  55], // 18px
+    [   0,   0,  90,  70,  60,  55,  50,  50,  50], // 21px
+    [   0,   0,  75,  60,  55,  50,  45,  45,  45], // 24px
+    [   0, 100,  70,  55,  50,  45,  43,  43,  43], // 28px
+    [   0,  90,  65,  50,  45,  43,  40,  40,  40], // 32px
+    [   0,  75,  60,  45,  43,  40,  38,  38,  38], // 36px
+    [ 100,  70,  55,  43,  40,  38,  35,  35,  35], // 42px
+    [  90,  60,  50,  40,  38,  35,  33,  33,  33], // 48px
+    [  75,  55,  45,  38,  35,  33,  30,  30,  30], // 60px
+    [  60,  50,  40,  35,  33,  30,  30,  30,  30], // 72px
+    [  50,  45,  35,  33,  30,  30,  30,  30,  30], // 96px
+];
+
+/// Look up the minimum APCA lightness contrast magnitude for text with
+/// `font_size` in CSS pixels and `font_weight`, from 100 to 900.
+///
+/// The values are from the font lookup table of the APCA Readability
+/// Criterion. Sizes and weights between the table entries are rounded down,
+/// toward the more demanding entry. `None` is returned for fonts that are too
+/// small or thin to be used for text at any contrast, and for weights below
+/// 100.
+///
+/// ```rust
+/// use palette::min_lightness_contrast;
+///
+/// assert_eq!(min_lightness_contrast(18.0, 400), Some(75.0));
+/// assert_eq!(min_lightness_contrast(24.0, 700), Some(45.0));
+/// assert_eq!(min_lightness_contrast(12.0, 400), None);
+/// ```
+#[must_use]
+pub fn min_lightness_contrast(font_size: f64, font_weight: u16) -> Option<f64> {
+    if font_weight < 100 {
+        return None;
+    }
+
+    let row = FONT_SIZES.iter().rposition(|&size| size <= font_size)?;
+    let column = (usize::from(font_weight.min(900)) / 100) - 1;
+
+    match FONT_LOOKUP[row][column] {
+        0 => None,
+        minimum => Some(f64::from(minimum)),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use core::str::FromStr;
 
-    use crate::RelativeContrast;
-    use crate::Srgb;
+    use super::min_lightness_contrast;
+    use crate::{LightnessContrast, RelativeContrast};
+    use crate::{LinLuma, Srgb, SrgbLuma};
 
     #[test]
     fn relative_contrast() {
@@ -161,4 +357,107 @@ mod test {
         assert_relative_eq!(c1.get_contrast_ratio(white), 1.22, epsilon = 0.01);
         assert_relative_eq!(c1.get_contrast_ratio(black), 17.11, epsilon = 0.01);
     }
+
+    #[test]
+    fn lightness_contrast() {
+        let white = Srgb::new(1.0f64, 1.0, 1.0);
+        let black = Srgb::new(0.0, 0.0, 0.0);
+        let gray = Srgb::from(0x888888).into_format();
+        let light_gray = Srgb::from(0xaaaaaa).into_format();
+
+        assert_relative_eq!(white.get_lightness_contrast(white), 0.0);
+        assert_relative_eq!(black.get_lightness_contrast(white), 106.04, epsilon = 0.01);
+        assert_relative_eq!(white.get_lightness_contrast(black), -107.88, epsilon = 0.01);
+
+        // Values from the APCA reference implementation.
+        assert_relative_eq!(
+            gray.get_lightness_contrast(white),
+            63.056469930209424,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            white.get_lightness_contrast(gray),
+            -68.54146436644962,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            black.get_lightness_contrast(light_gray),
+            58.146262578561334,
+            epsilon = 0.000001
+        );
+        assert_relative_eq!(
+            light_gray.get_lightness_contrast(black),
+            -56.24113336839742,
+            epsilon = 0.000001
+        );
+
+        let text: Srgb<f64> = Srgb::from(0x123456).into_format();
+        let background: Srgb<f64> = Srgb::from(0xdef012).into_format();
+        assert_relative_eq!(
+            text.get_lightness_contrast(background),
+            83.085,
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            text.into_linear()
+                .get_lightness_contrast(background.into_linear()),
+            83.085,
+            epsilon = 0.001
+        );
+
+        // Too small differences are clipped to 0.
+        let almost_black = Srgb::new(0.05f64, 0.05, 0.05);
+        assert_relative_eq!(almost_black.get_lightness_contrast(black), 0.0);
+    }
+
+    #[test]
+    fn lightness_contrast_luma() {
+        let white = SrgbLuma::new(1.0f64);
+        let gray = SrgbLuma::new(136.0 / 255.0);
+
+        assert_relative_eq!(
+            gray.get_lightness_contrast(white),
+            63.056469930209424,
+            epsilon = 0.0001
+        );
+
+        let white = LinLuma::new(1.0f64);
+        let gray = SrgbLuma::new(136.0 / 255.0).into_linear();
+
+        assert_relative_eq!(
+            white.get_lightness_contrast(gray),
+            -68.54146436644962,
+            epsilon = 0.0001
+        );
+    }
+
+    #[test]
+    fn font_lookup() {
+        assert_eq!(min_lightness_contrast(14.0, 400), Some(100.0));
+        assert_eq!(min_lightness_contrast(16.0, 400), Some(90.0));
+        assert_eq!(min_lightness_contrast(16.0, 700), Some(60.0));
+        assert_eq!(min_lightness_contrast(18.0, 400), Some(75.0));
+        assert_eq!(min_lightness_contrast(24.0, 400), Some(60.0));
+        assert_eq!(min_lightness_contrast(36.0, 400), Some(45.0));
+        assert_eq!(min_lightness_contrast(24.0, 700), Some(45.0));
+
+        // Between entries, towards the more demanding one.
+        assert_eq!(min_lightness_contrast(17.0, 450), Some(90.0));
+        assert_eq!(min_lightness_contrast(200.0, 1000), Some(30.0));
+
+        assert_eq!(min_lightness_contrast(13.0, 400), None);
+        assert_eq!(min_lightness_contrast(16.0, 300), None);
+        assert_eq!(min_lightness_contrast(48.0, 50), None);
+    }
+
+    #[test]
+    fn has_min_lightness_contrast() {
+        let white = Srgb::new(1.0f32, 1.0, 1.0);
+        let gray: Srgb<f32> = Srgb::from(0x888888).into_format();
+
+        assert!(gray.has_min_lightness_contrast(white, 24.0, 700));
+        assert!(white.has_min_lightness_contrast(gray, 24.0, 700));
+        assert!(!gray.has_min_lightness_contrast(white, 16.0, 400));
+        assert!(!Srgb::new(0.0, 0.0, 0.0).has_min_lightness_contrast(white, 12.0, 400));
+    }
 }
@@ -67,9 +67,11 @@ use crate::{
     Yxy,
 };
 
+pub use self::dynamic::DynamicRgbSpace;
 pub use self::rgb::{FromHexError, Rgb, Rgba};
 
 pub mod channels;
+mod dynamic;
 mod rgb;
 
 /// Non-linear sRGB.
@@ -84,6 +86,91 @@ pub type LinSrgb<T = f32> = Rgb<Linear<encoding::Srgb>, T>;
 #[doc(alias = "linear")]
 pub type LinSrgba<T = f32> = Rgba<Linear<encoding::Srgb>, T>;
 
+/// Non-linear Display P3.
+pub type DisplayP3Rgb<T = f32> = Rgb<encoding::DisplayP3, T>;
+/// Non-linear Display P3 with an alpha component.
+pub type DisplayP3Rgba<T = f32> = Rgba<encoding::DisplayP3, T>;
+
+/// Linear Display P3.
+#[doc(alias = "linear")]
+pub type LinDisplayP3<T = f32> = Rgb<Linear<encoding::DisplayP3>, T>;
+/// Linear Display P3 with an alpha component.
+#[doc(alias = "linear")]
+pub type LinDisplayP3a<T = f32> = Rgba<Linear<encoding::DisplayP3>, T>;
+
+/// Non-linear DCI-P3.
+pub type DciP3Rgb<T = f32> = Rgb<encoding::DciP3, T>;
+/// Non-linear DCI-P3 with an alpha component.
+pub type DciP3Rgba<T = f32> = Rgba<encoding::DciP3, T>;
+
+/// Linear DCI-P3.
+#[doc(alias = "linear")]
+pub type LinDciP3<T = f32> = Rgb<Linear<encoding::DciP3>, T>;
+/// Linear DCI-P3 with an alpha component.
+#[doc(alias = "linear")]
+pub type LinDciP3a<T = f32> = Rgba<Linear<encoding::DciP3>, T>;
+
+/// Non-linear Adobe RGB (1998).
+pub type AdobeRgb<T = f32> = Rgb<encoding::AdobeRgb, T>;
+/// Non-linear Adobe RGB (1998) with an alpha component.
+pub type AdobeRgba<T = f32> = Rgba<encoding::AdobeRgb, T>;
+
+/// Linear Adobe RGB (1998).
+#[doc(alias = "linear")]
+pub type LinAdobeRgb<T = f32> = Rgb<Linear<encoding::AdobeRgb>, T>;
+/// Linear Adobe RGB (1998) with an alpha component.
+#[doc(alias = "linear")]
+pub type LinAdobeRgba<T = f32> = Rgba<Linear<encoding::AdobeRgb>, T>;
+
+/// Non-linear ProPhoto RGB.
+pub type ProPhotoRgb<T = f32> = Rgb<encoding::ProPhotoRgb, T>;
+/// Non-linear ProPhoto RGB with an alpha component.
+pub type ProPhotoRgba<T = f32> = Rgba<encoding::ProPhotoRgb, T>;
+
+/// Linear ProPhoto RGB.
+#[doc(alias = "linear")]
+pub type LinProPhotoRgb<T = f32> = Rgb<Linear<encoding::ProPhotoRgb>, T>;
+/// Linear ProPhoto RGB with an alpha component.
+#[doc(alias = "linear")]
+pub type LinProPhotoRgba<T = f32> = Rgba<Linear<encoding::ProPhotoRgb>, T>;
+
+/// Linear ACES2065-1.
+pub type Aces2065Rgb<T = f32> = Rgb<encoding::Aces2065, T>;
+/// Linear ACES2065-1 with an alpha component.
+pub type Aces2065Rgba<T = f32> = Rgba<encoding::Aces2065, T>;
+
+/// Linear ACEScg.
+pub type AcesCgRgb<T = f32> = Rgb<encoding::AcesCg, T>;
+/// Linear ACEScg with an alpha component.
+pub type AcesCgRgba<T = f32> = Rgba<encoding::AcesCg, T>;
+
+/// Logarithmic ACEScc.
+pub type AcesCcRgb<T = f32> = Rgb<encoding::AcesCc, T>;
+/// Logarithmic ACEScc with an alpha component.
+pub type AcesCcRgba<T = f32> = Rgba<encoding::AcesCc, T>;
+
+/// Logarithmic ACEScct.
+pub type AcesCctRgb<T = f32> = Rgb<encoding::AcesCct, T>;
+/// Logarithmic ACEScct with an alpha component.
+pub type AcesCctRgba<T = f32> = Rgba<encoding::AcesCct, T>;
+
+/// Non-linear ITU-R BT.709.
+pub type Rec709Rgb<T = f32> = Rgb<encoding::Rec709, T>;
+/// Non-linear ITU-R BT.709 with an alpha component.
+pub type Rec709Rgba<T = f32> = Rgba<encoding::Rec709, T>;
+
+/// Non-linear ITU-R BT.2020.
+pub type Rec2020Rgb<T = f32> = Rgb<encoding::Rec2020, T>;
+/// Non-linear ITU-R BT.2020 with an alpha component.
+pub type Rec2020Rgba<T = f32> = Rgba<encoding::Rec2020, T>;
+
+/// Linear ITU-R BT.2020.
+#[doc(alias = "linear")]
+pub type LinRec2020<T = f32> = Rgb<Linear<encoding::Rec2020>, T>;
+/// Linear ITU-R BT.2020 with an alpha component.
+#[doc(alias = "linear")]
+pub type LinRec2020a<T = f32> = Rgba<Linear<encoding::Rec2020>, T>;
+
 /// Gamma 2.2 encoded sRGB.
 pub type GammaSrgb<T = f32> = Rgb<Gamma<encoding::Srgb>, T>;
 /// Gamma 2.2 encoded sRGB with an alpha component.
@@ -0,0 +1,216 @@
+use core::marker::PhantomData;
+
+use crate::{
+    bool_mask::LazySelect,
+    chromatic_adaptation::{Method, TransformMatrix},
+    convert::{FromColorUnclamped, IntoColorUnclamped},
+    encoding::ParametricCurve,
+    matrix::{matrix_inverse, multiply_3x3, multiply_xyz, rgb_to_xyz_matrix_from_primaries, Mat3},
+    num::{Arithmetics, FromScalar, IsValidDivisor, One, PartialCmp, Powf, Real, Recip, Zero},
+    white_point::{Any, WhitePoint},
+    Xyz, Yxy,
+};
+
+/// An RGB space with primaries, white point and transfer function that are
+/// only known at runtime.
+///
+/// This is for spaces that are described by metadata, such as the `cHRM` and
+/// `gAMA` chunks in PNG files, EXR chromaticities or a monitor's EDID. The
+/// conversion matrices are computed once, when the space is created, and the
+/// space can then convert pixel values to and from any color type that can be
+/// converted from `Xyz<Wp, T>`.
+///
+/// The white point type `Wp` is the white point of the colors on the other
+/// side of the conversion. The space's own white point is adapted to it with
+/// the Bradford method, if they are different.
+///
+/// ```
+/// use palette::{
+///     encoding::ParametricCurve,
+///     rgb::DynamicRgbSpace,
+///     white_point::D65,
+///     Lab, Yxy,
+/// };
+///
+/// // The chromaticities from a PNG file's `cHRM` chunk.
+/// let space = DynamicRgbSpace::<D65, f32>::new(
+///     Yxy::new(0.64, 0.33, 1.0),
+///     Yxy::new(0.30, 0.60, 1.0),
+///     Yxy::new(0.15, 0.06, 1.0),
+///     Yxy::new(0.3127, 0.3290, 1.0),
+///     ParametricCurve::gamma(1.0 / 0.45455),
+/// );
+///
+/// let lab: Lab = space.decode([0.8, 0.3, 0.1]);
+/// let pixel = space.encode(lab);
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct DynamicRgbSpace<Wp, T> {
+    rgb_to_xyz: Mat3<T>,
+    xyz_to_rgb: Mat3<T>,
+    transfer_fn: ParametricCurve<T>,
+    white_point: PhantomData<Wp>,
+}
+
+impl<Wp, T> DynamicRgbSpace<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + Zero
+        + One
+        + Recip
+        + IsValidDivisor<Mask = bool>
+        + Arithmetics
+        + Clone
+        + FromScalar<Scalar = T>,
+    Yxy<Any, T>: IntoColorUnclamped<Xyz<Any, T>>,
+{
+    /// Create an RGB space from the chromaticities of its primaries and white
+    /// point, and its transfer function.
+    ///
+    /// Only the `x` and `y` components of the primaries and the white point
+    /// are used. The white point is normalized to a luminance of 1.0.
+    pub fn new(
+        red: Yxy<Any, T>,
+        green: Yxy<Any, T>,
+        blue: Yxy<Any, T>,
+        white_point: Yxy<Any, T>,
+        transfer_fn: ParametricCurve<T>,
+    ) -> Self {
+        let white_point = Yxy::<Any, T>::new(white_point.x, white_point.y, T::one());
+        let white_point: Xyz<Any, T> = white_point.into_color_unclamped();
+
+        let rgb_to_xyz = rgb_to_xyz_matrix_from_primaries(red, green, blue, white_point.clone());
+        let adaptation = Method::Bradford.generate_transform_matrix(white_point, Wp::get_xyz());
+        let rgb_to_xyz = multiply_3x3(adaptation, rgb_to_xyz);
+
+        DynamicRgbSpace {
+            xyz_to_rgb: matrix_inverse(rgb_to_xyz.clone()),
+            rgb_to_xyz,
+            transfer_fn,
+            white_point: PhantomData,
+        }
+    }
+}
+
+impl<Wp, T> DynamicRgbSpace<Wp, T> {
+    /// Get the matrix that converts linear RGB in this space to `Xyz<Wp, T>`.
+    pub fn rgb_to_xyz_matrix(&self) -> &Mat3<T> {
+        &self.rgb_to_xyz
+    }
+
+    /// Get the matrix that converts `Xyz<Wp, T>` to linear RGB in this space.
+    pub fn xyz_to_rgb_matrix(&self) -> &Mat3<T> {
+        &self.xyz_to_rgb
+    }
+
+    /// Get the transfer function of this space.
+    pub fn transfer_fn(&self) -> &ParametricCurve<T> {
+        &self.transfer_fn
+    }
+}
+
+impl<Wp, T> DynamicRgbSpace<Wp, T>
+where
+    T: Zero + One + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    /// Convert an encoded `[red, green, blue]` pixel in this space to a color.
+    ///
+    /// The color is converted without clamping.
+    pub fn decode<C>(&self, pixel: [T; 3]) -> C
+    where
+        C: FromColorUnclamped<Xyz<Wp, T>>,
+    {
+        let [red, green, blue] = pixel;
+        let linear = Xyz::new(
+            self.transfer_fn.into_linear(red),
+            self.transfer_fn.into_linear(green),
+            self.transfer_fn.into_linear(blue),
+        );
+
+        let xyz = multiply_xyz(self.rgb_to_xyz.clone(), linear);
+        C::from_color_unclamped(xyz.with_white_point())
+    }
+
+    /// Convert a color to an encoded `[red, green, blue]` pixel in this space.
+    ///
+    /// The pixel values are not clamped, so colors outside the gamut of the
+    /// space may give values outside the range from 0.0 to 1.0.
+    pub fn encode<C>(&self, color: C) -> [T; 3]
+    where
+        C: IntoColorUnclamped<Xyz<Wp, T>>,
+    {
+        let xyz: Xyz<Wp, T> = color.into_color_unclamped();
+        let linear = multiply_xyz(self.xyz_to_rgb.clone(), xyz.with_white_point());
+
+        [
+            self.transfer_fn.from_linear(linear.x),
+            self.transfer_fn.from_linear(linear.y),
+            self.transfer_fn.from_linear(linear.z),
+        ]
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::DynamicRgbSpace;
+    use crate::{
+        chromatic_adaptation::AdaptInto,
+        convert::FromColorUnclamped,
+        encoding::ParametricCurve,
+        matrix::rgb_to_xyz_matrix,
+        white_point::{WhitePoint, D50, D65},
+        Lab, LinSrgb, Srgb, Xyz, Yxy,
+    };
+
+    fn srgb_space<Wp>() -> DynamicRgbSpace<Wp, f64>
+    where
+        Wp: WhitePoint<f64>,
+    {
+        DynamicRgbSpace::new(
+            Yxy::new(0.6400, 0.3300, 1.0),
+            Yxy::new(0.3000, 0.6000, 1.0),
+            Yxy::new(0.1500, 0.0600, 1.0),
+            Yxy::new(0.312727, 0.329023, 1.0),
+            ParametricCurve::srgb(),
+        )
+    }
+
+    #[test]
+    fn same_as_srgb() {
+        let space = srgb_space::<D65>();
+        assert_relative_eq!(
+            space.rgb_to_xyz_matrix()[..],
+            rgb_to_xyz_matrix::<crate::encoding::Srgb, f64>()[..],
+            epsilon = 0.00001
+        );
+
+        let xyz: Xyz<D65, f64> = space.decode([0.8, 0.3, 0.1]);
+        let expected = Xyz::<D65, f64>::from_color_unclamped(Srgb::new(0.8, 0.3, 0.1));
+        assert_relative_eq!(xyz, expected, epsilon = 0.00001);
+
+        let pixel = space.encode(LinSrgb::new(0.2f64, 0.5, 0.9));
+        let expected = Srgb::<f64>::from_linear(LinSrgb::new(0.2, 0.5, 0.9));
+        assert_relative_eq!(
+            pixel[..],
+            [expected.red, expected.green, expected.blue][..],
+            epsilon = 0.00001
+        );
+    }
+
+    #[test]
+    fn adapted_white_point() {
+        let space = srgb_space::<D50>();
+
+        let lab: Lab<D50, f64> = space.decode([0.8, 0.3, 0.1]);
+        let expected: Lab<D50, f64> = Srgb::new(0.8f64, 0.3, 0.1).into_linear().adapt_into();
+        assert_relative_eq!(lab, expected, epsilon = 0.01);
+
+        let white: Lab<D50, f64> = space.decode([1.0, 1.0, 1.0]);
+        assert_relative_eq!(white, Lab::new(100.0, 0.0, 0.0), epsilon = 0.001);
+
+        let pixel = space.encode(lab);
+        assert_relative_eq!(pixel[..], [0.8, 0.3, 0.1][..], epsilon = 0.000001);
+    }
+}
@@ -28,20 +28,25 @@ use crate::{
     blend::{PreAlpha, Premultiply},
     bool_mask::{BitOps, HasBoolMask, LazySelect},
     cast::{ComponentOrder, Packed},
+    chromatic_adaptation::ConvertFromWhitePoint,
     clamp, clamp_assign, contrast_ratio,
     convert::{FromColorUnclamped, IntoColorUnclamped},
-    encoding::{linear::LinearFn, FromLinear, IntoLinear, Linear, Srgb},
+    encoding::{
+        linear::LinearFn, FromLinear, IntoLinear, Linear, Parametric, ParametricCurve, Srgb,
+    },
+    lightness_contrast,
     luma::LumaStandard,
     matrix::{matrix_inverse, multiply_xyz_to_rgb, rgb_to_xyz_matrix},
     num::{
         self, Abs, Arithmetics, FromScalar, FromScalarArray, IntoScalarArray, IsValidDivisor,
-        MinMax, One, PartialCmp, Real, Recip, Round, Trigonometry, Zero,
+        MinMax, One, PartialCmp, Powf, Real, Recip, Round, Trigonometry, Zero,
     },
+    relative_contrast::apca_screen_luminance,
     rgb::{RgbSpace, RgbStandard},
     stimulus::{FromStimulus, Stimulus, StimulusColor},
     white_point::{Any, WhitePoint},
-    Clamp, ClampAssign, FromColor, GetHue, Hsl, Hsv, IsWithinBounds, Lighten, LightenAssign, Luma,
-    Mix, MixAssign, Oklab, RelativeContrast, RgbHue, Xyz, Yxy,
+    Clamp, ClampAssign, FromColor, GetHue, Hsl, Hsv, IsWithinBounds, Lab, Lighten, LightenAssign,
+    LightnessContrast, Luma, Mix, MixAssign, Oklab, RelativeContrast, RgbHue, Xyz, Yxy,
 };
 
 use super::Primaries;
@@ -67,7 +72,7 @@ pub type Rgba<S = Srgb, T = f32> = Alpha<Rgb<S, T>, T>;
     palette_internal,
     rgb_standard = "S",
     component = "T",
-    skip_derives(Xyz, Hsv, Hsl, Luma, Rgb, Oklab)
+    skip_derives(Xyz, Lab, Hsv, Hsl, Luma, Rgb, Oklab)
 )]
 #[repr(C)]
 pub struct Rgb<S = Srgb, T = f32> {
@@ -290,6 +295,55 @@ impl<S: RgbStandard, T> Rgb<S, T> {
     }
 }
 
+impl<S, T> Rgb<S, T>
+where
+    S: RgbStandard<TransferFn = Parametric>,
+    T: Zero + One + Powf + PartialCmp + Arithmetics + Clone,
+    T::Mask: LazySelect<T>,
+{
+    /// Convert the color to linear RGB, using a transfer function with runtime
+    /// parameters.
+    ///
+    /// ```
+    /// use palette::{
+    ///     encoding::{Parametric, ParametricCurve, Srgb},
+    ///     rgb::Rgb,
+    ///     LinSrgb,
+    /// };
+    ///
+    /// let curve = ParametricCurve::gamma(1.8);
+    /// let linear: LinSrgb = Rgb::<(Srgb, Parametric)>::new(0.8, 0.3, 0.1).into_linear_with(&curve);
+    /// ```
+    pub fn into_linear_with(self, curve: &ParametricCurve<T>) -> Rgb<Linear<S::Space>, T> {
+        Rgb::new(
+            curve.into_linear(self.red),
+            curve.into_linear(self.green),
+            curve.into_linear(self.blue),
+        )
+    }
+
+    /// Convert linear RGB to non-linear RGB, using a transfer function with
+    /// runtime parameters.
+    ///
+    /// ```
+    /// use palette::{
+    ///     encoding::{Parametric, ParametricCurve, Srgb},
+    ///     rgb::Rgb,
+    ///     LinSrgb,
+    /// };
+    ///
+    /// let curve = ParametricCurve::gamma(1.8);
+    /// let encoded = Rgb::<(Srgb, Parametric)>::from_linear_with(LinSrgb::new(0.95, 0.90, 0.30), &curve);
+    /// ```
+    pub fn from_linear_with(color: Rgb<Linear<S::Space>, T>, curve: &ParametricCurve<T>) -> Self {
+        Rgb::new(
+            curve.from_linear(color.red),
+            curve.from_linear(color.green),
+            curve.from_linear(color.blue),
+        )
+    }
+}
+
 impl<S: RgbSpace, T> Rgb<Linear<S>, T> {
     /// Convert a linear color to a different encoding.
     ///
@@ -343,7 +397,6 @@ where
     #[inline]
     pub(crate) fn reinterpret_as<St>(self) -> Rgb<St, T>
     where
-        S::Space: RgbSpace<WhitePoint = <St::Space as RgbSpace>::WhitePoint>,
         St: RgbStandard,
     {
         Rgb {
@@ -566,41 +619,61 @@ where
     S2: RgbStandard + 'static,
     S1::TransferFn: FromLinear<T, T>,
     S2::TransferFn: IntoLinear<T, T>,
-    S2::Space: RgbSpace<WhitePoint = <S1::Space as RgbSpace>::WhitePoint>,
+    <S1::Space as RgbSpace>::WhitePoint:
+        ConvertFromWhitePoint<<S2::Space as RgbSpace>::WhitePoint, T>,
     Xyz<<S2::Space as RgbSpace>::WhitePoint, T>: FromColorUnclamped<Rgb<S2, T>>,
     Rgb<S1, T>: FromColorUnclamped<Xyz<<S1::Space as RgbSpace>::WhitePoint, T>>,
 {
     fn from_color_unclamped(rgb: Rgb<S2, T>) -> Self {
         let rgb_space1 = TypeId::of::<<S1::Space as RgbSpace>::Primaries>();
         let rgb_space2 = TypeId::of::<<S2::Space as RgbSpace>::Primaries>();
+        let white_point1 = TypeId::of::<<S1::Space as RgbSpace>::WhitePoint>();
+        let white_point2 = TypeId::of::<<S2::Space as RgbSpace>::WhitePoint>();
 
         if TypeId::of::<S1>() == TypeId::of::<S2>() {
             rgb.reinterpret_as()
-        } else if rgb_space1 == rgb_space2 {
+        } else if rgb_space1 == rgb_space2 && white_point1 == white_point2 {
             Self::from_linear(rgb.into_linear().reinterpret_as())
         } else {
-            Self::from_color_unclamped(Xyz::from_color_unclamped(rgb))
+            // Adapts the color if the white points are different.
+            let xyz = Xyz::<<S2::Space as RgbSpace>::WhitePoint, T>::from_color_unclamped(rgb);
+            Self::from_color_unclamped(<S1::Space as RgbSpace>::WhitePoint::convert_xyz(xyz))
         }
     }
 }
 
-impl<S, T> FromColorUnclamped<Xyz<<S::Space as RgbSpace>::WhitePoint, T>> for Rgb<S, T>
+impl<S, T, Wp> FromColorUnclamped<Xyz<Wp, T>> for Rgb<S, T>
 where
     S: RgbStandard,
     S::TransferFn: FromLinear<T, T>,
     <S::Space as RgbSpace>::Primaries: Primaries<T::Scalar>,
-    <S::Space as RgbSpace>::WhitePoint: WhitePoint<T::Scalar>,
+    <S::Space as RgbSpace>::WhitePoint: ConvertFromWhitePoint<Wp, T> + WhitePoint<T::Scalar>,
     T: Arithmetics + FromScalar,
     T::Scalar:
         Recip + IsValidDivisor<Mask = bool> + Arithmetics + Clone + FromScalar<Scalar = T::Scalar>,
     Yxy<Any, T::Scalar>: IntoColorUnclamped<Xyz<Any, T::Scalar>>,
 {
-    fn from_color_unclamped(color: Xyz<<S::Space as RgbSpace>::WhitePoint, T>) -> Self {
+    fn from_color_unclamped(color: Xyz<Wp, T>) -> Self {
+        // Adapts the color if the white points are different.
+        let color = <S::Space as RgbSpace>::WhitePoint::convert_xyz(color);
+
         let transform_matrix = matrix_inverse(rgb_to_xyz_matrix::<S::Space, T::Scalar>());
         Self::from_linear(multiply_xyz_to_rgb(transform_matrix, color))
     }
 }
 
+impl<S, T, Wp> FromColorUnclamped<Lab<Wp, T>> for Rgb<S, T>
+where
+    Xyz<Wp, T>: FromColorUnclamped<Lab<Wp, T>>,
+    Self: FromColorUnclamped<Xyz<Wp, T>>,
+{
+    fn from_color_unclamped(color: Lab<Wp, T>) -> Self {
+        // The white points may be different, in which case the color is
+        // adapted while converting from `Xyz`.
+        Self::from_color_unclamped(Xyz::<Wp, T>::from_color_unclamped(color))
+    }
+}
+
 impl<S, T> FromColorUnclamped<Hsl<S, T>> for Rgb<S, T>
 where
     T: Real
@@ -1309,6 +1382,26 @@ where
     }
 }
 
+impl<S, T> LightnessContrast for Rgb<S, T>
+where
+    T: Real + Zero + Powf + Abs + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+    Rgb<Srgb, T>: FromColor<Self>,
+{
+    type Scalar = T;
+
+    #[inline]
+    fn get_lightness_contrast(self, background: Self) -> T {
+        let text = Rgb::<Srgb, T>::from_color(self);
+        let background = Rgb::<Srgb, T>::from_color(background);
+
+        lightness_contrast(
+            apca_screen_luminance(text.red, text.green, text.blue),
+            apca_screen_luminance(background.red, background.green, background.blue),
+        )
+    }
+}
+
 #[cfg(feature = "random")]
 impl<S, T> Distribution<Rgb<S, T>> for Standard
 where
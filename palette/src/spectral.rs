@@ -0,0 +1,660 @@
+//! Spectral power distributions and their conversion to tristimulus values.
+//!
+//! A [`Spectrum`] is a series of evenly spaced samples over a range of
+//! wavelengths, such as the spectral power distribution of a light source or
+//! the spectral reflectance of a surface. It can be integrated into [`Xyz`]
+//! with the color matching functions of an [`Observer`]:
+//!
+//! ```
+//! use palette::{
+//!     spectral::{Cie1931, Spectrum},
+//!     white_point::D65,
+//!     Xyz,
+//! };
+//!
+//! // A flat, 50% gray reflectance, from 400 nm to 700 nm.
+//! let gray = Spectrum::new(400.0, 10.0, [0.5f64; 31]);
+//!
+//! // The color of the surface under D65 light, as seen by the 2° observer.
+//! let xyz: Xyz<D65, f64> = gray.reflective_to_xyz(Cie1931, D65);
+//! assert!((xyz.y - 0.5).abs() < 1e-10);
+//! ```
+//!
+//! The color matching functions of the CIE 1931 2° and CIE 1964 10° standard
+//! observers, and the spectral power distributions of the standard
+//! illuminants A, D50, D55, D65, D75 and E, are embedded from 380 nm to 780 nm,
+//! in 10 nm steps. The [`Illuminant`] trait connects an illuminant to the
+//! [`white_point`](crate::white_point) type that matches each observer, so
+//! integrating a spectrum under `D65` with [`Cie1964`] results in an
+//! `Xyz<D65Degree10, T>`.
+//!
+//! Going the other way, from a color to a spectrum, has infinitely many
+//! solutions. [`SigmoidPolynomial`] picks a smooth reflectance spectrum for an
+//! RGB color, which is useful for spectral rendering.
+
+use crate::{
+    bool_mask::HasBoolMask,
+    cct,
+    num::{Arithmetics, Exp, One, PartialCmp, Real, Zero},
+    white_point::{D50Degree10, D55Degree10, D65Degree10, D75Degree10, A, D50, D55, D65, D75, E},
+    Xyz, Yxy,
+};
+
+pub use self::sigmoid::SigmoidPolynomial;
+
+pub(crate) use self::sigmoid::REFLECTANCE_EPSILON;
+
+mod data;
+mod sigmoid;
+
+/// A spectrum, sampled at evenly spaced wavelengths.
+///
+/// The samples are stored in `C`, which can be anything that can be borrowed
+/// as a slice, such as an array or a `Vec`. The first sample is at the
+/// wavelength `start`, and each following sample is `step` nanometers after
+/// the previous one.
+///
+/// The spectrum is linearly interpolated between the samples, and has the
+/// value of the closest sample outside its range, as recommended by CIE 15.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Spectrum<C> {
+    start: f64,
+    step: f64,
+    values: C,
+}
+
+impl<C> Spectrum<C> {
+    /// Create a spectrum from its samples, where the first sample is at the
+    /// wavelength `start` and the following samples are `step` nanometers
+    /// apart.
+    ///
+    /// # Panics
+    ///
+    /// Panics if there are no samples, or if `step` isn't positive.
+    pub fn new<T>(start: f64, step: f64, values: C) -> Self
+    where
+        C: AsRef<[T]>,
+    {
+        assert!(
+            !values.as_ref().is_empty(),
+            "a Spectrum must contain at least one sample"
+        );
+        assert!(step > 0.0, "the step of a Spectrum must be positive");
+
+        Spectrum {
+            start,
+            step,
+            values,
+        }
+    }
+
+    /// The wavelength of the first sample, in nanometers.
+    pub fn start(&self) -> f64 {
+        self.start
+    }
+
+    /// The distance between the samples, in nanometers.
+    pub fn step(&self) -> f64 {
+        self.step
+    }
+
+    /// The wavelength of the last sample, in nanometers.
+    pub fn end<T>(&self) -> f64
+    where
+        C: AsRef<[T]>,
+    {
+        self.wavelength(self.len() - 1)
+    }
+
+    /// The number of samples.
+    #[allow(clippy::len_without_is_empty)] // A spectrum is never empty.
+    pub fn len<T>(&self) -> usize
+    where
+        C: AsRef<[T]>,
+    {
+        self.values.as_ref().len()
+    }
+
+    /// Borrow the samples.
+    pub fn values(&self) -> &C {
+        &self.values
+    }
+
+    /// Return the samples.
+    pub fn into_values(self) -> C {
+        self.values
+    }
+
+    /// The wavelengths of the samples, in nanometers.
+    pub fn wavelengths<T>(&self) -> impl Iterator<Item = f64> + '_
+    where
+        C: AsRef<[T]>,
+    {
+        (0..self.len()).map(move |index| self.wavelength(index))
+    }
+
+    /// Get the value at `wavelength`, in nanometers.
+    ///
+    /// The value is linearly interpolated between the two closest samples, or
+    /// is the value of the closest sample if `wavelength` is outside the
+    /// range of the spectrum.
+    ///
+    /// ```
+    /// use palette::spectral::Spectrum;
+    ///
+    /// let spectrum = Spectrum::new(400.0, 100.0, [0.0f64, 1.0, 0.5]);
+    ///
+    /// assert_eq!(spectrum.get(450.0), 0.5);
+    /// assert_eq!(spectrum.get(550.0), 0.75);
+    /// assert_eq!(spectrum.get(300.0), 0.0);
+    /// assert_eq!(spectrum.get(800.0), 0.5);
+    /// ```
+    #[must_use]
+    pub fn get<T>(&self, wavelength: f64) -> T
+    where
+        C: AsRef<[T]>,
+        T: Real + Arithmetics + Clone,
+    {
+        let values = self.values.as_ref();
+        let position = (wavelength - self.start) / self.step;
+
+        if position <= 0.0 {
+            return values[0].clone();
+        }
+
+        let index = position as usize;
+        if index >= values.len() - 1 {
+            return values[values.len() - 1].clone();
+        }
+
+        let factor = T::from_f64(position - index as f64);
+        let from = values[index].clone();
+        let to = values[index + 1].clone();
+
+        from.clone() + factor * (to - from)
+    }
+
+    /// Resample the spectrum to `len` samples, starting at the wavelength
+    /// `start` and spaced `step` nanometers apart.
+    ///
+    /// This is only available if the `std` feature is enabled (this is the
+    /// default).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `len` is 0, or if `step` isn't positive.
+    #[cfg(feature = "std")]
+    #[must_use]
+    pub fn resample<T>(&self, start: f64, step: f64, len: usize) -> Spectrum<Vec<T>>
+    where
+        C: AsRef<[T]>,
+        T: Real + Arithmetics + Clone,
+    {
+        let values = (0..len)
+            .map(|index| self.get(start + index as f64 * step))
+            .collect();
+
+        Spectrum::new(start, step, values)
+    }
+
+    /// Convert a light source's spectral power distribution to `Xyz`, as
+    /// seen by `observer`.
+    ///
+    /// The result is relative to an equal energy spectrum, so a spectrum
+    /// where all samples are 1.0 gets a luminance of 1.0. An emissive spectrum
+    /// has no reference white, so the white point type is chosen by the
+    /// caller.
+    ///
+    /// ```
+    /// use palette::{
+    ///     convert::FromColorUnclamped,
+    ///     spectral::{self, Cie1931},
+    ///     white_point::Any,
+    ///     Xyz, Yxy,
+    /// };
+    ///
+    /// let xyz: Xyz<Any, f64> = spectral::illuminant_a().emissive_to_xyz(Cie1931);
+    /// let chromaticity = Yxy::from_color_unclamped(xyz);
+    /// assert!((chromaticity.x - 0.4476).abs() < 0.001);
+    /// ```
+    #[must_use]
+    pub fn emissive_to_xyz<O, Wp, T>(&self, observer: O) -> Xyz<Wp, T>
+    where
+        O: Observer,
+        C: AsRef<[T]>,
+        T: Real + Zero + Arithmetics + Clone,
+    {
+        let _ = observer;
+        let [x_bar, y_bar, z_bar] = O::color_matching_functions();
+        let norm = y_bar.values.iter().sum::<f64>() * y_bar.step;
+
+        let mut xyz = self.integrate(&x_bar, &y_bar, &z_bar, |_| T::from_f64(self.step));
+        xyz.x = xyz.x / T::from_f64(norm);
+        xyz.y = xyz.y / T::from_f64(norm);
+        xyz.z = xyz.z / T::from_f64(norm);
+
+        xyz
+    }
+
+    /// Convert a surface's spectral reflectance to `Xyz`, as seen by
+    /// `observer` under `illuminant`.
+    ///
+    /// The result has the white point of the illuminant, for the observer. A
+    /// perfect white reflector, where all samples are 1.0, gets the same
+    /// values as the white point.
+    #[must_use]
+    pub fn reflective_to_xyz<O, I, T>(&self, observer: O, illuminant: I) -> Xyz<I::WhitePoint, T>
+    where
+        O: Observer,
+        I: Illuminant<O>,
+        C: AsRef<[T]>,
+        T: Real + Zero + Arithmetics + Clone,
+    {
+        let _ = illuminant;
+        self.reflective_to_xyz_with(observer, &I::spectrum::<f64>())
+    }
+
+    /// Convert a surface's spectral reflectance to `Xyz`, as seen by
+    /// `observer` under a light source with the spectral power distribution
+    /// `illuminant`.
+    ///
+    /// The result is relative to the light source, so a perfect white
+    /// reflector gets a luminance of 1.0. The light source doesn't have a
+    /// white point type, so it's chosen by the caller.
+    #[must_use]
+    pub fn reflective_to_xyz_with<O, Wp, T, I>(
+        &self,
+        observer: O,
+        illuminant: &Spectrum<I>,
+    ) -> Xyz<Wp, T>
+    where
+        O: Observer,
+        C: AsRef<[T]>,
+        I: AsRef<[f64]>,
+        T: Real + Zero + Arithmetics + Clone,
+    {
+        let _ = observer;
+        let [x_bar, y_bar, z_bar] = O::color_matching_functions();
+
+        let weight = |wavelength| T::from_f64(illuminant.get(wavelength));
+        let mut xyz = self.integrate(&x_bar, &y_bar, &z_bar, weight);
+
+        let norm = self
+            .wavelengths()
+            .map(|wavelength| illuminant.get::<f64>(wavelength) * sample_cmf(&y_bar, wavelength))
+            .sum::<f64>();
+
+        xyz.x = xyz.x / T::from_f64(norm);
+        xyz.y = xyz.y / T::from_f64(norm);
+        xyz.z = xyz.z / T::from_f64(norm);
+
+        xyz
+    }
+
+    /// Sum the samples, weighted by the color matching functions and `weight`,
+    /// over the wavelengths of the spectrum.
+    fn integrate<Wp, T>(
+        &self,
+        x_bar: &Spectrum<&'static [f64]>,
+        y_bar: &Spectrum<&'static [f64]>,
+        z_bar: &Spectrum<&'static [f64]>,
+        weight: impl Fn(f64) -> T,
+    ) -> Xyz<Wp, T>
+    where
+        C: AsRef<[T]>,
+        T: Real + Zero + Arithmetics + Clone,
+    {
+        let mut xyz = Xyz::new(T::zero(), T::zero(), T::zero());
+
+        for (index, value) in self.values.as_ref().iter().enumerate() {
+            let wavelength = self.wavelength(index);
+            let value = value.clone() * weight(wavelength);
+
+            xyz.x = xyz.x + value.clone() * T::from_f64(sample_cmf(x_bar, wavelength));
+            xyz.y = xyz.y + value.clone() * T::from_f64(sample_cmf(y_bar, wavelength));
+            xyz.z = xyz.z + value * T::from_f64(sample_cmf(z_bar, wavelength));
+        }
+
+        xyz
+    }
+
+    fn wavelength(&self, index: usize) -> f64 {
+        self.start + index as f64 * self.step
+    }
+}
+
+/// Sample a color matching function, which is 0.0 outside its range.
+fn sample_cmf(cmf: &Spectrum<&'static [f64]>, wavelength: f64) -> f64 {
+    if wavelength < cmf.start() || wavelength > cmf.end() {
+        0.0
+    } else {
+        cmf.get(wavelength)
+    }
+}
+
+/// A standard observer, defined by its color matching functions.
+pub trait Observer {
+    /// Get the `[x̄, ȳ, z̄]` color matching functions of the observer.
+    #[must_use]
+    fn color_matching_functions() -> [Spectrum<&'static [f64]>; 3];
+}
+
+/// The CIE 1931 2° standard observer.
+///
+/// This is the observer that is assumed for most color spaces, including the
+/// white points without a `Degree10` suffix.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cie1931;
+
+impl Observer for Cie1931 {
+    fn color_matching_functions() -> [Spectrum<&'static [f64]>; 3] {
+        [
+            Spectrum::new(380.0, 10.0, &data::CIE_1931_X[..]),
+            Spectrum::new(380.0, 10.0, &data::CIE_1931_Y[..]),
+            Spectrum::new(380.0, 10.0, &data::CIE_1931_Z[..]),
+        ]
+    }
+}
+
+/// The CIE 1964 10° standard observer.
+///
+/// It represents a larger field of view than the 2° observer, and is what the
+/// white points with a `Degree10` suffix are based on.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cie1964;
+
+impl Observer for Cie1964 {
+    fn color_matching_functions() -> [Spectrum<&'static [f64]>; 3] {
+        [
+            Spectrum::new(380.0, 10.0, &data::CIE_1964_X[..]),
+            Spectrum::new(380.0, 10.0, &data::CIE_1964_Y[..]),
+            Spectrum::new(380.0, 10.0, &data::CIE_1964_Z[..]),
+        ]
+    }
+}
+
+/// A standard illuminant with a known spectral power distribution.
+///
+/// It's implemented for the white point types of the illuminants, and maps
+/// them to the white point type for the observer `O`.
+pub trait Illuminant<O: Observer> {
+    /// The white point of the illuminant, for the observer `O`.
+    type WhitePoint;
+
+    /// Get the relative spectral power distribution of the illuminant, from
+    /// 380 nm to 780 nm in 10 nm steps.
+    #[must_use]
+    fn spectrum<T: Real>() -> Spectrum<[T; 41]>;
+}
+
+macro_rules! impl_illuminant {
+    ($($illuminant: ident => $spectrum: ident, $degree2: ident, $degree10: ident;)+) => {
+        $(
+            impl Illuminant<Cie1931> for $illuminant {
+                type WhitePoint = $degree2;
+
+                #[inline]
+                fn spectrum<T: Real>() -> Spectrum<[T; 41]> {
+                    $spectrum()
+                }
+            }
+
+            impl Illuminant<Cie1964> for $illuminant {
+                type WhitePoint = $degree10;
+
+                #[inline]
+                fn spectrum<T: Real>() -> Spectrum<[T; 41]> {
+                    $spectrum()
+                }
+            }
+        )+
+    };
+}
+
+impl_illuminant! {
+    D50 => illuminant_d50, D50, D50Degree10;
+    D55 => illuminant_d55, D55, D55Degree10;
+    D65 => illuminant_d65, D65, D65Degree10;
+    D75 => illuminant_d75, D75, D75Degree10;
+    E => illuminant_e, E, E;
+}
+
+impl Illuminant<Cie1931> for A {
+    type WhitePoint = A;
+
+    #[inline]
+    fn spectrum<T: Real>() -> Spectrum<[T; 41]> {
+        illuminant_a()
+    }
+}
+
+/// Get the relative spectral power distribution of CIE standard illuminant A,
+/// from 380 nm to 780 nm in 10 nm steps.
+///
+/// It's the spectrum of a Planckian radiator at about 2856 K, normalized to
+/// 100.0 at 560 nm.
+#[must_use]
+pub fn illuminant_a<T: Real>() -> Spectrum<[T; 41]> {
+    const C2: f64 = 1.435e7;
+    const TEMPERATURE: f64 = 2848.0;
+
+    let reference = Exp::exp(C2 / (TEMPERATURE * 560.0)) - 1.0;
+    let values = core::array::from_fn(|index| {
+        let wavelength = 380.0 + index as f64 * 10.0;
+        let ratio = 560.0 / wavelength;
+        let power = ratio * ratio * ratio * ratio * ratio;
+
+        T::from_f64(100.0 * power * reference / (Exp::exp(C2 / (TEMPERATURE * wavelength)) - 1.0))
+    });
+
+    Spectrum::new(380.0, 10.0, values)
+}
+
+/// Get the relative spectral power distribution of CIE standard illuminant
+/// D50, from 380 nm to 780 nm in 10 nm steps.
+///
+/// It's calculated from the CIE daylight components, like [`daylight`].
+#[must_use]
+pub fn illuminant_d50<T: Real>() -> Spectrum<[T; 41]> {
+    standard_daylight(5000.0)
+}
+
+/// Get the relative spectral power distribution of CIE standard illuminant
+/// D55, from 380 nm to 780 nm in 10 nm steps.
+///
+/// It's calculated from the CIE daylight components, like [`daylight`].
+#[must_use]
+pub fn illuminant_d55<T: Real>() -> Spectrum<[T; 41]> {
+    standard_daylight(5500.0)
+}
+
+/// Get the relative spectral power distribution of CIE standard illuminant
+/// D65, from 380 nm to 780 nm in 10 nm steps.
+#[must_use]
+pub fn illuminant_d65<T: Real>() -> Spectrum<[T; 41]> {
+    Spectrum::new(380.0, 10.0, data::D65.map(T::from_f64))
+}
+
+/// Get the relative spectral power distribution of CIE standard illuminant
+/// D75, from 380 nm to 780 nm in 10 nm steps.
+///
+/// It's calculated from the CIE daylight components, like [`daylight`].
+#[must_use]
+pub fn illuminant_d75<T: Real>() -> Spectrum<[T; 41]> {
+    standard_daylight(7500.0)
+}
+
+/// Get the relative spectral power distribution of CIE standard illuminant E,
+/// the equal energy spectrum, from 380 nm to 780 nm in 10 nm steps.
+#[must_use]
+pub fn illuminant_e<T: Real>() -> Spectrum<[T; 41]> {
+    Spectrum::new(380.0, 10.0, core::array::from_fn(|_| T::from_f64(100.0)))
+}
+
+/// Get the relative spectral power distribution of CIE daylight at the
+/// correlated color temperature `cct`, from 380 nm to 780 nm in 10 nm steps.
+///
+/// The CIE daylight model is defined from 4000 K to 25000 K, and the spectrum
+/// is normalized to 100.0 at 560 nm.
+///
+/// ```
+/// use palette::spectral;
+///
+/// let overcast_sky = spectral::daylight(7000.0f64);
+/// ```
+#[must_use]
+pub fn daylight<T>(cct: T) -> Spectrum<[T; 41]>
+where
+    T: Real + One + PartialCmp + HasBoolMask<Mask = bool> + Arithmetics + Clone,
+{
+    let Yxy { x, y, .. } = cct::daylight_locus(cct);
+
+    let m = T::from_f64(0.0241) + T::from_f64(0.2562) * &x - T::from_f64(0.7341) * &y;
+    let m1 = (T::from_f64(-1.3515) - T::from_f64(1.7703) * &x + T::from_f64(5.9114) * &y) / &m;
+    let m2 = (T::from_f64(0.0300) - T::from_f64(31.4424) * x + T::from_f64(30.0717) * y) / m;
+
+    let values = core::array::from_fn(|index| {
+        T::from_f64(data::DAYLIGHT_S0[index])
+            + m1.clone() * T::from_f64(data::DAYLIGHT_S1[index])
+            + m2.clone() * T::from_f64(data::DAYLIGHT_S2[index])
+    });
+
+    Spectrum::new(380.0, 10.0, values)
+}
+
+/// The D series illuminants are defined with an older value of the second
+/// radiation constant, `c2`, which shifts their nominal temperatures.
+fn standard_daylight<T: Real>(nominal_cct: f64) -> Spectrum<[T; 41]> {
+    let spectrum = daylight(nominal_cct * 1.4388 / 1.4380);
+    Spectrum::new(380.0, 10.0, spectrum.values.map(T::from_f64))
+}
+
+#[cfg(test)]
+mod test {
+    use super::{daylight, illuminant_d65, Cie1931, Cie1964, Illuminant, Spectrum};
+    use crate::{
+        white_point::{
+            Any, D50Degree10, D65Degree10, D75Degree10, WhitePoint, A, D50, D55, D65, D75, E,
+        },
+        Xyz,
+    };
+
+    #[test]
+    fn interpolation() {
+        let spectrum = Spectrum::new(400.0, 10.0, [1.0f64, 3.0, 2.0]);
+
+        assert_relative_eq!(spectrum.end(), 420.0);
+        assert_relative_eq!(spectrum.get(400.0), 1.0);
+        assert_relative_eq!(spectrum.get(405.0), 2.0);
+        assert_relative_eq!(spectrum.get(417.5), 2.25);
+        assert_relative_eq!(spectrum.get(420.0), 2.0);
+        assert_relative_eq!(spectrum.get(380.0), 1.0);
+        assert_relative_eq!(spectrum.get(500.0), 2.0);
+
+        let wavelengths: Vec<_> = spectrum.wavelengths().collect();
+        assert_eq!(wavelengths, [400.0, 410.0, 420.0]);
+    }
+
+    #[test]
+    fn resample() {
+        let spectrum = Spectrum::new(400.0, 10.0, [1.0f64, 3.0, 2.0]);
+        let resampled = spectrum.resample(395.0, 5.0, 7);
+
+        assert_eq!(resampled.start(), 395.0);
+        assert_eq!(resampled.step(), 5.0);
+        assert_eq!(resampled.values(), &[1.0, 1.0, 2.0, 3.0, 2.5, 2.0, 2.0]);
+    }
+
+    #[test]
+    fn equal_energy() {
+        let flat = Spectrum::new(380.0, 10.0, [1.0f64; 41]);
+
+        let xyz: Xyz<E, f64> = flat.emissive_to_xyz(Cie1931);
+        assert_relative_eq!(xyz, Xyz::new(1.0, 1.0, 1.0), epsilon = 0.001);
+
+        let xyz: Xyz<E, f64> = flat.emissive_to_xyz(Cie1964);
+        assert_relative_eq!(xyz, Xyz::new(1.0, 1.0, 1.0), epsilon = 0.00001);
+    }
+
+    #[test]
+    fn white_points_2_degrees() {
+        let white = Spectrum::new(380.0, 10.0, [1.0f64; 41]);
+
+        // The embedded data is sampled at 10 nm, while the white points are
+        // calculated from finer data.
+        assert_relative_eq!(
+            white.reflective_to_xyz(Cie1931, A),
+            A::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            white.reflective_to_xyz(Cie1931, D50),
+            D50::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            white.reflective_to_xyz(Cie1931, D55),
+            D55::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            white.reflective_to_xyz(Cie1931, D65),
+            D65::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+        assert_relative_eq!(
+            white.reflective_to_xyz(Cie1931, E),
+            E::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn white_points_10_degrees() {
+        let white = Spectrum::new(380.0, 10.0, [1.0f64; 41]);
+
+        let d50: Xyz<D50Degree10, f64> = white.reflective_to_xyz(Cie1964, D50);
+        assert_relative_eq!(
+            d50,
+            D50Degree10::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+
+        let d65: Xyz<D65Degree10, f64> = white.reflective_to_xyz(Cie1964, D65);
+        assert_relative_eq!(
+            d65,
+            D65Degree10::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+
+        let d75: Xyz<D75Degree10, f64> = white.reflective_to_xyz(Cie1964, D75);
+        assert_relative_eq!(
+            d75,
+            D75Degree10::get_xyz().with_white_point(),
+            epsilon = 0.001
+        );
+    }
+
+    #[test]
+    fn partial_reflectance() {
+        // Only the 400 nm to 700 nm range is measured, which is common for
+        // reflectance spectra.
+        let white = Spectrum::new(400.0, 20.0, [1.0f64; 16]);
+        let xyz = white.reflective_to_xyz(Cie1931, D65);
+        assert_relative_eq!(xyz.y, 1.0, epsilon = 0.0000001);
+        assert_relative_eq!(xyz, D65::get_xyz().with_white_point(), epsilon = 0.01);
+
+        let custom: Xyz<Any, f64> = white.reflective_to_xyz_with(Cie1931, &illuminant_d65::<f64>());
+        assert_relative_eq!(custom.with_white_point(), xyz);
+    }
+
+    #[test]
+    fn daylight_matches_d65() {
+        let computed = daylight(6504.0f64);
+        let table = <D65 as Illuminant<Cie1931>>::spectrum::<f64>();
+
+        for (computed, table) in computed.values().iter().zip(table.values()) {
+            assert_relative_eq!(computed, table, epsilon = 0.2);
+        }
+    }
+}
@@ -0,0 +1,94 @@
+//! Embedded spectral data, from 380 nm to 780 nm in 10 nm steps.
+
+/// The CIE 1931 2° color matching function x̄.
+#[rustfmt::skip]
+pub(super) const CIE_1931_X: [f64; 41] = [
+    0.001368, 0.004243, 0.014310, 0.043510, 0.134380, 0.283900, 0.348280, 0.336200, 0.290800,
+    0.195360, 0.095640, 0.032010, 0.004900, 0.009300, 0.063270, 0.165500, 0.290400, 0.433450,
+    0.594500, 0.762100, 0.916300, 1.026300, 1.062200, 1.002600, 0.854450, 0.642400, 0.447900,
+    0.283500, 0.164900, 0.087400, 0.046770, 0.022700, 0.011359, 0.005790, 0.002899, 0.001440,
+    0.000690, 0.000332, 0.000166, 0.000083, 0.000042,
+];
+
+/// The CIE 1931 2° color matching function ȳ.
+#[rustfmt::skip]
+pub(super) const CIE_1931_Y: [f64; 41] = [
+    0.000039, 0.000120, 0.000396, 0.001210, 0.004000, 0.011600, 0.023000, 0.038000, 0.060000,
+    0.090980, 0.139020, 0.208020, 0.323000, 0.503000, 0.710000, 0.862000, 0.954000, 0.994950,
+    0.995000, 0.952000, 0.870000, 0.757000, 0.631000, 0.503000, 0.381000, 0.265000, 0.175000,
+    0.107000, 0.061000, 0.032000, 0.017000, 0.008210, 0.004102, 0.002091, 0.001047, 0.000520,
+    0.000249, 0.000120, 0.000060, 0.000030, 0.000015,
+];
+
+/// The CIE 1931 2° color matching function z̄.
+#[rustfmt::skip]
+pub(super) const CIE_1931_Z: [f64; 41] = [
+    0.006450, 0.020050, 0.067850, 0.207400, 0.645600, 1.385600, 1.747060, 1.772110, 1.669200,
+    1.287640, 0.812950, 0.465180, 0.272000, 0.158200, 0.078250, 0.042160, 0.020300, 0.008750,
+    0.003900, 0.002100, 0.001650, 0.001100, 0.000800, 0.000340, 0.000190, 0.000050, 0.000020,
+    0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+    0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+];
+
+/// The CIE 1964 10° color matching function x̄.
+#[rustfmt::skip]
+pub(super) const CIE_1964_X: [f64; 41] = [
+    0.000160, 0.002362, 0.019110, 0.084736, 0.204492, 0.314679, 0.383734, 0.370702, 0.302273,
+    0.195618, 0.080507, 0.016172, 0.003816, 0.037465, 0.117749, 0.236491, 0.376772, 0.529826,
+    0.705224, 0.878655, 1.014160, 1.118520, 1.123990, 1.030480, 0.856297, 0.647467, 0.431567,
+    0.268329, 0.152568, 0.081261, 0.040851, 0.019941, 0.009577, 0.004553, 0.002175, 0.001045,
+    0.000508, 0.000251, 0.000126, 0.000065, 0.000033,
+];
+
+/// The CIE 1964 10° color matching function ȳ.
+#[rustfmt::skip]
+pub(super) const CIE_1964_Y: [f64; 41] = [
+    0.000017, 0.000253, 0.002004, 0.008756, 0.021391, 0.038676, 0.062077, 0.089456, 0.128201,
+    0.185190, 0.253589, 0.339133, 0.460777, 0.606741, 0.761757, 0.875211, 0.961988, 0.991761,
+    0.997340, 0.955552, 0.868934, 0.777405, 0.658341, 0.527963, 0.398057, 0.283493, 0.179828,
+    0.107633, 0.060281, 0.031800, 0.015905, 0.007749, 0.003718, 0.001768, 0.000846, 0.000407,
+    0.000199, 0.000098, 0.000050, 0.000025, 0.000013,
+];
+
+/// The CIE 1964 10° color matching function z̄.
+#[rustfmt::skip]
+pub(super) const CIE_1964_Z: [f64; 41] = [
+    0.000705, 0.010482, 0.086011, 0.389366, 0.972542, 1.553480, 1.967280, 1.994800, 1.745370,
+    1.317560, 0.772125, 0.415254, 0.218502, 0.112044, 0.060709, 0.030451, 0.013676, 0.003988,
+    0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+    0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+    0.000000, 0.000000, 0.000000, 0.000000, 0.000000,
+];
+
+/// The relative spectral power distribution of CIE standard illuminant D65.
+#[rustfmt::skip]
+pub(super) const D65: [f64; 41] = [
+    49.9755, 54.6482, 82.7549, 91.486, 93.4318, 86.6823, 104.865, 117.008, 117.812, 114.861,
+    115.923, 108.811, 109.354, 107.802, 104.79, 107.689, 104.405, 104.046, 100.0, 96.3342, 95.788,
+    88.6856, 90.0062, 89.5991, 87.6987, 83.2886, 83.6992, 80.0268, 80.2146, 82.2778, 78.2842,
+    69.7213, 71.6091, 74.349, 61.604, 69.8856, 75.087, 63.5927, 46.4182, 66.8054, 63.3828,
+];
+
+/// The mean component, S0, of CIE daylight.
+#[rustfmt::skip]
+pub(super) const DAYLIGHT_S0: [f64; 41] = [
+    63.4, 65.8, 94.8, 104.8, 105.9, 96.8, 113.9, 125.6, 125.5, 121.3, 121.3, 113.5, 113.1, 110.8,
+    106.5, 108.8, 105.3, 104.4, 100.0, 96.0, 95.1, 89.1, 90.5, 90.3, 88.4, 84.0, 85.1, 81.9, 82.6,
+    84.9, 81.3, 71.9, 74.3, 76.4, 63.3, 71.7, 77.0, 65.2, 47.7, 68.6, 65.0,
+];
+
+/// The first characteristic vector, S1, of CIE daylight.
+#[rustfmt::skip]
+pub(super) const DAYLIGHT_S1: [f64; 41] = [
+    38.5, 35.0, 43.4, 46.3, 43.9, 37.1, 36.7, 35.9, 32.6, 27.9, 24.3, 20.1, 16.2, 13.2, 8.6, 6.1,
+    4.2, 1.9, 0.0, -1.6, -3.5, -3.5, -5.8, -7.2, -8.6, -9.5, -10.9, -10.7, -12.0, -14.0, -13.6,
+    -12.0, -13.3, -12.9, -10.6, -11.6, -12.2, -10.2, -7.8, -11.2, -10.4,
+];
+
+/// The second characteristic vector, S2, of CIE daylight.
+#[rustfmt::skip]
+pub(super) const DAYLIGHT_S2: [f64; 41] = [
+    3.0, 1.2, -1.1, -0.5, -0.7, -1.2, -2.6, -2.9, -2.8, -2.6, -2.6, -1.8, -1.5, -1.3, -1.2, -1.0,
+    -0.5, -0.3, 0.0, 0.2, 0.5, 2.1, 3.2, 4.1, 4.7, 5.1, 6.7, 7.3, 8.6, 9.8, 10.2, 8.3, 9.6, 8.5,
+    7.0, 7.6, 8.0, 6.7, 5.2, 7.4, 6.8,
+];
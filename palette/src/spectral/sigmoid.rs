@@ -0,0 +1,313 @@
+use crate::{
+    bool_mask::{HasBoolMask, LazySelect},
+    convert::FromColorUnclamped,
+    matrix::{matrix_inverse, multiply_xyz},
+    num::{
+        Abs, Arithmetics, Cbrt, Clamp, IsValidDivisor, MinMax, One, PartialCmp, Powi, Real, Recip,
+        Sqrt, Zero,
+    },
+    white_point::D65,
+    Lab, LinSrgb, Xyz,
+};
+
+use super::{Cie1931, Spectrum};
+
+/// The first and last wavelengths of the fitted range, in nanometers.
+const START: f64 = 380.0;
+const END: f64 = 780.0;
+
+const MAX_ITERATIONS: usize = 100;
+
+/// How close a flat spectrum can get to 0.0 and 1.0. The sigmoid can't reach
+/// them, and other models, such as Kubelka-Munk, break down at 0.0.
+pub(crate) const REFLECTANCE_EPSILON: f64 = 1e-6;
+
+/// A smooth reflectance spectrum, described by a quadratic polynomial in a
+/// sigmoid function.
+///
+/// This is the spectral upsampling method by Jakob and Hanika (2019), which
+/// turns an RGB color into a smooth spectrum that has the same color under a
+/// reference illuminant. The reflectance at the wavelength `λ` is
+///
+/// `S(λ) = s(c0 * t² + c1 * t + c2)`, where `s(x) = 0.5 + x / (2 * sqrt(1 +
+/// x²))`,
+///
+/// and `t` is `λ` normalized from the 380 nm to 780 nm range to the 0.0 to
+/// 1.0 range. The spectrum is always between 0.0 and 1.0, which makes it
+/// physically plausible as a reflectance.
+///
+/// ```
+/// use palette::{
+///     convert::FromColorUnclamped,
+///     spectral::{Cie1931, SigmoidPolynomial},
+///     white_point::D65,
+///     LinSrgb, Srgb,
+/// };
+///
+/// let albedo = Srgb::new(0.8f64, 0.3, 0.1).into_linear();
+/// let spectrum = SigmoidPolynomial::from_linear_srgb(albedo);
+///
+/// let reflectance_at_600nm = spectrum.get(600.0);
+///
+/// // The spectrum has the same color under D65.
+/// let color = spectrum.to_spectrum().reflective_to_xyz(Cie1931, D65);
+/// let roundtrip = LinSrgb::from_color_unclamped(color);
+/// assert!((roundtrip.red - albedo.red).abs() < 0.001);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SigmoidPolynomial<T> {
+    c0: T,
+    c1: T,
+    c2: T,
+}
+
+impl<T> SigmoidPolynomial<T> {
+    /// Create a sigmoid polynomial from its coefficients, for the normalized
+    /// wavelength range.
+    pub const fn new(c0: T, c1: T, c2: T) -> Self {
+        SigmoidPolynomial { c0, c1, c2 }
+    }
+
+    /// Get the coefficients, `[c0, c1, c2]`, for the normalized wavelength
+    /// range.
+    pub fn coefficients(self) -> [T; 3] {
+        [self.c0, self.c1, self.c2]
+    }
+}
+
+impl<T> SigmoidPolynomial<T>
+where
+    T: Real + One + Sqrt + Arithmetics + Clone,
+{
+    /// Get the reflectance at `wavelength`, in nanometers.
+    #[must_use]
+    pub fn get(&self, wavelength: f64) -> T {
+        let t = T::from_f64((wavelength - START) / (END - START));
+        let x = (self.c0.clone() * &t + &self.c1) * t + &self.c2;
+
+        sigmoid(x)
+    }
+
+    /// Sample the reflectance from 380 nm to 780 nm in 10 nm steps, like the
+    /// embedded observers and illuminants.
+    #[must_use]
+    pub fn to_spectrum(&self) -> Spectrum<[T; 41]> {
+        let values = core::array::from_fn(|index| self.get(START + index as f64 * 10.0));
+        Spectrum::new(START, 10.0, values)
+    }
+}
+
+impl<T> SigmoidPolynomial<T>
+where
+    T: Real
+        + Zero
+        + One
+        + Sqrt
+        + Abs
+        + MinMax
+        + Clamp
+        + Powi
+        + Cbrt
+        + Recip
+        + IsValidDivisor<Mask = bool>
+        + PartialCmp
+        + HasBoolMask<Mask = bool>
+        + Arithmetics
+        + Clone,
+    T::Mask: LazySelect<T>,
+    Xyz<D65, T>: FromColorUnclamped<LinSrgb<T>>,
+{
+    /// Find the sigmoid polynomial with the same color as `color`, when
+    /// integrated under D65 with the CIE 1931 2° observer.
+    ///
+    /// The components of `color` are clamped to the 0.0 to 1.0 range, since a
+    /// reflectance can't be outside it. The coefficients are found with the
+    /// Gauss-Newton method, minimizing the difference in CIE L\*a\*b\*. The
+    /// extreme colors, such as pure black, white and the primaries, can only
+    /// be approached, and are as close as a smooth spectrum can get.
+    #[must_use]
+    pub fn from_linear_srgb(color: LinSrgb<T>) -> Self {
+        let red = color.red.clamp(T::zero(), T::one());
+        let green = color.green.clamp(T::zero(), T::one());
+        let blue = color.blue.clamp(T::zero(), T::one());
+
+        // Grays are flat spectra, where the sigmoid can be inverted directly.
+        if red.eq(&green) && green.eq(&blue) {
+            let epsilon = T::from_f64(REFLECTANCE_EPSILON);
+            let value = red.clamp(epsilon.clone(), T::one() - epsilon);
+            let x = T::from_f64(2.0) * value - T::one();
+            let c2 = x.clone() / (T::one() - x.clone() * x).sqrt();
+
+            return SigmoidPolynomial::new(T::zero(), T::zero(), c2);
+        }
+
+        let target = lab_under_d65(&LinSrgb::new(red, green, blue));
+        let residual = |coefficients: &[T; 3]| {
+            let [c0, c1, c2] = coefficients.clone();
+            let spectrum = SigmoidPolynomial::new(c0, c1, c2).to_spectrum();
+            let lab: Lab<D65, T> =
+                Lab::from_color_unclamped(spectrum.reflective_to_xyz(Cie1931, D65));
+
+            [lab.l - &target.l, lab.a - &target.a, lab.b - &target.b]
+        };
+
+        let step = T::from_f64(1e-4);
+        let max_change = T::from_f64(5.0);
+        let tolerance = T::from_f64(1e-3);
+
+        let mut coefficients = [T::zero(), T::zero(), T::zero()];
+        for _ in 0..MAX_ITERATIONS {
+            let r = residual(&coefficients);
+            let length =
+                (r[0].clone() * &r[0] + r[1].clone() * &r[1] + r[2].clone() * &r[2]).sqrt();
+            if length.lt(&tolerance) {
+                break;
+            }
+
+            // The Jacobian, from forward differences.
+            let mut jacobian: [T; 9] = core::array::from_fn(|_| T::zero());
+            for column in 0..3 {
+                let mut moved = coefficients.clone();
+                moved[column] = moved[column].clone() + &step;
+                let r2 = residual(&moved);
+
+                for row in 0..3 {
+                    jacobian[row * 3 + column] = (r2[row].clone() - &r[row]) / &step;
+                }
+            }
+
+            if !determinant(&jacobian).is_valid_divisor() {
+                break;
+            }
+
+            let [r0, r1, r2] = r;
+            let delta = multiply_xyz(matrix_inverse(jacobian), Xyz::new(r0, r1, r2));
+            let mut delta = [delta.x, delta.y, delta.z];
+
+            // Limit the step size, to not overshoot far from the solution.
+            let largest = delta[0]
+                .clone()
+                .abs()
+                .max(delta[1].clone().abs())
+                .max(delta[2].clone().abs());
+            if largest.gt(&max_change) {
+                let scale = max_change.clone() / largest;
+                for value in &mut delta {
+                    *value = value.clone() * &scale;
+                }
+            }
+
+            for (coefficient, delta) in coefficients.iter_mut().zip(delta) {
+                *coefficient = coefficient.clone() - delta;
+            }
+        }
+
+        let [c0, c1, c2] = coefficients;
+        SigmoidPolynomial::new(c0, c1, c2)
+    }
+}
+
+impl<T> From<SigmoidPolynomial<T>> for Spectrum<[T; 41]>
+where
+    T: Real + One + Sqrt + Arithmetics + Clone,
+{
+    fn from(polynomial: SigmoidPolynomial<T>) -> Self {
+        polynomial.to_spectrum()
+    }
+}
+
+fn sigmoid<T>(x: T) -> T
+where
+    T: Real + One + Sqrt + Arithmetics + Clone,
+{
+    let half = T::from_f64(0.5);
+    half.clone() + half * x.clone() / (T::one() + x.clone() * x).sqrt()
+}
+
+fn lab_under_d65<T>(color: &LinSrgb<T>) -> Lab<D65, T>
+where
+    T: Real + Powi + Cbrt + Arithmetics + PartialCmp + Clone,
+    T::Mask: LazySelect<T>,
+    Xyz<D65, T>: FromColorUnclamped<LinSrgb<T>>,
+{
+    Lab::from_color_unclamped(Xyz::from_color_unclamped(color.clone()))
+}
+
+fn determinant<T>(m: &[T; 9]) -> T
+where
+    T: Arithmetics + Clone,
+{
+    m[0].clone() * (m[4].clone() * &m[8] - m[5].clone() * &m[7])
+        - m[1].clone() * (m[3].clone() * &m[8] - m[5].clone() * &m[6])
+        + m[2].clone() * (m[3].clone() * &m[7] - m[4].clone() * &m[6])
+}
+
+#[cfg(test)]
+mod test {
+    use super::SigmoidPolynomial;
+    use crate::{
+        convert::FromColorUnclamped, spectral::Cie1931, white_point::D65, LinSrgb, Srgb, Xyz,
+    };
+
+    fn roundtrip(color: LinSrgb<f64>) -> LinSrgb<f64> {
+        let spectrum = SigmoidPolynomial::from_linear_srgb(color).to_spectrum();
+        let xyz: Xyz<D65, f64> = spectrum.reflective_to_xyz(Cie1931, D65);
+        LinSrgb::from_color_unclamped(xyz)
+    }
+
+    #[test]
+    fn roundtrip_colors() {
+        let colors = [
+            Srgb::new(0.8, 0.3, 0.1),
+            Srgb::new(0.1, 0.6, 0.2),
+            Srgb::new(0.2, 0.3, 0.9),
+            Srgb::new(0.9, 0.9, 0.2),
+            Srgb::new(0.6, 0.4, 0.7),
+            Srgb::new(0.05, 0.1, 0.08),
+        ];
+
+        for color in colors {
+            let color = color.into_linear();
+            assert_relative_eq!(roundtrip(color), color, epsilon = 0.0001);
+        }
+    }
+
+    #[test]
+    fn grays() {
+        for &value in &[0.02, 0.18, 0.5, 0.9] {
+            let gray = LinSrgb::new(value, value, value);
+            let polynomial = SigmoidPolynomial::from_linear_srgb(gray);
+
+            assert_relative_eq!(polynomial.get(400.0), value, epsilon = 0.0000001);
+            assert_relative_eq!(polynomial.get(700.0), value, epsilon = 0.0000001);
+
+            // The embedded D65 is slightly off from the D65 white point.
+            assert_relative_eq!(roundtrip(gray), gray, epsilon = 0.001);
+        }
+
+        let white = SigmoidPolynomial::from_linear_srgb(LinSrgb::new(1.0f64, 1.0, 1.0));
+        assert_relative_eq!(white.get(550.0), 1.0, epsilon = 0.00001);
+
+        let black = SigmoidPolynomial::from_linear_srgb(LinSrgb::new(0.0f64, 0.0, 0.0));
+        assert_relative_eq!(black.get(550.0), 0.0, epsilon = 0.00001);
+    }
+
+    #[test]
+    fn saturated() {
+        let red = roundtrip(LinSrgb::new(1.0, 0.0, 0.0));
+        assert_relative_eq!(red, LinSrgb::new(1.0, 0.0, 0.0), epsilon = 0.01);
+
+        let clamped = SigmoidPolynomial::from_linear_srgb(LinSrgb::new(1.5f64, -0.5, 0.0));
+        let expected = SigmoidPolynomial::from_linear_srgb(LinSrgb::new(1.0f64, 0.0, 0.0));
+        assert_eq!(clamped, expected);
+    }
+
+    #[test]
+    fn in_range() {
+        let polynomial = SigmoidPolynomial::from_linear_srgb(LinSrgb::new(0.9f64, 0.05, 0.4));
+
+        for value in polynomial.to_spectrum().into_values() {
+            assert!((0.0..=1.0).contains(&value));
+        }
+    }
+}
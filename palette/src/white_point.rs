@@ -31,6 +31,19 @@ pub trait WhitePoint<T>: 'static {
     fn get_xyz() -> Xyz<Any, T>;
 }
 
+/// Gives the white point of a color type, as used in its conversions.
+///
+/// This lets [`AdaptFrom`](crate::chromatic_adaptation::AdaptFrom) and
+/// [`AdaptInto`](crate::chromatic_adaptation::AdaptInto) find the source and
+/// destination white points, even when a color type can be converted to `Xyz`
+/// with more than one white point. It's implemented by the
+/// [`FromColorUnclamped`](crate::convert::FromColorUnclamped) derive macro,
+/// using the same white point as the derived conversions.
+pub trait HasWhitePoint {
+    /// The white point of the color type.
+    type WhitePoint;
+}
+
 /// CIE standard illuminant A
 ///
 /// CIE standard illuminant A is intended to represent typical, domestic,
@@ -39,10 +52,14 @@ pub trait WhitePoint<T>: 'static {
 /// CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct A;
+impl A {
+    pub(crate) const XYZ: [f64; 3] = [1.09850, 1.0, 0.35585];
+}
 impl<T: Real> WhitePoint<T> for A {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(1.09850), T::from_f64(1.0), T::from_f64(0.35585))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE standard illuminant B
@@ -51,10 +68,14 @@ impl<T: Real> WhitePoint<T> for A {
 /// temperature (CCT) of 4874 K Uses the CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct B;
+impl B {
+    pub(crate) const XYZ: [f64; 3] = [0.99072, 1.0, 0.85223];
+}
 impl<T: Real> WhitePoint<T> for B {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.99072), T::from_f64(1.0), T::from_f64(0.85223))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE standard illuminant C
@@ -63,10 +84,14 @@ impl<T: Real> WhitePoint<T> for B {
 /// 6774 K Uses the CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct C;
+impl C {
+    pub(crate) const XYZ: [f64; 3] = [0.98074, 1.0, 1.18232];
+}
 impl<T: Real> WhitePoint<T> for C {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.98074), T::from_f64(1.0), T::from_f64(1.18232))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D50
@@ -75,10 +100,14 @@ impl<T: Real> WhitePoint<T> for C {
 /// 5000K for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D50;
+impl D50 {
+    pub(crate) const XYZ: [f64; 3] = [0.96422, 1.0, 0.82521];
+}
 impl<T: Real> WhitePoint<T> for D50 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.96422), T::from_f64(1.0), T::from_f64(0.82521))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D55
@@ -87,10 +116,14 @@ impl<T: Real> WhitePoint<T> for D50 {
 /// 5500K for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D55;
+impl D55 {
+    pub(crate) const XYZ: [f64; 3] = [0.95682, 1.0, 0.92149];
+}
 impl<T: Real> WhitePoint<T> for D55 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.95682), T::from_f64(1.0), T::from_f64(0.92149))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D65
@@ -99,10 +132,14 @@ impl<T: Real> WhitePoint<T> for D55 {
 /// for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D65;
+impl D65 {
+    pub(crate) const XYZ: [f64; 3] = [0.95047, 1.0, 1.08883];
+}
 impl<T: Real> WhitePoint<T> for D65 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.95047), T::from_f64(1.0), T::from_f64(1.08883))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D75
@@ -111,10 +148,14 @@ impl<T: Real> WhitePoint<T> for D65 {
 /// 7500K for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D75;
+impl D75 {
+    pub(crate) const XYZ: [f64; 3] = [0.94972, 1.0, 1.22638];
+}
 impl<T: Real> WhitePoint<T> for D75 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.94972), T::from_f64(1.0), T::from_f64(1.22638))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE standard illuminant E
@@ -123,10 +164,14 @@ impl<T: Real> WhitePoint<T> for D75 {
 /// Uses the CIE 1932 2° Standard Observer
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct E;
+impl E {
+    pub(crate) const XYZ: [f64; 3] = [1.0, 1.0, 1.0];
+}
 impl<T: Real> WhitePoint<T> for E {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(1.0), T::from_f64(1.0), T::from_f64(1.0))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE fluorescent illuminant series - F2
@@ -134,10 +179,14 @@ impl<T: Real> WhitePoint<T> for E {
 /// F2 represents a semi-broadband fluorescent lamp for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct F2;
+impl F2 {
+    pub(crate) const XYZ: [f64; 3] = [0.99186, 1.0, 0.67393];
+}
 impl<T: Real> WhitePoint<T> for F2 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.99186), T::from_f64(1.0), T::from_f64(0.67393))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE fluorescent illuminant series - F7
@@ -145,10 +194,14 @@ impl<T: Real> WhitePoint<T> for F2 {
 /// F7 represents a broadband fluorescent lamp for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct F7;
+impl F7 {
+    pub(crate) const XYZ: [f64; 3] = [0.95041, 1.0, 1.08747];
+}
 impl<T: Real> WhitePoint<T> for F7 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.95041), T::from_f64(1.0), T::from_f64(1.08747))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE fluorescent illuminant series - F11
@@ -156,10 +209,47 @@ impl<T: Real> WhitePoint<T> for F7 {
 /// F11 represents a narrowband fluorescent lamp for 2° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct F11;
+impl F11 {
+    pub(crate) const XYZ: [f64; 3] = [1.00962, 1.0, 0.64350];
+}
 impl<T: Real> WhitePoint<T> for F11 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(1.00962), T::from_f64(1.0), T::from_f64(0.64350))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
+    }
+}
+/// DCI-P3 white point
+///
+/// The white point of digital cinema projection, from SMPTE RP 431-2. It's
+/// slightly greener than D65 and isn't a CIE standard illuminant.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Dci;
+impl Dci {
+    pub(crate) const XYZ: [f64; 3] = [0.89459, 1.0, 0.95442];
+}
+impl<T: Real> WhitePoint<T> for Dci {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
+    }
+}
+/// ACES white point
+///
+/// The white point of the Academy Color Encoding System, at the chromaticity
+/// coordinates x = 0.32168, y = 0.33767. It's close to, but not the same as,
+/// D60.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Aces;
+impl Aces {
+    pub(crate) const XYZ: [f64; 3] = [0.95264607, 1.0, 1.00882518];
+}
+impl<T: Real> WhitePoint<T> for Aces {
+    #[inline]
+    fn get_xyz() -> Xyz<Any, T> {
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D50
@@ -168,10 +258,14 @@ impl<T: Real> WhitePoint<T> for F11 {
 /// 5000K for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D50Degree10;
+impl D50Degree10 {
+    pub(crate) const XYZ: [f64; 3] = [0.9672, 1.0, 0.8143];
+}
 impl<T: Real> WhitePoint<T> for D50Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.9672), T::from_f64(1.0), T::from_f64(0.8143))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D55
@@ -180,10 +274,14 @@ impl<T: Real> WhitePoint<T> for D50Degree10 {
 /// 5500K for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D55Degree10;
+impl D55Degree10 {
+    pub(crate) const XYZ: [f64; 3] = [0.958, 1.0, 0.9093];
+}
 impl<T: Real> WhitePoint<T> for D55Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.958), T::from_f64(1.0), T::from_f64(0.9093))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D65
@@ -192,10 +290,14 @@ impl<T: Real> WhitePoint<T> for D55Degree10 {
 /// for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D65Degree10;
+impl D65Degree10 {
+    pub(crate) const XYZ: [f64; 3] = [0.9481, 1.0, 1.073];
+}
 impl<T: Real> WhitePoint<T> for D65Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.9481), T::from_f64(1.0), T::from_f64(1.073))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
 /// CIE D series standard illuminant - D75
@@ -204,9 +306,13 @@ impl<T: Real> WhitePoint<T> for D65Degree10 {
 /// 7500K for 10° Standard Observer.
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub struct D75Degree10;
+impl D75Degree10 {
+    pub(crate) const XYZ: [f64; 3] = [0.94416, 1.0, 1.2064];
+}
 impl<T: Real> WhitePoint<T> for D75Degree10 {
     #[inline]
     fn get_xyz() -> Xyz<Any, T> {
-        Xyz::new(T::from_f64(0.94416), T::from_f64(1.0), T::from_f64(1.2064))
+        let [x, y, z] = Self::XYZ;
+        Xyz::new(T::from_f64(x), T::from_f64(y), T::from_f64(z))
     }
 }
@@ -15,22 +15,25 @@ use rand::{
 };
 
 use crate::{
+    angle::RealAngle,
     blend::{PreAlpha, Premultiply},
     bool_mask::{HasBoolMask, LazySelect},
+    cam16::{self, ViewingConditions},
+    chromatic_adaptation::ConvertFromWhitePoint,
     clamp, clamp_assign, contrast_ratio,
     convert::{FromColorUnclamped, IntoColorUnclamped},
     encoding::IntoLinear,
     luma::LumaStandard,
     matrix::{multiply_rgb_to_xyz, multiply_xyz, rgb_to_xyz_matrix},
     num::{
-        self, Arithmetics, FromScalar, FromScalarArray, IntoScalarArray, IsValidDivisor, MinMax,
-        One, PartialCmp, Powi, Real, Recip, Zero,
+        self, Abs, Arithmetics, Cbrt, Exp, FromScalar, FromScalarArray, Hypot, IntoScalarArray,
+        IsValidDivisor, MinMax, One, PartialCmp, Powf, Powi, Real, Recip, Sqrt, Trigonometry, Zero,
     },
     oklab,
     rgb::{Primaries, Rgb, RgbSpace, RgbStandard},
     stimulus::{Stimulus, StimulusColor},
     white_point::{Any, WhitePoint, D65},
-    Alpha, Clamp, ClampAssign, IsWithinBounds, Lab, Lighten, LightenAssign, Luma, Luv, Mix,
+    Alpha, Cam16, Clamp, ClampAssign, IsWithinBounds, Lab, Lighten, LightenAssign, Luma, Luv, Mix,
     MixAssign, Okhsl, Okhsv, Okhwb, Oklab, Oklch, RelativeContrast, Yxy,
 };
 
@@ -53,7 +56,9 @@ pub type Xyza<Wp = D65, T = f32> = Alpha<Xyz<Wp, T>, T>;
     palette_internal,
     white_point = "Wp",
     component = "T",
-    skip_derives(Xyz, Yxy, Luv, Rgb, Lab, Oklab, Oklch, Okhsl, Okhsv, Okhwb, Luma)
+    skip_derives(
+        Xyz, Yxy, Luv, Rgb, Lab, Oklab, Oklch, Okhsl, Okhsv, Okhwb, Luma, Cam16, Hct, Jzazbz, ICtCp
+    )
 )]
 #[repr(C)]
 pub struct Xyz<Wp = D65, T = f32> {
@@ -211,16 +216,18 @@ where
     T: Arithmetics + FromScalar,
     T::Scalar:
         Recip + IsValidDivisor<Mask = bool> + Arithmetics + FromScalar<Scalar = T::Scalar> + Clone,
-    Wp: WhitePoint<T::Scalar>,
+    Wp: ConvertFromWhitePoint<<S::Space as RgbSpace>::WhitePoint, T>,
     S: RgbStandard,
     S::TransferFn: IntoLinear<T, T>,
-    S::Space: RgbSpace<WhitePoint = Wp>,
     <S::Space as RgbSpace>::Primaries: Primaries<T::Scalar>,
+    <S::Space as RgbSpace>::WhitePoint: WhitePoint<T::Scalar>,
     Yxy<Any, T::Scalar>: IntoColorUnclamped<Xyz<Any, T::Scalar>>,
 {
     fn from_color_unclamped(color: Rgb<S, T>) -> Self {
         let transform_matrix = rgb_to_xyz_matrix::<S::Space, T::Scalar>();
-        multiply_rgb_to_xyz(transform_matrix, color.into_linear())
+
+        // Adapts the color if the white points are different.
+        Wp::convert_xyz(multiply_rgb_to_xyz(transform_matrix, color.into_linear()))
     }
 }
 
@@ -385,6 +392,37 @@ where
     }
 }
 
+impl<Wp, T> FromColorUnclamped<Cam16<Wp, T>> for Xyz<Wp, T>
+where
+    Wp: WhitePoint<T>,
+    T: Real
+        + RealAngle
+        + Arithmetics
+        + Powf
+        + Sqrt
+        + Cbrt
+        + Exp
+        + Abs
+        + Hypot
+        + MinMax
+        + Trigonometry
+        + num::Clamp
+        + Zero
+        + One
+        + PartialCmp
+        + Clone,
+    T::Mask: LazySelect<T>,
+{
+    fn from_color_unclamped(color: Cam16<Wp, T>) -> Self {
+        cam16::cam16_to_xyz(
+            color.lightness,
+            color.chroma,
+            color.hue,
+            &ViewingConditions::default().into(),
+        )
+    }
+}
+
 impl<Wp, T> From<(T, T, T)> for Xyz<Wp, T> {
     fn from(components: (T, T, T)) -> Self {
         Self::from_components(components)
@@ -617,21 +655,21 @@ mod test {
 
     #[test]
     fn red() {
-        let a = Xyz::from_color(LinSrgb::new(1.0, 0.0, 0.0));
+        let a = Xyz::<D65, _>::from_color(LinSrgb::new(1.0, 0.0, 0.0));
         let b = Xyz::new(0.41240, 0.21260, 0.01930);
         assert_relative_eq!(a, b, epsilon = 0.0001);
     }
 
     #[test]
     fn green() {
-        let a = Xyz::from_color(LinSrgb::new(0.0, 1.0, 0.0));
+        let a = Xyz::<D65, _>::from_color(LinSrgb::new(0.0, 1.0, 0.0));
         let b = Xyz::new(0.35760, 0.71520, 0.11920);
         assert_relative_eq!(a, b, epsilon = 0.0001);
     }
 
     #[test]
     fn blue() {
-        let a = Xyz::from_color(LinSrgb::new(0.0, 0.0, 1.0));
+        let a = Xyz::<D65, _>::from_color(LinSrgb::new(0.0, 0.0, 1.0));
         let b = Xyz::new(0.18050, 0.07220, 0.95030);
         assert_relative_eq!(a, b, epsilon = 0.0001);
     }
@@ -14,6 +14,7 @@ use csv;
 use approx::assert_relative_eq;
 use serde_derive::Deserialize;
 
+use palette::color_difference::{Ciede2000, DifferenceFormula};
 use palette::convert::FromColorUnclamped;
 use palette::white_point::D65;
 use palette::{ColorDifference, Lab, Lch};
@@ -78,5 +79,8 @@ pub fn run_tests() {
         let lch2: Lch<_, f64> = Lch::from_color_unclamped(expected.c2);
         let result_lch = lch1.get_color_difference(lch2);
         check_equal_lch(result_lch, expected.delta_e);
+
+        let result_formula = Ciede2000::default().get_difference(expected.c1, expected.c2);
+        check_equal_lab(result_formula, expected.delta_e);
     }
 }
@@ -0,0 +1,98 @@
+"""
+Generates data_color_difference.csv from the color pairs in data_ciede_2000.csv.
+
+Run from the palette directory:
+
+    python3 tests/convert/data_color_difference.py > tests/convert/data_color_difference.csv
+
+The formulas are written out from their definitions:
+
+* CIE94: CIE 116-1995, "Industrial Colour-Difference Evaluation", with the
+  graphic arts (kL = 1, K1 = 0.045, K2 = 0.015) and textile (kL = 2,
+  K1 = 0.048, K2 = 0.014) parameters.
+* CMC l:c: F. J. J. Clarke, R. McDonald and B. Rigg, "Modification to the
+  JPC79 Colour-difference Formula", J. Soc. Dyers Colour. 100 (1984), 128-132.
+
+The first color in each pair is the reference color.
+"""
+
+import csv
+import math
+import sys
+
+
+def chroma(a, b):
+    return math.hypot(a, b)
+
+
+def delta_h_squared(lab1, lab2):
+    delta_c = chroma(lab1[1], lab1[2]) - chroma(lab2[1], lab2[2])
+    delta_a = lab1[1] - lab2[1]
+    delta_b = lab1[2] - lab2[2]
+    return max(delta_a ** 2 + delta_b ** 2 - delta_c ** 2, 0.0)
+
+
+def cie76(lab1, lab2):
+    return math.sqrt(sum((x - y) ** 2 for x, y in zip(lab1, lab2)))
+
+
+def cie94(lab1, lab2, k_l, k_1, k_2):
+    c1 = chroma(lab1[1], lab1[2])
+    delta_c = c1 - chroma(lab2[1], lab2[2])
+    s_c = 1 + k_1 * c1
+    s_h = 1 + k_2 * c1
+    return math.sqrt(
+        ((lab1[0] - lab2[0]) / k_l) ** 2
+        + (delta_c / s_c) ** 2
+        + delta_h_squared(lab1, lab2) / s_h ** 2
+    )
+
+
+def cmc(lab1, lab2, l, c):
+    l1 = lab1[0]
+    c1 = chroma(lab1[1], lab1[2])
+    delta_c = c1 - chroma(lab2[1], lab2[2])
+    h1 = math.degrees(math.atan2(lab1[2], lab1[1])) % 360
+
+    s_l = 0.511 if l1 < 16 else 0.040975 * l1 / (1 + 0.01765 * l1)
+    s_c = 0.0638 * c1 / (1 + 0.0131 * c1) + 0.638
+    f = math.sqrt(c1 ** 4 / (c1 ** 4 + 1900))
+    if 164 <= h1 <= 345:
+        t = 0.56 + abs(0.2 * math.cos(math.radians(h1 + 168)))
+    else:
+        t = 0.36 + abs(0.4 * math.cos(math.radians(h1 + 35)))
+    s_h = s_c * (f * t + 1 - f)
+
+    return math.sqrt(
+        ((l1 - lab2[0]) / (l * s_l)) ** 2
+        + (delta_c / (c * s_c)) ** 2
+        + delta_h_squared(lab1, lab2) / s_h ** 2
+    )
+
+
+def main():
+    reader = csv.reader(open("tests/convert/data_ciede_2000.csv"))
+    next(reader)
+
+    writer = csv.writer(sys.stdout, lineterminator="\n")
+    writer.writerow([
+        "lab1_l", "lab1_a", "lab1_b", "lab2_l", "lab2_a", "lab2_b",
+        "delta_e_76", "delta_e_94_graphic_arts", "delta_e_94_textile",
+        "delta_e_cmc_2_1", "delta_e_cmc_1_1",
+    ])
+
+    for row in reader:
+        lab1 = [float(x) for x in row[0:3]]
+        lab2 = [float(x) for x in row[3:6]]
+        values = [
+            cie76(lab1, lab2),
+            cie94(lab1, lab2, 1.0, 0.045, 0.015),
+            cie94(lab1, lab2, 2.0, 0.048, 0.014),
+            cmc(lab1, lab2, 2.0, 1.0),
+            cmc(lab1, lab2, 1.0, 1.0),
+        ]
+        writer.writerow(row[0:6] + ["%.4f" % value for value in values])
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,110 @@
+/*
+The color pairs are from http://www2.ece.rochester.edu/~gsharma/ciede2000/
+
+Tests Lab color differences with expected CIE76, CIE94 and CMC l:c delta E
+values. The expected values are generated by `data_color_difference.py`, in
+this directory, which implements CIE94 from CIE 116-1995 and CMC l:c from
+Clarke, McDonald and Rigg (1984), "Modification to the JPC79 Colour-difference
+Formula", J. Soc. Dyers Colour. 100, 128-132.
+
+The first color in each pair is the reference color, since CIE94 and CMC l:c
+are not symmetric.
+*/
+
+use csv;
+
+use approx::assert_relative_eq;
+use serde_derive::Deserialize;
+
+use palette::color_difference::{Cie76, Cie94, Cmc, DifferenceFormula};
+use palette::convert::FromColorUnclamped;
+use palette::white_point::D65;
+use palette::{Lab, Lch};
+
+#[derive(Deserialize, PartialEq)]
+struct ColorDifferenceRaw {
+    lab1_l: f64,
+    lab1_a: f64,
+    lab1_b: f64,
+    lab2_l: f64,
+    lab2_a: f64,
+    lab2_b: f64,
+    delta_e_76: f64,
+    delta_e_94_graphic_arts: f64,
+    delta_e_94_textile: f64,
+    delta_e_cmc_2_1: f64,
+    delta_e_cmc_1_1: f64,
+}
+
+#[derive(Copy, Clone, PartialEq, Debug)]
+struct ColorDifference {
+    c1: Lab<D65, f64>,
+    c2: Lab<D65, f64>,
+    delta_e_76: f64,
+    delta_e_94_graphic_arts: f64,
+    delta_e_94_textile: f64,
+    delta_e_cmc_2_1: f64,
+    delta_e_cmc_1_1: f64,
+}
+
+impl From<ColorDifferenceRaw> for ColorDifference {
+    fn from(src: ColorDifferenceRaw) -> ColorDifference {
+        ColorDifference {
+            c1: Lab::new(src.lab1_l, src.lab1_a, src.lab1_b),
+            c2: Lab::new(src.lab2_l, src.lab2_a, src.lab2_b),
+            delta_e_76: src.delta_e_76,
+            delta_e_94_graphic_arts: src.delta_e_94_graphic_arts,
+            delta_e_94_textile: src.delta_e_94_textile,
+            delta_e_cmc_2_1: src.delta_e_cmc_2_1,
+            delta_e_cmc_1_1: src.delta_e_cmc_1_1,
+        }
+    }
+}
+
+fn load_data() -> Vec<ColorDifference> {
+    let file_name = "tests/convert/data_color_difference.csv";
+    let mut rdr = csv::Reader::from_path(file_name)
+        .expect("csv file could not be loaded in tests for color difference data");
+    let mut color_data: Vec<ColorDifference> = Vec::new();
+    for record in rdr.deserialize() {
+        let r: ColorDifferenceRaw =
+            record.expect("color data could not be decoded in tests for color difference data");
+        color_data.push(r.into())
+    }
+    color_data
+}
+
+fn check_equal(result: f64, expected: f64) {
+    assert_relative_eq!(result, expected, epsilon = 0.0001);
+}
+
+fn check_formula<F>(formula: F, get_expected: fn(&ColorDifference) -> f64)
+where
+    F: DifferenceFormula<f64>,
+{
+    let data = load_data();
+
+    for expected in data.iter() {
+        let result_lab = formula.get_difference(expected.c1, expected.c2);
+        check_equal(result_lab, get_expected(expected));
+
+        let lch1: Lch<_, f64> = Lch::from_color_unclamped(expected.c1);
+        let lch2: Lch<_, f64> = Lch::from_color_unclamped(expected.c2);
+        let result_lch = formula.get_difference(lch1, lch2);
+        check_equal(result_lch, get_expected(expected));
+    }
+}
+
+pub fn run_cie76_tests() {
+    check_formula(Cie76, |data| data.delta_e_76);
+}
+
+pub fn run_cie94_tests() {
+    check_formula(Cie94::graphic_arts(), |data| data.delta_e_94_graphic_arts);
+    check_formula(Cie94::textile(), |data| data.delta_e_94_textile);
+}
+
+pub fn run_cmc_tests() {
+    check_formula(Cmc::acceptability(), |data| data.delta_e_cmc_2_1);
+    check_formula(Cmc::perceptibility(), |data| data.delta_e_cmc_1_1);
+}
@@ -0,0 +1,90 @@
+/*
+Data is from the examples in Colour (https://www.colour-science.org/), for
+`colour.RGB_to_ICtCp` with the "ITU-R BT.2100-2 PQ" and "ITU-R BT.2100-2 HLG"
+methods, and from the reference white levels in ITU-R BT.2408.
+
+The RGB values are linear ITU-R BT.2020. They are in cd/m² for PQ and in
+normalized scene light for HLG.
+
+Tests conversion from linear BT.2020 RGB to ICtCp, and back.
+*/
+
+use approx::assert_relative_eq;
+use csv;
+use serde_derive::Deserialize;
+
+use palette::{
+    convert::FromColorUnclamped,
+    encoding::{Hlg, Pq},
+    rgb::LinRec2020,
+    ICtCp,
+};
+
+/// The luminance of `Y = 1.0` for PQ, in cd/m².
+const PQ_REFERENCE_WHITE: f64 = 203.0;
+
+/// The scene light of `Y = 1.0` for HLG.
+const HLG_REFERENCE_WHITE: f64 = 0.26496256;
+
+#[derive(Deserialize, PartialEq)]
+struct IctcpRaw {
+    method: String,
+    red: f64,
+    green: f64,
+    blue: f64,
+    i: f64,
+    ct: f64,
+    cp: f64,
+}
+
+fn load_data() -> Vec<IctcpRaw> {
+    let file_name = "tests/convert/data_ictcp.csv";
+    let mut rdr = csv::Reader::from_path(file_name)
+        .expect("csv file could not be loaded in tests for ICtCp data");
+    let mut color_data = Vec::new();
+    for record in rdr.deserialize() {
+        let r: IctcpRaw = record.expect("color data could not be decoded in tests for ICtCp data");
+        color_data.push(r)
+    }
+    color_data
+}
+
+pub fn run_tests() {
+    for expected in load_data() {
+        match &*expected.method {
+            "pq" => {
+                let rgb = LinRec2020::new(expected.red, expected.green, expected.blue)
+                    / PQ_REFERENCE_WHITE;
+                let ictcp = ICtCp::<Pq, f64>::from_color_unclamped(rgb);
+
+                assert_relative_eq!(
+                    ictcp,
+                    ICtCp::new(expected.i, expected.ct, expected.cp),
+                    epsilon = 0.000001
+                );
+                assert_relative_eq!(
+                    LinRec2020::from_color_unclamped(ictcp) * PQ_REFERENCE_WHITE,
+                    LinRec2020::new(expected.red, expected.green, expected.blue),
+                    epsilon = 0.0001
+                );
+            }
+            "hlg" => {
+                let rgb = LinRec2020::new(expected.red, expected.green, expected.blue)
+                    / HLG_REFERENCE_WHITE;
+                let ictcp = ICtCp::<Hlg, f64>::from_color_unclamped(rgb);
+
+                assert_relative_eq!(
+                    ictcp,
+                    ICtCp::new(expected.i, expected.ct, expected.cp),
+                    epsilon = 0.000001
+                );
+                assert_relative_eq!(
+                    LinRec2020::from_color_unclamped(ictcp) * HLG_REFERENCE_WHITE,
+                    LinRec2020::new(expected.red, expected.green, expected.blue),
+                    epsilon = 0.000001
+                );
+            }
+            method => panic!("unknown ICtCp method `{}`", method),
+        }
+    }
+}
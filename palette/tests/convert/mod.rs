@@ -1,6 +1,8 @@
 mod data_cie_15_2004;
 mod data_ciede_2000;
+mod data_color_difference;
 mod data_color_mine;
+mod data_ictcp;
 mod lab_lch;
 
 #[test]
@@ -13,6 +15,26 @@ pub fn color_difference_ciede() {
     data_ciede_2000::run_tests();
 }
 
+#[test]
+pub fn color_difference_cie76() {
+    data_color_difference::run_cie76_tests();
+}
+
+#[test]
+pub fn color_difference_cie94() {
+    data_color_difference::run_cie94_tests();
+}
+
+#[test]
+pub fn color_difference_cmc() {
+    data_color_difference::run_cmc_tests();
+}
+
+#[test]
+pub fn ictcp_from_rec2020() {
+    data_ictcp::run_tests();
+}
+
 #[test]
 pub fn color_mine_from_lab() {
     data_color_mine::run_from_lab_tests();
@@ -65,6 +65,14 @@ pub fn derive(item: TokenStream) -> ::std::result::Result<TokenStream, Vec<::syn
     let mut implementations =
         generate_from_implementations(&ident, &generics, &item_meta, &all_from_impl_params);
 
+    implementations.push(generate_has_white_point_implementation(
+        &ident,
+        &generics,
+        &item_meta,
+        &white_point,
+        white_point_source,
+    ));
+
     if let Some((alpha_property, alpha_type)) = alpha_field {
         implementations.push(generate_from_alpha_implementation_with_internal(
             &ident,
@@ -144,7 +152,7 @@ fn prepare_from_impl(
                     parse_quote!(#nearest_color_path::<#linear_path<#white_point>, #component>)
                 }
             }
-            "Oklab" | "Oklch" | "Okhsv" | "Okhsl" | "Okhwb" => {
+            "Oklab" | "Oklch" | "Okhsv" | "Okhsl" | "Okhwb" | "Hct" | "Jzazbz" | "Jzczhz" => {
                 parse_quote!(#nearest_color_path::<#component>)
             }
             _ => {
@@ -277,6 +285,46 @@ fn generate_from_implementations(
     implementations
 }
 
+fn generate_has_white_point_implementation(
+    ident: &Ident,
+    generics: &Generics,
+    meta: &TypeItemAttributes,
+    white_point: &Type,
+    white_point_source: Option<WhitePointSource>,
+) -> TokenStream2 {
+    let has_white_point_path = util::path(&["white_point", "HasWhitePoint"], meta.internal);
+
+    let mut generics = generics.clone();
+    match white_point_source {
+        Some(WhitePointSource::RgbStandard) => {
+            let rgb_standard_path = util::path(&["rgb", "RgbStandard"], meta.internal);
+            let rgb_standard = meta.rgb_standard.as_ref();
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#rgb_standard: #rgb_standard_path));
+        }
+        Some(WhitePointSource::LumaStandard) => {
+            let luma_standard_path = util::path(&["luma", "LumaStandard"], meta.internal);
+            let luma_standard = meta.luma_standard.as_ref();
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#luma_standard: #luma_standard_path));
+        }
+        Some(WhitePointSource::WhitePoint) | None => {}
+    }
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #impl_generics #has_white_point_path for #ident #type_generics #where_clause {
+            type WhitePoint = #white_point;
+        }
+    }
+}
+
 fn generate_from_alpha_implementation(
     ident: &Ident,
     generics: &Generics,
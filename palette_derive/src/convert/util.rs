@@ -109,9 +109,19 @@ pub fn get_convert_color_type(
                 )
             }
         }
-        "Oklab" | "Oklch" | "Okhsv" | "Okhsl" | "Okhwb" => {
+        "Oklab" | "Oklch" | "Okhsv" | "Okhsl" | "Okhwb" | "Hct" | "Jzazbz" | "Jzczhz" => {
             (parse_quote!(#color_path<#component>), UsedInput::default())
         }
+        "ICtCp" => {
+            generics.params.push(GenericParam::Type(
+                Ident::new("_S", Span::call_site()).into(),
+            ));
+
+            (
+                parse_quote!(#color_path<_S, #component>),
+                UsedInput::default(),
+            )
+        }
         _ => (
             parse_quote!(#color_path<#white_point, #component>),
             UsedInput { white_point: true },
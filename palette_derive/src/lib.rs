@@ -39,7 +39,8 @@ mod util;
 
 const COLOR_TYPES: &[&str] = &[
     "Rgb", "Luma", "Hsl", "Hsluv", "Hsv", "Hwb", "Lab", "Lch", "Lchuv", "Luv", "Oklab", "Oklch",
-    "Okhwb", "Okhsl", "Okhsv", "Xyz", "Yxy",
+    "Okhwb", "Okhsl", "Okhsv", "Xyz", "Yxy", "Cam16", "Cam16Ucs", "Hct", "Jzazbz", "Jzczhz",
+    "ICtCp",
 ];
 
 const PREFERRED_CONVERSION_SOURCE: &[(&str, &str)] = &[
@@ -59,6 +60,12 @@ const PREFERRED_CONVERSION_SOURCE: &[(&str, &str)] = &[
     ("Okhsv", "Oklab"),
     ("Okhwb", "Okhsv"),
     ("Yxy", "Xyz"),
+    ("Cam16", "Xyz"),
+    ("Cam16Ucs", "Cam16"),
+    ("Hct", "Xyz"),
+    ("Jzazbz", "Xyz"),
+    ("Jzczhz", "Jzazbz"),
+    ("ICtCp", "Xyz"),
 ];
 
 #[proc_macro_derive(WithAlpha, attributes(palette))]